@@ -0,0 +1,212 @@
+// connection.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! A WebSocket connection to gpop-daemon that transparently reconnects with
+//! exponential backoff and replays any request that was sent but never
+//! answered before the link dropped. A freshly (re)established connection
+//! starts out on gpop-daemon's default wildcard subscription, so resuming the
+//! read loop after a reconnect is all that "resuming event delivery"
+//! requires; this connection never narrows that subscription itself.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::{Sink, SinkExt, StreamExt};
+use tokio::sync::{broadcast, mpsc, oneshot, Mutex};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{debug, error, info, warn};
+
+use crate::protocol::{Notification, Request, Response};
+
+/// Delay before the first reconnect attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+/// Reconnect delay never grows past this, no matter how many attempts fail.
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+/// Capacity of the notification broadcast channel; a slow subscriber only
+/// misses events once it falls this far behind.
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+struct PendingRequest {
+    request: Request,
+    response_tx: oneshot::Sender<Response>,
+}
+
+type PendingMap = Arc<Mutex<HashMap<String, PendingRequest>>>;
+
+/// Handle to a reconnecting gpop-daemon connection. Cheap to clone; every
+/// clone shares the same background connection task.
+#[derive(Clone)]
+pub struct Connection {
+    request_tx: mpsc::UnboundedSender<PendingRequest>,
+    event_tx: broadcast::Sender<Notification>,
+}
+
+impl Connection {
+    /// Start connecting to `url` in the background. Returns immediately; the
+    /// connection task keeps retrying with backoff until the process exits.
+    pub fn connect(url: String) -> Self {
+        let (request_tx, request_rx) = mpsc::unbounded_channel::<PendingRequest>();
+        let (event_tx, _) = broadcast::channel::<Notification>(EVENT_CHANNEL_CAPACITY);
+
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+
+        let run_event_tx = event_tx.clone();
+        tokio::spawn(Self::run(url, request_rx, pending, run_event_tx));
+
+        Self {
+            request_tx,
+            event_tx,
+        }
+    }
+
+    /// Send a request and await its response. Returns `None` if the
+    /// connection task has shut down.
+    pub async fn send(&self, request: Request) -> Option<Response> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.request_tx
+            .send(PendingRequest {
+                request,
+                response_tx,
+            })
+            .ok()?;
+        response_rx.await.ok()
+    }
+
+    /// Subscribe to daemon notifications (state changes, EOS, pipeline
+    /// lifecycle). This is the local fan-out of whatever the connection
+    /// receives; it is unrelated to the daemon's own `subscribe` JSON-RPC
+    /// method, which this connection never calls.
+    pub fn subscribe(&self) -> broadcast::Receiver<Notification> {
+        self.event_tx.subscribe()
+    }
+
+    /// Owns the socket for the lifetime of the connection: connects, serves
+    /// requests and events until the link drops, then reconnects with
+    /// exponential backoff and replays whatever was still pending.
+    async fn run(
+        url: String,
+        mut request_rx: mpsc::UnboundedReceiver<PendingRequest>,
+        pending: PendingMap,
+        event_tx: broadcast::Sender<Notification>,
+    ) {
+        let mut backoff = INITIAL_BACKOFF;
+
+        loop {
+            let ws_stream = match connect_async(&url).await {
+                Ok((stream, _)) => {
+                    info!("Connected to {}", url);
+                    backoff = INITIAL_BACKOFF;
+                    stream
+                }
+                Err(e) => {
+                    warn!("Failed to connect to {}: {}", url, e);
+                    Self::sleep_backoff(&mut backoff).await;
+                    continue;
+                }
+            };
+
+            let (mut write, mut read) = ws_stream.split();
+
+            // Replay every request still awaiting a response from the
+            // previous socket.
+            {
+                let pending = pending.lock().await;
+                for pending_request in pending.values() {
+                    if !Self::send_request(&mut write, &pending_request.request).await {
+                        break;
+                    }
+                }
+            }
+
+            let mut link_alive = true;
+            while link_alive {
+                tokio::select! {
+                    incoming = request_rx.recv() => {
+                        let Some(pending_request) = incoming else {
+                            // Sender side dropped: the process is shutting down.
+                            return;
+                        };
+                        let id = pending_request.request.id.clone();
+                        if Self::send_request(&mut write, &pending_request.request).await {
+                            pending.lock().await.insert(id, pending_request);
+                        } else {
+                            let _ = pending_request.response_tx.send(Response {
+                                id,
+                                result: None,
+                                error: None,
+                            });
+                            link_alive = false;
+                        }
+                    }
+                    msg = read.next() => {
+                        match msg {
+                            Some(Ok(Message::Text(text))) => {
+                                Self::dispatch_message(&text, &pending, &event_tx).await;
+                            }
+                            Some(Ok(Message::Close(_))) | None => {
+                                info!("Connection to {} closed", url);
+                                link_alive = false;
+                            }
+                            Some(Ok(_)) => {}
+                            Some(Err(e)) => {
+                                error!("Error reading from {}: {}", url, e);
+                                link_alive = false;
+                            }
+                        }
+                    }
+                }
+            }
+
+            Self::sleep_backoff(&mut backoff).await;
+        }
+    }
+
+    async fn send_request<W>(write: &mut W, request: &Request) -> bool
+    where
+        W: Sink<Message> + Unpin,
+    {
+        let msg = match serde_json::to_string(request) {
+            Ok(msg) => msg,
+            Err(e) => {
+                error!("Failed to serialize request {}: {}", request.id, e);
+                return false;
+            }
+        };
+        write.send(Message::Text(msg.into())).await.is_ok()
+    }
+
+    /// Parse an incoming text frame as either a response (matched to a
+    /// pending request by id) or an event broadcast.
+    async fn dispatch_message(
+        text: &str,
+        pending: &PendingMap,
+        event_tx: &broadcast::Sender<Notification>,
+    ) {
+        if let Ok(response) = serde_json::from_str::<Response>(text) {
+            if let Some(pending_request) = pending.lock().await.remove(&response.id) {
+                let _ = pending_request.response_tx.send(response);
+            }
+        } else if let Ok(notification) = serde_json::from_str::<Notification>(text) {
+            if event_tx.send(notification).is_err() {
+                debug!("No event subscribers");
+            }
+        } else {
+            warn!("Unrecognized message from daemon: {}", text);
+        }
+    }
+
+    async fn sleep_backoff(backoff: &mut Duration) {
+        let jitter_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_millis() % 100)
+            .unwrap_or(0) as u64;
+        tokio::time::sleep(*backoff + Duration::from_millis(jitter_ms)).await;
+        *backoff = (*backoff * 2).min(MAX_BACKOFF);
+    }
+}
@@ -6,45 +6,15 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use futures_util::{SinkExt, StreamExt};
+mod connection;
+mod protocol;
+
 use rustyline::error::ReadlineError;
 use rustyline::DefaultEditor;
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
 use tokio::sync::mpsc;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
-
-#[derive(Debug, Serialize)]
-struct Request {
-    id: String,
-    method: String,
-    params: Value,
-}
-
-#[derive(Debug, Deserialize)]
-struct Response {
-    id: String,
-    #[serde(default)]
-    result: Option<Value>,
-    #[serde(default)]
-    error: Option<ErrorInfo>,
-}
 
-#[derive(Debug, Deserialize)]
-struct ErrorInfo {
-    code: i32,
-    message: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct Event {
-    event: String,
-    data: Value,
-}
-
-fn new_id() -> String {
-    uuid::Uuid::new_v4().to_string()
-}
+use connection::Connection;
+use protocol::{new_id, Request};
 
 fn print_help() {
     println!("\nAvailable commands:");
@@ -191,11 +161,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .unwrap_or_else(|| "ws://127.0.0.1:9000".to_string());
 
     println!("Connecting to {}...", url);
-
-    let (ws_stream, _) = connect_async(&url).await?;
-    println!("Connected!");
-
-    let (mut write, mut read) = ws_stream.split();
+    let connection = Connection::connect(url);
 
     // Channel for sending commands from readline thread to async task
     let (cmd_tx, mut cmd_rx) = mpsc::unbounded_channel::<InputEvent>();
@@ -242,40 +208,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     });
 
-    // Spawn a task to read messages from WebSocket
-    let read_task = tokio::spawn(async move {
-        while let Some(msg) = read.next().await {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    // Try to parse as event first
-                    if let Ok(event) = serde_json::from_str::<Event>(&text) {
-                        println!("\n[EVENT] {}: {:?}", event.event, event.data);
-                    } else if let Ok(response) = serde_json::from_str::<Response>(&text) {
-                        if let Some(error) = response.error {
-                            println!(
-                                "\n[ERROR] id={}: {} (code: {})",
-                                response.id, error.message, error.code
-                            );
-                        } else if let Some(result) = response.result {
-                            println!(
-                                "\n[RESPONSE] id={}: {}",
-                                response.id,
-                                serde_json::to_string_pretty(&result).unwrap()
-                            );
-                        }
-                    } else {
-                        println!("\n[RAW] {}", text);
-                    }
-                }
-                Ok(Message::Close(_)) => {
-                    println!("\nConnection closed");
-                    break;
+    // Spawn a task to print events as they arrive; it keeps receiving across
+    // reconnects since `Connection` resumes its read loop transparently.
+    let mut event_rx = connection.subscribe();
+    let event_task = tokio::spawn(async move {
+        loop {
+            match event_rx.recv().await {
+                Ok(notification) => {
+                    println!("\n[EVENT] {}: {:?}", notification.method, notification.params)
                 }
-                Err(e) => {
-                    eprintln!("\nError: {}", e);
-                    break;
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    eprintln!("\n[EVENT] missed {} events", n);
                 }
-                _ => {}
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
             }
         }
     });
@@ -290,9 +235,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     InputEvent::Line(line) => {
                         let trimmed = line.trim();
                         if let Some(request) = parse_command(trimmed) {
-                            let msg = serde_json::to_string(&request)?;
-                            println!("Sending: {}", msg);
-                            write.send(Message::Text(msg.into())).await?;
+                            let id = request.id.clone();
+                            println!("Sending: {} {}", request.method, request.params);
+                            match connection.send(request).await {
+                                Some(response) => {
+                                    if let Some(error) = response.error {
+                                        println!(
+                                            "[ERROR] id={}: {} (code: {})",
+                                            id, error.message, error.code
+                                        );
+                                    } else if let Some(result) = response.result {
+                                        println!(
+                                            "[RESPONSE] id={}: {}",
+                                            id,
+                                            serde_json::to_string_pretty(&result).unwrap()
+                                        );
+                                    }
+                                }
+                                None => {
+                                    eprintln!("[ERROR] connection closed before a response arrived");
+                                }
+                            }
                         }
                     }
                     InputEvent::Quit => {
@@ -310,7 +273,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    read_task.abort();
+    event_task.abort();
     let _ = readline_handle.join();
     println!("Goodbye!");
     Ok(())
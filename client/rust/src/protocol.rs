@@ -0,0 +1,49 @@
+// protocol.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Wire types for the gpop-daemon JSON-RPC protocol, shared between the
+//! interactive command loop and the reconnecting [`crate::connection`] layer.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Request {
+    pub id: String,
+    pub method: String,
+    pub params: Value,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Response {
+    pub id: String,
+    #[serde(default)]
+    pub result: Option<Value>,
+    #[serde(default)]
+    pub error: Option<ErrorInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ErrorInfo {
+    pub code: i32,
+    pub message: String,
+}
+
+/// A server-pushed notification: `method` names the event kind
+/// (`state_changed`, `eos`, `error`, ...) and `params` carries its payload.
+/// Has no `id` field, which is how [`crate::connection::Connection`] tells it
+/// apart from a [`Response`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct Notification {
+    pub method: String,
+    pub params: Value,
+}
+
+pub fn new_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
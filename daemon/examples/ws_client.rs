@@ -25,6 +25,18 @@ struct Response {
     result: Option<Value>,
     #[serde(default)]
     error: Option<ErrorInfo>,
+    /// Mirrors `gpop::error::Severity`: `Success` for a normal reply,
+    /// `Failure` for a recoverable per-request error the prompt can just
+    /// keep going after, `Fatal` for a daemon/transport-level condition
+    /// that means the rest of this connection can't be trusted.
+    severity: Severity,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+enum Severity {
+    Success,
+    Failure,
+    Fatal,
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,8 +79,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     } else if let Ok(response) = serde_json::from_str::<Response>(&text) {
                         if let Some(error) = response.error {
                             println!(
-                                "\n[ERROR] id={}: {} (code: {})",
-                                response.id, error.message, error.code
+                                "\n[{:?}] id={}: {} (code: {})",
+                                response.severity, response.id, error.message, error.code
                             );
                         } else if let Some(result) = response.result {
                             println!(
@@ -77,6 +89,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 serde_json::to_string_pretty(&result).unwrap()
                             );
                         }
+
+                        if response.severity == Severity::Fatal {
+                            println!("\nFatal error from server, closing connection");
+                            break;
+                        }
                     } else {
                         println!("\n[RAW] {}", text);
                     }
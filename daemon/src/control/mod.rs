@@ -0,0 +1,21 @@
+// mod.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+mod protocol;
+mod server;
+
+pub use protocol::{Command, CommandResponse, CommandResult, PipelineInfo, Position};
+pub use server::ControlSocketServer;
+
+/// Default permissions (octal) applied to the control socket file: owner
+/// read/write only, since the socket has no authentication of its own
+/// beyond whatever the filesystem already enforces.
+pub const DEFAULT_SOCKET_PERMISSIONS: u32 = 0o600;
+
+/// Default path for the control socket.
+pub const DEFAULT_CONTROL_SOCKET_PATH: &str = "/tmp/gpop-control.sock";
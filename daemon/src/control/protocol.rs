@@ -0,0 +1,80 @@
+// protocol.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Compact binary command/response types for the Unix-domain control
+//! socket. Mirrors the methods exposed over WebSocket/IPC in
+//! [`crate::websocket::protocol`], but as a `bincode`-encoded enum instead
+//! of untyped JSON, for callers that want to skip JSON parsing entirely.
+
+use serde::{Deserialize, Serialize};
+
+use crate::gst::PipelineState;
+
+/// A request sent to the control socket. One variant per supported
+/// operation, each carrying exactly the parameters that operation needs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Command {
+    ListPipelines,
+    CreatePipeline { description: String },
+    RemovePipeline { pipeline_id: String },
+    GetPipelineInfo { pipeline_id: String },
+    SetState { pipeline_id: String, state: PipelineState },
+    Play { pipeline_id: String },
+    Pause { pipeline_id: String },
+    Stop { pipeline_id: String },
+    GetPosition { pipeline_id: String },
+    UpdatePipeline { pipeline_id: String, description: String },
+    GetVersion,
+    GetPipelineCount,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineInfo {
+    pub id: String,
+    pub description: String,
+    pub state: PipelineState,
+    pub streaming: bool,
+}
+
+impl From<crate::gst::PipelineInfo> for PipelineInfo {
+    fn from(info: crate::gst::PipelineInfo) -> Self {
+        Self {
+            id: info.id,
+            description: info.description,
+            state: info.state,
+            streaming: info.streaming,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Position {
+    pub position_ns: Option<u64>,
+    pub duration_ns: Option<u64>,
+}
+
+/// The payload of a successful [`CommandResponse`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CommandResult {
+    Ok,
+    PipelineCreated { pipeline_id: String },
+    Pipelines { pipelines: Vec<PipelineInfo> },
+    PipelineInfo(PipelineInfo),
+    Position(Position),
+    PipelineCount { count: usize },
+    Version { version: String },
+}
+
+/// The frame written back for every [`Command`]: either the result, or an
+/// error message (mirroring `GpopError::to_string()`, since bincode has no
+/// equivalent of serde_json's untyped `Value` to carry a structured error).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CommandResponse {
+    Ok(CommandResult),
+    Err { message: String },
+}
@@ -0,0 +1,205 @@
+// server.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Unix-domain socket control interface: a lean, JSON-free sibling to the
+//! WebSocket/IPC transports for shell scripts and sidecar tools on the same
+//! host that want to add/remove/play pipelines without the HTTP upgrade or
+//! API-key dance. Frames are length-prefixed (`u32` little-endian byte
+//! count) `bincode`-encoded [`Command`]/[`CommandResponse`] values.
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{UnixListener, UnixStream};
+use tracing::{info, warn};
+
+use crate::gst::PipelineManager;
+
+use super::protocol::{Command, CommandResponse, CommandResult, Position};
+
+/// Maximum size of a single encoded frame, to bound memory use against a
+/// malformed or hostile length prefix.
+const MAX_FRAME_LEN: u32 = 16 * 1024 * 1024;
+
+pub struct ControlSocketServer {
+    path: PathBuf,
+    permissions: u32,
+    manager: Arc<PipelineManager>,
+}
+
+impl ControlSocketServer {
+    pub fn new(path: impl Into<PathBuf>, permissions: u32, manager: Arc<PipelineManager>) -> Self {
+        Self {
+            path: path.into(),
+            permissions,
+            manager,
+        }
+    }
+
+    /// Bind the socket and serve connections until cancelled. Each accepted
+    /// `UnixStream` is handled on its own task so one slow or stuck client
+    /// can't stall the others.
+    pub async fn run(&self) -> std::io::Result<()> {
+        // A stale socket file from a previous, uncleanly-terminated run
+        // would otherwise make the bind fail with `AddrInUse`.
+        if self.path.exists() {
+            std::fs::remove_file(&self.path)?;
+        }
+
+        let listener = UnixListener::bind(&self.path)?;
+        std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(self.permissions))?;
+        info!(
+            "Control socket listening at {} (mode {:o})",
+            self.path.display(),
+            self.permissions
+        );
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let manager = Arc::clone(&self.manager);
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream, manager).await {
+                    warn!("Control socket connection error: {}", e);
+                }
+            });
+        }
+    }
+
+    /// Remove the socket file from the filesystem. Called during daemon
+    /// shutdown so it doesn't linger and confuse the next `UnixListener::bind`.
+    pub fn unlink(&self) {
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                warn!(
+                    "Failed to remove control socket {}: {}",
+                    self.path.display(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+async fn handle_connection(
+    mut stream: UnixStream,
+    manager: Arc<PipelineManager>,
+) -> std::io::Result<()> {
+    loop {
+        let mut len_buf = [0u8; 4];
+        match stream.read_exact(&mut len_buf).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(()),
+            Err(e) => return Err(e),
+        }
+
+        let len = u32::from_le_bytes(len_buf);
+        if len > MAX_FRAME_LEN {
+            warn!(
+                "Control socket frame too large ({} bytes, max {}), closing connection",
+                len, MAX_FRAME_LEN
+            );
+            return Ok(());
+        }
+
+        let mut payload = vec![0u8; len as usize];
+        stream.read_exact(&mut payload).await?;
+
+        let response = match bincode::deserialize::<Command>(&payload) {
+            Ok(command) => dispatch(&manager, command).await,
+            Err(e) => CommandResponse::Err {
+                message: format!("Invalid command: {}", e),
+            },
+        };
+
+        send_frame(&mut stream, &response).await?;
+    }
+}
+
+async fn send_frame(stream: &mut UnixStream, response: &CommandResponse) -> std::io::Result<()> {
+    let encoded = bincode::serialize(response).unwrap_or_else(|e| {
+        warn!("Failed to encode control socket response: {}", e);
+        bincode::serialize(&CommandResponse::Err {
+            message: "Internal serialization error".to_string(),
+        })
+        .expect("serializing a fixed error response cannot fail")
+    });
+
+    stream.write_all(&(encoded.len() as u32).to_le_bytes()).await?;
+    stream.write_all(&encoded).await?;
+    Ok(())
+}
+
+async fn dispatch(manager: &PipelineManager, command: Command) -> CommandResponse {
+    let result = match command {
+        Command::ListPipelines => Ok(CommandResult::Pipelines {
+            pipelines: manager
+                .list_pipelines()
+                .await
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }),
+        Command::CreatePipeline { description } => manager
+            .add_pipeline(&description)
+            .await
+            .map(|pipeline_id| CommandResult::PipelineCreated { pipeline_id }),
+        Command::RemovePipeline { pipeline_id } => manager
+            .remove_pipeline(&pipeline_id)
+            .await
+            .map(|()| CommandResult::Ok),
+        Command::GetPipelineInfo { pipeline_id } => manager
+            .get_pipeline_info(&pipeline_id)
+            .await
+            .map(|info| CommandResult::PipelineInfo(info.into())),
+        Command::SetState { pipeline_id, state } => manager
+            .set_state(&pipeline_id, state)
+            .await
+            .map(|()| CommandResult::Ok),
+        Command::Play { pipeline_id } => {
+            manager.play(&pipeline_id).await.map(|()| CommandResult::Ok)
+        }
+        Command::Pause { pipeline_id } => manager
+            .pause(&pipeline_id)
+            .await
+            .map(|()| CommandResult::Ok),
+        Command::Stop { pipeline_id } => {
+            manager.stop(&pipeline_id).await.map(|()| CommandResult::Ok)
+        }
+        Command::GetPosition { pipeline_id } => manager
+            .get_position(&pipeline_id)
+            .await
+            .map(|(position_ns, duration_ns)| {
+                CommandResult::Position(Position {
+                    position_ns,
+                    duration_ns,
+                })
+            }),
+        Command::UpdatePipeline {
+            pipeline_id,
+            description,
+        } => manager
+            .update_pipeline(&pipeline_id, &description)
+            .await
+            .map(|()| CommandResult::Ok),
+        Command::GetVersion => Ok(CommandResult::Version {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }),
+        Command::GetPipelineCount => Ok(CommandResult::PipelineCount {
+            count: manager.pipeline_count().await,
+        }),
+    };
+
+    match result {
+        Ok(result) => CommandResponse::Ok(result),
+        Err(e) => CommandResponse::Err {
+            message: e.to_string(),
+        },
+    }
+}
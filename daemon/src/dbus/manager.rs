@@ -10,7 +10,7 @@ use std::sync::Arc;
 use zbus::object_server::SignalEmitter;
 use zbus::{interface, zvariant::ObjectPath};
 
-use crate::gst::PipelineManager;
+use crate::gst::{PipelineManager, PlaylistMode};
 
 pub struct ManagerInterface {
     pub manager: Arc<PipelineManager>,
@@ -22,28 +22,63 @@ impl ManagerInterface {
         self.manager
             .add_pipeline(description)
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     async fn remove_pipeline(&self, id: &str) -> zbus::fdo::Result<()> {
         self.manager
             .remove_pipeline(id)
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     async fn get_pipeline_desc(&self, id: &str) -> zbus::fdo::Result<String> {
         self.manager
             .get_pipeline_description(id)
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     async fn update_pipeline(&self, id: &str, description: &str) -> zbus::fdo::Result<()> {
         self.manager
             .update_pipeline(id, description)
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
+    }
+
+    /// Enable (`interval_ms > 0`) or disable (`interval_ms == 0`) periodic
+    /// `Progress` signals for a pipeline, at the given cadence.
+    async fn set_progress_reporting(&self, id: &str, interval_ms: u64) -> zbus::fdo::Result<()> {
+        self.manager
+            .set_progress_reporting(id, interval_ms)
+            .await
+            .map_err(|e| super::gpop_error_to_fdo(&e))
+    }
+
+    /// Create a gapless auto-advancing playlist pipeline from `uris`.
+    /// `mode` is `"finite"` or `"infinite"`; `iterations` is ignored in
+    /// infinite mode and clamped to at least 1 in finite mode.
+    async fn add_playlist(
+        &self,
+        uris: Vec<String>,
+        mode: &str,
+        iterations: u32,
+    ) -> zbus::fdo::Result<String> {
+        let mode = match mode {
+            "finite" => PlaylistMode::Finite,
+            "infinite" => PlaylistMode::Infinite,
+            other => {
+                return Err(zbus::fdo::Error::Failed(format!(
+                    "Invalid mode '{}'. Valid values: finite, infinite",
+                    other
+                )))
+            }
+        };
+
+        self.manager
+            .add_playlist(uris, mode, iterations)
+            .await
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     #[zbus(property)]
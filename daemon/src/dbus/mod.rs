@@ -14,9 +14,10 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{error, info, warn};
 use zbus::connection::Builder;
+use zbus::object_server::SignalEmitter;
 use zbus::Connection;
 
-use crate::error::Result;
+use crate::error::{GpopError, Result, Severity};
 use crate::gst::{EventReceiver, PipelineEvent, PipelineManager};
 
 use self::manager::ManagerInterface;
@@ -24,6 +25,19 @@ use self::pipeline::PipelineInterface;
 
 pub const DBUS_SERVICE_NAME: &str = "org.gpop";
 
+/// Convert a `GpopError` into a D-Bus fault, carrying its [`Severity`] over
+/// into zbus's own error vocabulary: a recoverable `Failure` maps to the
+/// generic `Failed`, while a `Fatal` condition (manager/transport no longer
+/// usable) maps to `IOError` so D-Bus clients can distinguish "retry this
+/// call" from "this connection is no longer trustworthy" without parsing the
+/// message text.
+pub fn gpop_error_to_fdo(err: &GpopError) -> zbus::fdo::Error {
+    match err.severity() {
+        Severity::Fatal => zbus::fdo::Error::IOError(err.to_string()),
+        Severity::Failure | Severity::Success => zbus::fdo::Error::Failed(err.to_string()),
+    }
+}
+
 pub struct DbusServer {
     connection: Connection,
     manager: Arc<PipelineManager>,
@@ -118,6 +132,75 @@ impl DbusServer {
     pub fn connection(&self) -> &Connection {
         &self.connection
     }
+
+    /// Emit a `Progress` signal for `pipeline_id`, if it's still registered.
+    /// Silently does nothing for a pipeline that's already been removed -
+    /// its last progress tick can race with its `PipelineRemoved` event.
+    pub async fn emit_progress(
+        &self,
+        pipeline_id: &str,
+        position_ns: i64,
+        duration_ns: i64,
+    ) -> Result<()> {
+        let index = {
+            let indices = self.pipeline_indices.read().await;
+            indices.get(pipeline_id).copied()
+        };
+
+        let Some(index) = index else {
+            return Ok(());
+        };
+
+        let path = PipelineInterface::object_path(index);
+        let emitter = SignalEmitter::new(&self.connection, path, "org.gpop.Pipeline")?;
+        PipelineInterface::progress(&emitter, position_ns, duration_ns).await?;
+        Ok(())
+    }
+
+    /// Emit an `EntryChanged` signal for a playlist pipeline that just
+    /// advanced to a new entry. Silently does nothing for a pipeline that's
+    /// already been removed.
+    pub async fn emit_entry_changed(&self, pipeline_id: &str, index: usize, uri: &str) -> Result<()> {
+        let Some(dbus_index) = self.pipeline_indices.read().await.get(pipeline_id).copied() else {
+            return Ok(());
+        };
+
+        let path = PipelineInterface::object_path(dbus_index);
+        let emitter = SignalEmitter::new(&self.connection, path, "org.gpop.Pipeline")?;
+        PipelineInterface::entry_changed(&emitter, index as u32, uri).await?;
+        Ok(())
+    }
+
+    /// Emit a `PlaylistEnded` signal for a playlist pipeline that reached EOS
+    /// on its last entry with no more iterations left to play.
+    pub async fn emit_playlist_ended(&self, pipeline_id: &str) -> Result<()> {
+        let Some(dbus_index) = self.pipeline_indices.read().await.get(pipeline_id).copied() else {
+            return Ok(());
+        };
+
+        let path = PipelineInterface::object_path(dbus_index);
+        let emitter = SignalEmitter::new(&self.connection, path, "org.gpop.Pipeline")?;
+        PipelineInterface::playlist_ended(&emitter).await?;
+        Ok(())
+    }
+
+    /// Emit a `BitrateChanged` signal for a pipeline whose adaptive bitrate
+    /// controller just changed its target for an encoder.
+    pub async fn emit_bitrate_changed(
+        &self,
+        pipeline_id: &str,
+        encoder_name: &str,
+        bitrate_bps: u32,
+    ) -> Result<()> {
+        let Some(dbus_index) = self.pipeline_indices.read().await.get(pipeline_id).copied() else {
+            return Ok(());
+        };
+
+        let path = PipelineInterface::object_path(dbus_index);
+        let emitter = SignalEmitter::new(&self.connection, path, "org.gpop.Pipeline")?;
+        PipelineInterface::bitrate_changed(&emitter, encoder_name, bitrate_bps).await?;
+        Ok(())
+    }
 }
 
 pub async fn run_dbus_event_forwarder(dbus_server: Arc<DbusServer>, mut event_rx: EventReceiver) {
@@ -137,6 +220,47 @@ pub async fn run_dbus_event_forwarder(dbus_server: Arc<DbusServer>, mut event_rx
                         error!("Failed to unregister pipeline from DBus: {}", e);
                     }
                 }
+                PipelineEvent::Progress {
+                    pipeline_id,
+                    position_ns,
+                    duration_ns,
+                    ..
+                } => {
+                    let position_ns = position_ns.map(|p| p as i64).unwrap_or(-1);
+                    let duration_ns = duration_ns.map(|d| d as i64).unwrap_or(-1);
+                    if let Err(e) = dbus_server
+                        .emit_progress(&pipeline_id, position_ns, duration_ns)
+                        .await
+                    {
+                        error!("Failed to emit progress signal on DBus: {}", e);
+                    }
+                }
+                PipelineEvent::EntryChanged {
+                    pipeline_id,
+                    index,
+                    uri,
+                } => {
+                    if let Err(e) = dbus_server.emit_entry_changed(&pipeline_id, index, &uri).await {
+                        error!("Failed to emit entry_changed signal on DBus: {}", e);
+                    }
+                }
+                PipelineEvent::PlaylistEnded { pipeline_id } => {
+                    if let Err(e) = dbus_server.emit_playlist_ended(&pipeline_id).await {
+                        error!("Failed to emit playlist_ended signal on DBus: {}", e);
+                    }
+                }
+                PipelineEvent::BitrateChanged {
+                    pipeline_id,
+                    encoder_name,
+                    bitrate_bps,
+                } => {
+                    if let Err(e) = dbus_server
+                        .emit_bitrate_changed(&pipeline_id, &encoder_name, bitrate_bps)
+                        .await
+                    {
+                        error!("Failed to emit bitrate_changed signal on DBus: {}", e);
+                    }
+                }
                 _ => {
                     // State changes, errors, EOS are handled via DBus signals
                     // when properties are queried
@@ -28,7 +28,7 @@ impl PipelineInterface {
             .set_state(&self.pipeline_id, state)
             .await
             .map(|_| true)
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     async fn play(&self) -> zbus::fdo::Result<bool> {
@@ -36,7 +36,7 @@ impl PipelineInterface {
             .play(&self.pipeline_id)
             .await
             .map(|_| true)
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     async fn pause(&self) -> zbus::fdo::Result<bool> {
@@ -44,7 +44,7 @@ impl PipelineInterface {
             .pause(&self.pipeline_id)
             .await
             .map(|_| true)
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     async fn stop(&self) -> zbus::fdo::Result<bool> {
@@ -52,7 +52,7 @@ impl PipelineInterface {
             .stop(&self.pipeline_id)
             .await
             .map(|_| true)
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     /// Get the DOT graph representation of the pipeline.
@@ -71,7 +71,7 @@ impl PipelineInterface {
         self.manager
             .get_dot(&self.pipeline_id, details)
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     /// Get the current position and duration of the pipeline in nanoseconds.
@@ -81,7 +81,7 @@ impl PipelineInterface {
             .manager
             .get_position(&self.pipeline_id)
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))?;
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
 
         // Convert Option<u64> to i64, using -1 for None
         let pos = position.map(|p| p as i64).unwrap_or(-1);
@@ -96,7 +96,142 @@ impl PipelineInterface {
             .update_pipeline(&self.pipeline_id, description)
             .await
             .map(|_| true)
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
+    }
+
+    /// Seek to an absolute position, in nanoseconds. `accurate` trades off
+    /// exact (but possibly slower) seeking against snapping to the nearest
+    /// keyframe.
+    async fn seek(&self, position_ns: u64, accurate: bool) -> zbus::fdo::Result<bool> {
+        self.manager
+            .seek(&self.pipeline_id, position_ns, accurate)
+            .await
+            .map(|_| true)
+            .map_err(|e| super::gpop_error_to_fdo(&e))
+    }
+
+    /// Change the playback rate for fast-forward/reverse trick-mode
+    /// playback. A negative rate plays backward.
+    async fn set_rate(&self, rate: f64) -> zbus::fdo::Result<bool> {
+        self.manager
+            .set_rate(&self.pipeline_id, rate)
+            .await
+            .map(|_| true)
+            .map_err(|e| super::gpop_error_to_fdo(&e))
+    }
+
+    /// Step forward by `frames` video frames while paused.
+    async fn step(&self, frames: u64) -> zbus::fdo::Result<bool> {
+        self.manager
+            .step(&self.pipeline_id, frames)
+            .await
+            .map(|_| true)
+            .map_err(|e| super::gpop_error_to_fdo(&e))
+    }
+
+    /// Get the pipeline's latest latency/QoS statistics (dropped frames,
+    /// jitter, congestion) as a JSON object.
+    async fn get_stats(&self) -> zbus::fdo::Result<String> {
+        let stats = self
+            .manager
+            .stats(&self.pipeline_id)
+            .await
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
+        serde_json::to_string(&stats).map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+    }
+
+    /// List every element in the pipeline's tree, as `(name, factory)`
+    /// pairs, recursing into bins.
+    async fn list_elements(&self) -> zbus::fdo::Result<Vec<(String, String)>> {
+        let elements = self
+            .manager
+            .list_elements(&self.pipeline_id)
+            .await
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
+        Ok(elements.into_iter().map(|e| (e.name, e.factory)).collect())
+    }
+
+    /// List a named element's GObject properties (name, type, flags,
+    /// default and current value) as a JSON array, since D-Bus has no
+    /// convenient ad hoc struct-array type for it on this interface.
+    async fn element_properties(&self, element_name: &str) -> zbus::fdo::Result<String> {
+        let properties = self
+            .manager
+            .element_properties(&self.pipeline_id, element_name)
+            .await
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
+        serde_json::to_string(&properties).map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+    }
+
+    /// List a named pad's GObject properties, same shape as
+    /// `element_properties`. The pad must already exist - a request pad
+    /// that hasn't been requested/linked yet isn't visible here.
+    async fn pad_properties(&self, element_name: &str, pad_name: &str) -> zbus::fdo::Result<String> {
+        let properties = self
+            .manager
+            .pad_properties(&self.pipeline_id, element_name, pad_name)
+            .await
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
+        serde_json::to_string(&properties).map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+    }
+
+    /// Read a single element property as its JSON representation (e.g.
+    /// `"42"`, `"true"`, `"\"x264enc\""`).
+    async fn get_property(&self, element_name: &str, property_name: &str) -> zbus::fdo::Result<String> {
+        let value = self
+            .manager
+            .get_element_property(&self.pipeline_id, element_name, property_name)
+            .await
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
+        Ok(value.to_string())
+    }
+
+    /// Set a single element property from its JSON representation.
+    async fn set_property(
+        &self,
+        element_name: &str,
+        property_name: &str,
+        value_json: &str,
+    ) -> zbus::fdo::Result<bool> {
+        let value: crate::gst::graph::PropertyValue =
+            serde_json::from_str(value_json).map_err(|e| zbus::fdo::Error::Failed(e.to_string()))?;
+        self.manager
+            .set_element_property(&self.pipeline_id, element_name, property_name, &value)
+            .await
+            .map(|_| true)
+            .map_err(|e| super::gpop_error_to_fdo(&e))
+    }
+
+    /// Read a single pad property as its JSON representation.
+    async fn get_pad_property(
+        &self,
+        element_name: &str,
+        pad_name: &str,
+        property_name: &str,
+    ) -> zbus::fdo::Result<String> {
+        let value = self
+            .manager
+            .get_pad_property(&self.pipeline_id, element_name, pad_name, property_name)
+            .await
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
+        Ok(value.to_string())
+    }
+
+    /// Set a single pad property from its JSON representation.
+    async fn set_pad_property(
+        &self,
+        element_name: &str,
+        pad_name: &str,
+        property_name: &str,
+        value_json: &str,
+    ) -> zbus::fdo::Result<bool> {
+        let value: crate::gst::graph::PropertyValue =
+            serde_json::from_str(value_json).map_err(|e| zbus::fdo::Error::Failed(e.to_string()))?;
+        self.manager
+            .set_pad_property(&self.pipeline_id, element_name, pad_name, property_name, &value)
+            .await
+            .map(|_| true)
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     #[zbus(property)]
@@ -109,7 +244,7 @@ impl PipelineInterface {
         self.manager
             .get_pipeline_description(&self.pipeline_id)
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+            .map_err(|e| super::gpop_error_to_fdo(&e))
     }
 
     #[zbus(property, name = "State")]
@@ -118,17 +253,44 @@ impl PipelineInterface {
             .manager
             .get_pipeline_info(&self.pipeline_id)
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))?;
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
         Ok(info.state.to_string())
     }
 
+    /// Get the current entry and progress of a playlist pipeline as a JSON
+    /// object. Fails if this pipeline wasn't created via `Manager.AddPlaylist`.
+    async fn get_playlist_info(&self) -> zbus::fdo::Result<String> {
+        let info = self
+            .manager
+            .get_playlist_info(&self.pipeline_id)
+            .await
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
+        serde_json::to_string(&info).map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+    }
+
+    /// Enable (or reconfigure) delay-based adaptive bitrate control on a
+    /// named encoder element, reusing the pipeline's QoS jitter samples as
+    /// its congestion signal (see `crate::gst::bitrate`).
+    async fn set_bitrate_limits(
+        &self,
+        encoder_name: &str,
+        min_bitrate_bps: u32,
+        max_bitrate_bps: u32,
+    ) -> zbus::fdo::Result<bool> {
+        self.manager
+            .set_bitrate_limits(&self.pipeline_id, encoder_name, min_bitrate_bps, max_bitrate_bps)
+            .await
+            .map(|_| true)
+            .map_err(|e| super::gpop_error_to_fdo(&e))
+    }
+
     #[zbus(property)]
     async fn streaming(&self) -> zbus::fdo::Result<bool> {
         let info = self
             .manager
             .get_pipeline_info(&self.pipeline_id)
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))?;
+            .map_err(|e| super::gpop_error_to_fdo(&e))?;
         Ok(info.streaming)
     }
 
@@ -144,6 +306,35 @@ impl PipelineInterface {
 
     #[zbus(signal)]
     async fn eos(emitter: &SignalEmitter<'_>) -> zbus::Result<()>;
+
+    /// Emitted when a playlist pipeline (see [`crate::gst::playlist`])
+    /// advances to a new entry on EOS instead of tearing down.
+    #[zbus(signal)]
+    async fn entry_changed(emitter: &SignalEmitter<'_>, index: u32, uri: &str) -> zbus::Result<()>;
+
+    /// Emitted when a playlist pipeline reaches EOS on its last entry with no
+    /// more iterations left to play.
+    #[zbus(signal)]
+    async fn playlist_ended(emitter: &SignalEmitter<'_>) -> zbus::Result<()>;
+
+    /// Emitted when the adaptive bitrate controller (see
+    /// `crate::gst::bitrate`) changes its target bitrate for an encoder in
+    /// response to observed congestion.
+    #[zbus(signal)]
+    async fn bitrate_changed(
+        emitter: &SignalEmitter<'_>,
+        encoder_name: &str,
+        bitrate_bps: u32,
+    ) -> zbus::Result<()>;
+
+    /// `position_ns`/`duration_ns` use -1 for "not available", matching
+    /// `get_position`.
+    #[zbus(signal)]
+    async fn progress(
+        emitter: &SignalEmitter<'_>,
+        position_ns: i64,
+        duration_ns: i64,
+    ) -> zbus::Result<()>;
 }
 
 impl PipelineInterface {
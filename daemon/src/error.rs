@@ -6,8 +6,25 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Coarse-grained severity for a JSON-RPC/D-Bus reply, so a client can
+/// decide whether to retry the same request, surface a user-facing error,
+/// or tear down the connection entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Severity {
+    /// The request succeeded.
+    Success,
+    /// A recoverable condition rooted in the request itself (unknown
+    /// pipeline id, bad state string, element that can't be linked). The
+    /// connection and daemon remain fully usable.
+    Failure,
+    /// A daemon-level condition where the manager or transport is no longer
+    /// usable; the client should not keep issuing requests on it.
+    Fatal,
+}
+
 #[derive(Error, Debug)]
 pub enum GpopError {
     #[error("GStreamer error: {0}")]
@@ -19,9 +36,21 @@ pub enum GpopError {
     #[error("Invalid pipeline description: {0}")]
     InvalidPipeline(String),
 
+    #[error("Pipeline description too long: {0}")]
+    DescriptionTooLong(String),
+
+    #[error("Media not supported: {0}")]
+    MediaNotSupported(String),
+
     #[error("State change failed: {0}")]
     StateChangeFailed(String),
 
+    #[error("Element not found: {0}")]
+    ElementNotFound(String),
+
+    #[error("Property not found: {0}")]
+    PropertyNotFound(String),
+
     #[cfg(target_os = "linux")]
     #[error("DBus error: {0}")]
     DBus(#[from] zbus::Error),
@@ -36,4 +65,70 @@ pub enum GpopError {
     Io(#[from] std::io::Error),
 }
 
+/// How long to suggest a client wait before retrying a retriable error.
+const DEFAULT_RETRY_AFTER_MS: u64 = 250;
+
+impl GpopError {
+    /// Whether reissuing the request that produced this error stands a
+    /// chance of succeeding. Borrowed from diem-client's error
+    /// classification: transient conditions (a state change racing a
+    /// pipeline still transitioning, a busy GStreamer resource, a dropped
+    /// transport) are retriable, while errors rooted in the request itself
+    /// (an unknown pipeline id, a malformed description, an unsupported
+    /// codec) will just fail the same way again.
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            GpopError::PipelineNotFound(_)
+            | GpopError::InvalidPipeline(_)
+            | GpopError::DescriptionTooLong(_)
+            | GpopError::MediaNotSupported(_)
+            | GpopError::ElementNotFound(_)
+            | GpopError::PropertyNotFound(_)
+            | GpopError::Json(_) => false,
+            GpopError::GStreamer(_)
+            | GpopError::StateChangeFailed(_)
+            | GpopError::WebSocket(_)
+            | GpopError::Io(_) => true,
+            #[cfg(target_os = "linux")]
+            GpopError::DBus(_) => true,
+        }
+    }
+
+    /// Suggested backoff before retrying, or `None` if this error isn't
+    /// retriable at all.
+    pub fn retry_after_ms(&self) -> Option<u64> {
+        self.is_retriable().then_some(DEFAULT_RETRY_AFTER_MS)
+    }
+
+    /// Classify this error as `Failure` (the request itself was bad, but the
+    /// daemon and connection are still fine) or `Fatal` (the manager or
+    /// transport the request went over is no longer usable).
+    ///
+    /// `StateChangeFailed` is a `Failure`: one transition was refused or
+    /// timed out, but the daemon and connection stay usable and the request
+    /// may be reissued (see [`Self::is_retriable`]). `GStreamer` is `Fatal`:
+    /// it represents the pipeline's bus posting an error out of its own
+    /// accord, not a bad parameter a caller passed in, so there's nothing a
+    /// client can fix by adjusting the request and retrying. Media the pipeline merely can't
+    /// play is never classified from here - it's caught earlier and reported
+    /// as `PipelineEvent::Unsupported` instead of a `GpopError` at all.
+    pub fn severity(&self) -> Severity {
+        match self {
+            GpopError::PipelineNotFound(_)
+            | GpopError::InvalidPipeline(_)
+            | GpopError::DescriptionTooLong(_)
+            | GpopError::MediaNotSupported(_)
+            | GpopError::ElementNotFound(_)
+            | GpopError::PropertyNotFound(_)
+            | GpopError::StateChangeFailed(_)
+            | GpopError::Json(_) => Severity::Failure,
+            GpopError::GStreamer(_)
+            | GpopError::WebSocket(_)
+            | GpopError::Io(_) => Severity::Fatal,
+            #[cfg(target_os = "linux")]
+            GpopError::DBus(_) => Severity::Fatal,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, GpopError>;
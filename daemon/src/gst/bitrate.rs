@@ -0,0 +1,190 @@
+// bitrate.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Delay-based adaptive bitrate control for streaming pipelines, modeled on
+//! the linear-regression flavour of Google Congestion Control (GCC): a
+//! least-squares trend fit over accumulated one-way delay classifies the
+//! link as overusing, underusing, or normal, and a small state machine turns
+//! that classification into bitrate adjustments on a named encoder.
+//!
+//! The regression-based slope is preferred over a raw per-sample gradient
+//! because it is far less sensitive to isolated delay spikes.
+
+use std::collections::VecDeque;
+
+/// Number of recent delay samples kept for the regression window.
+const DELAY_WINDOW_SIZE: usize = 60;
+
+/// Initial overuse/underuse threshold (γ), in the same units as the
+/// regression slope (ms of accumulated delay growth per ms of send time).
+const INITIAL_THRESHOLD: f64 = 12.5;
+
+/// Floor under the adaptive threshold so it can't collapse to zero and
+/// start classifying ordinary jitter as overuse.
+const MIN_THRESHOLD: f64 = 1.0;
+
+/// How fast γ is nudged toward the observed slope magnitude each sample.
+const THRESHOLD_ADAPT_RATE: f64 = 0.01;
+
+/// Multiplicative cut applied to the target bitrate on overuse.
+const DECREASE_FACTOR: f64 = 0.85;
+
+/// Multiplicative growth applied to the target bitrate per sample while the
+/// link is classified `Normal`.
+const INCREASE_FACTOR: f64 = 1.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Trend {
+    Overuse,
+    Underuse,
+    Normal,
+}
+
+/// Same `Increase`/`Hold`/`Decrease` shape as GCC's remote bitrate
+/// estimator: overuse always drops straight to `Decrease`; recovering from
+/// a decrease passes through `Hold` for one normal sample before resuming
+/// `Increase`, so a single noisy sample can't immediately undo a cut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ControllerState {
+    Increase,
+    Hold,
+    Decrease,
+}
+
+/// A delay-based adaptive bitrate controller for one streaming pipeline's
+/// encoder. Feed it `(send_time_ms, arrival_time_ms)` pairs as packet groups
+/// are observed (e.g. RTP sender/receiver feedback bucketed into ~5ms
+/// groups of send time); read back [`Self::target_bitrate_bps`] after each
+/// sample to decide whether to push a new value onto the encoder.
+#[derive(Debug, Clone)]
+pub struct BitrateController {
+    min_bitrate_bps: u32,
+    max_bitrate_bps: u32,
+    target_bitrate_bps: u32,
+    state: ControllerState,
+    threshold: f64,
+    /// Running sum of per-group delay variations `d(i) = arrival_delta -
+    /// send_delta` - the regression is fit against this accumulated signal,
+    /// not the raw per-group deltas, per the GCC algorithm.
+    accumulated_delay_ms: f64,
+    /// `(send_time_ms, accumulated_delay_ms)` samples, oldest first, capped
+    /// at `DELAY_WINDOW_SIZE`.
+    window: VecDeque<(f64, f64)>,
+    last_group_send_ms: Option<f64>,
+    last_group_arrival_ms: Option<f64>,
+}
+
+impl BitrateController {
+    pub fn new(min_bitrate_bps: u32, max_bitrate_bps: u32, initial_bitrate_bps: u32) -> Self {
+        Self {
+            min_bitrate_bps,
+            max_bitrate_bps,
+            target_bitrate_bps: initial_bitrate_bps.clamp(min_bitrate_bps, max_bitrate_bps),
+            state: ControllerState::Hold,
+            threshold: INITIAL_THRESHOLD,
+            accumulated_delay_ms: 0.0,
+            window: VecDeque::with_capacity(DELAY_WINDOW_SIZE),
+            last_group_send_ms: None,
+            last_group_arrival_ms: None,
+        }
+    }
+
+    pub fn set_bitrate_limits(&mut self, min_bitrate_bps: u32, max_bitrate_bps: u32) {
+        self.min_bitrate_bps = min_bitrate_bps;
+        self.max_bitrate_bps = max_bitrate_bps;
+        self.target_bitrate_bps = self.target_bitrate_bps.clamp(min_bitrate_bps, max_bitrate_bps);
+    }
+
+    pub fn target_bitrate_bps(&self) -> u32 {
+        self.target_bitrate_bps
+    }
+
+    /// Feed one packet-group's send/arrival timestamps (milliseconds since
+    /// an arbitrary but consistent epoch) and return the (possibly
+    /// unchanged) target bitrate after processing this sample.
+    pub fn on_packet_group(&mut self, send_time_ms: f64, arrival_time_ms: f64) -> u32 {
+        if let (Some(last_send), Some(last_arrival)) =
+            (self.last_group_send_ms, self.last_group_arrival_ms)
+        {
+            let send_delta = send_time_ms - last_send;
+            let arrival_delta = arrival_time_ms - last_arrival;
+            self.accumulated_delay_ms += arrival_delta - send_delta;
+
+            if self.window.len() == DELAY_WINDOW_SIZE {
+                self.window.pop_front();
+            }
+            self.window.push_back((send_time_ms, self.accumulated_delay_ms));
+
+            if let Some(slope) = self.regression_slope() {
+                self.update_threshold(slope);
+                let trend = self.classify(slope);
+                self.apply_trend(trend);
+            }
+        }
+
+        self.last_group_send_ms = Some(send_time_ms);
+        self.last_group_arrival_ms = Some(arrival_time_ms);
+        self.target_bitrate_bps
+    }
+
+    /// Least-squares slope of accumulated delay vs. send time:
+    /// `m = Σ((tₖ−t̄)(yₖ−ȳ)) / Σ((tₖ−t̄)²)`.
+    fn regression_slope(&self) -> Option<f64> {
+        let n = self.window.len();
+        if n < 2 {
+            return None;
+        }
+        let n_f = n as f64;
+        let mean_t = self.window.iter().map(|(t, _)| t).sum::<f64>() / n_f;
+        let mean_y = self.window.iter().map(|(_, y)| y).sum::<f64>() / n_f;
+
+        let (numerator, denominator) = self.window.iter().fold((0.0, 0.0), |(num, den), &(t, y)| {
+            let dt = t - mean_t;
+            (num + dt * (y - mean_y), den + dt * dt)
+        });
+
+        if denominator.abs() < f64::EPSILON {
+            None
+        } else {
+            Some(numerator / denominator)
+        }
+    }
+
+    /// Nudge γ toward the observed slope magnitude so the threshold tracks
+    /// the link's own baseline noise instead of needing hand-tuning.
+    fn update_threshold(&mut self, slope: f64) {
+        self.threshold += THRESHOLD_ADAPT_RATE * (slope.abs() - self.threshold);
+        self.threshold = self.threshold.max(MIN_THRESHOLD);
+    }
+
+    fn classify(&self, slope: f64) -> Trend {
+        if slope > self.threshold {
+            Trend::Overuse
+        } else if slope < -self.threshold {
+            Trend::Underuse
+        } else {
+            Trend::Normal
+        }
+    }
+
+    fn apply_trend(&mut self, trend: Trend) {
+        self.state = match (self.state, trend) {
+            (_, Trend::Overuse) => ControllerState::Decrease,
+            (ControllerState::Decrease, Trend::Normal) => ControllerState::Hold,
+            (_, Trend::Normal) => ControllerState::Increase,
+            (_, Trend::Underuse) => ControllerState::Hold,
+        };
+
+        self.target_bitrate_bps = match self.state {
+            ControllerState::Decrease => (self.target_bitrate_bps as f64 * DECREASE_FACTOR) as u32,
+            ControllerState::Increase => (self.target_bitrate_bps as f64 * INCREASE_FACTOR) as u32,
+            ControllerState::Hold => self.target_bitrate_bps,
+        }
+        .clamp(self.min_bitrate_bps, self.max_bitrate_bps);
+    }
+}
@@ -0,0 +1,70 @@
+// bitrate_tests.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use super::bitrate::*;
+
+#[test]
+fn test_new_clamps_initial_to_range() {
+    let controller = BitrateController::new(500_000, 2_000_000, 10_000_000);
+    assert_eq!(controller.target_bitrate_bps(), 2_000_000);
+
+    let controller = BitrateController::new(500_000, 2_000_000, 100);
+    assert_eq!(controller.target_bitrate_bps(), 500_000);
+}
+
+#[test]
+fn test_target_increases_when_delay_stays_flat() {
+    let mut controller = BitrateController::new(500_000, 5_000_000, 1_000_000);
+    let initial = controller.target_bitrate_bps();
+
+    // Packet groups 20ms apart with no delay variation: a perfectly
+    // on-time link, which should be classified `Normal` and ramp up.
+    let mut target = initial;
+    for i in 0..30 {
+        let t = (i * 20) as f64;
+        target = controller.on_packet_group(t, t);
+    }
+
+    assert!(target > initial, "expected target to grow, got {}", target);
+}
+
+#[test]
+fn test_target_decreases_under_growing_delay() {
+    let mut controller = BitrateController::new(500_000, 5_000_000, 2_000_000);
+    let initial = controller.target_bitrate_bps();
+
+    // Each group's arrival lags its send time by a growing amount: a
+    // steadily congesting link, which should be classified `Overuse`.
+    let mut target = initial;
+    for i in 0..30 {
+        let send = (i * 20) as f64;
+        let arrival = send + (i * 500) as f64;
+        target = controller.on_packet_group(send, arrival);
+    }
+
+    assert!(target < initial, "expected target to shrink, got {}", target);
+}
+
+#[test]
+fn test_target_never_leaves_configured_range() {
+    let mut controller = BitrateController::new(500_000, 5_000_000, 2_000_000);
+
+    for i in 0..100 {
+        let send = (i * 20) as f64;
+        let arrival = send + (i * 500) as f64;
+        let target = controller.on_packet_group(send, arrival);
+        assert!((500_000..=5_000_000).contains(&target));
+    }
+}
+
+#[test]
+fn test_set_bitrate_limits_clamps_current_target() {
+    let mut controller = BitrateController::new(500_000, 5_000_000, 4_000_000);
+    controller.set_bitrate_limits(500_000, 1_000_000);
+    assert_eq!(controller.target_bitrate_bps(), 1_000_000);
+}
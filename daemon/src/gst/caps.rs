@@ -0,0 +1,82 @@
+// caps.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Negotiated pad caps, serialized to JSON for UI consumption, plus RFC 6381
+//! MIME codec strings (`avc1.42c01e`, `hev1.1.6.L93.B0`, ...) so clients can
+//! build HLS/DASH manifests without parsing a DOT dump themselves.
+
+use gstreamer::prelude::*;
+use gstreamer::{self as gst};
+use serde_json::Value;
+
+use crate::gst::stats::structure_to_json;
+
+/// One pad's negotiated caps and derived codec identifier, as returned by
+/// [`crate::gst::manager::PipelineManager::get_streams`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PadStreamInfo {
+    pub element: String,
+    pub pad: String,
+    pub direction: String,
+    /// The pad's current (negotiated) caps as JSON, or `None` if the pad
+    /// hasn't negotiated caps yet.
+    pub caps: Option<Value>,
+    /// RFC 6381 MIME codec string, when `caps` describes a codec
+    /// `codec_utils_caps_get_mime_codec` recognizes (e.g. video/audio caps
+    /// with a `codec_data`/profile it can derive one from).
+    pub mime_codec: Option<String>,
+}
+
+/// Convert caps into JSON: each structure's fields plus its media type
+/// under `name`. Fixed caps (the common case for negotiated pad caps) carry
+/// exactly one structure and serialize to a single object; unfixed caps with
+/// several alternative structures serialize to an array of them.
+fn caps_to_json(caps: &gst::Caps) -> Value {
+    let structures: Vec<Value> = (0..caps.size())
+        .filter_map(|i| caps.structure(i))
+        .map(|s| {
+            let mut value = structure_to_json(s);
+            if let Value::Object(map) = &mut value {
+                map.insert("name".to_string(), Value::from(s.name()));
+            }
+            value
+        })
+        .collect();
+
+    match <[Value; 1]>::try_from(structures) {
+        Ok([single]) => single,
+        Err(structures) => Value::Array(structures),
+    }
+}
+
+/// Recursively collect every pad's negotiated caps (including pads inside
+/// nested bins), mirroring how [`super::pipeline::collect_elements`] walks
+/// the element tree.
+pub(crate) fn collect_pad_streams(element: &gst::Element, out: &mut Vec<PadStreamInfo>) {
+    for pad in element.pads() {
+        let caps = pad.current_caps();
+        let mime_codec = caps
+            .as_ref()
+            .and_then(|c| gstreamer_pbutils::functions::codec_utils_caps_get_mime_codec(c).ok())
+            .map(|s| s.to_string());
+
+        out.push(PadStreamInfo {
+            element: element.name().to_string(),
+            pad: pad.name().to_string(),
+            direction: format!("{:?}", pad.direction()).to_lowercase(),
+            caps: caps.as_ref().map(caps_to_json),
+            mime_codec,
+        });
+    }
+
+    if let Some(bin) = element.downcast_ref::<gst::Bin>() {
+        for child in bin.iterate_elements().into_iter().flatten() {
+            collect_pad_streams(&child, out);
+        }
+    }
+}
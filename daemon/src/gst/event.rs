@@ -8,6 +8,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::Severity;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PipelineState {
@@ -79,8 +81,22 @@ pub enum PipelineEvent {
         old_state: PipelineState,
         new_state: PipelineState,
     },
+    /// `severity` mirrors the `Response` envelope's: `Fatal` for a bus-level
+    /// error that leaves the pipeline unusable (the overwhelming majority),
+    /// `Failure` for one a caller could plausibly recover from. Media the
+    /// pipeline merely can't play surfaces as `Unsupported` instead of
+    /// `Error` in the first place - see [`PipelineEvent::Unsupported`].
     #[serde(rename = "error")]
-    Error { pipeline_id: String, message: String },
+    Error {
+        pipeline_id: String,
+        message: String,
+        severity: Severity,
+    },
+    /// The pipeline hit media it can't play (missing codec/demuxer, or an
+    /// error message matching [`crate::gst::pipeline::is_media_not_supported_error`])
+    /// rather than a generic pipeline error.
+    #[serde(rename = "unsupported")]
+    Unsupported { pipeline_id: String, message: String },
     #[serde(rename = "eos")]
     Eos { pipeline_id: String },
     #[serde(rename = "pipeline_added")]
@@ -95,6 +111,61 @@ pub enum PipelineEvent {
     },
     #[serde(rename = "pipeline_removed")]
     PipelineRemoved { pipeline_id: String },
+    #[serde(rename = "stats")]
+    Stats {
+        pipeline_id: String,
+        stats: serde_json::Value,
+    },
+    #[serde(rename = "progress")]
+    Progress {
+        pipeline_id: String,
+        position_ns: Option<u64>,
+        duration_ns: Option<u64>,
+        progress: Option<f64>,
+    },
+    #[serde(rename = "seek_done")]
+    SeekDone {
+        pipeline_id: String,
+        position_ns: Option<u64>,
+    },
+    #[serde(rename = "seek_failed")]
+    SeekFailed { pipeline_id: String, message: String },
+    /// A playlist pipeline (see [`crate::gst::playlist`]) advanced to a new
+    /// entry on EOS instead of tearing the pipeline down.
+    #[serde(rename = "entry_changed")]
+    EntryChanged {
+        pipeline_id: String,
+        index: usize,
+        uri: String,
+    },
+    /// A playlist pipeline reached EOS on its last entry with no more
+    /// iterations left to play.
+    #[serde(rename = "playlist_ended")]
+    PlaylistEnded { pipeline_id: String },
+    /// The adaptive bitrate controller (see [`crate::gst::bitrate`]) changed
+    /// its target for a streaming pipeline's encoder in response to
+    /// observed congestion.
+    #[serde(rename = "bitrate_changed")]
+    BitrateChanged {
+        pipeline_id: String,
+        encoder_name: String,
+        bitrate_bps: u32,
+    },
+    /// Synthetic, connection-scoped event pushed directly to a single
+    /// client (never broadcast) when its event delivery fell behind and
+    /// some notifications were dropped before it could be forwarded. Not
+    /// emitted by [`PipelineManager`](crate::gst::PipelineManager) itself;
+    /// the WebSocket/IPC server constructs it and follows it with a fresh
+    /// `list_pipelines`-equivalent snapshot so the client can rebuild its
+    /// view instead of silently diverging from server state.
+    #[serde(rename = "resync")]
+    Resync { dropped: usize },
+    /// A pipeline with a restart policy (see
+    /// [`crate::gst::manager::RestartPolicy`]) was torn down and rebuilt
+    /// from its stored description after an `Error`/`Eos`. `attempt` is
+    /// 1-based and counts toward that policy's `max_retries` budget.
+    #[serde(rename = "pipeline_restarted")]
+    PipelineRestarted { pipeline_id: String, attempt: u32 },
 }
 
 pub type EventSender = tokio::sync::broadcast::Sender<PipelineEvent>;
@@ -126,6 +126,7 @@ fn test_pipeline_event_serialize_error() {
     let event = PipelineEvent::Error {
         pipeline_id: "pipeline-0".to_string(),
         message: "Test error".to_string(),
+        severity: crate::error::Severity::Fatal,
     };
 
     let json = serde_json::to_string(&event).unwrap();
@@ -190,6 +191,27 @@ fn test_pipeline_event_serialize_pipeline_updated() {
     assert!(json.contains("\"description\":\"videotestsrc ! fakesink\""));
 }
 
+#[test]
+fn test_pipeline_event_serialize_resync() {
+    let event = PipelineEvent::Resync { dropped: 7 };
+
+    let json = serde_json::to_string(&event).unwrap();
+    assert!(json.contains("\"event\":\"resync\""));
+    assert!(json.contains("\"dropped\":7"));
+}
+
+#[test]
+fn test_pipeline_event_serialize_pipeline_restarted() {
+    let event = PipelineEvent::PipelineRestarted {
+        pipeline_id: "pipeline-0".to_string(),
+        attempt: 2,
+    };
+
+    let json = serde_json::to_string(&event).unwrap();
+    assert!(json.contains("\"event\":\"pipeline_restarted\""));
+    assert!(json.contains("\"attempt\":2"));
+}
+
 #[test]
 fn test_event_channel_creation() {
     let (tx, rx) = create_event_channel();
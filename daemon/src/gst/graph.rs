@@ -0,0 +1,243 @@
+// graph.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Structured, node/port graph pipeline construction, as an alternative to
+//! building up a `gst-launch` description by hand. Modeled on the node/port
+//! graph GstPipelineStudio edits: a [`GraphSpec`] is a set of [`NodeSpec`]s
+//! (an element factory name plus properties) and [`EdgeSpec`]s linking a
+//! named source pad on one node to a named sink pad on another.
+
+use std::collections::HashMap;
+
+use gstreamer::prelude::*;
+use gstreamer::{self as gst};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::error::{GpopError, Result};
+
+/// A property value settable on a graph node. GStreamer element properties
+/// are `glib::Value`s under the hood; only the primitive types a pipeline
+/// description would realistically carry are supported here - anything more
+/// exotic (boxed types, enums by numeric value, caps) should go through
+/// direct element access instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PropertyValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+}
+
+impl PropertyValue {
+    pub(crate) fn to_glib_value(&self) -> gst::glib::Value {
+        match self {
+            PropertyValue::Bool(b) => b.to_value(),
+            PropertyValue::Int(i) => i.to_value(),
+            PropertyValue::Float(f) => f.to_value(),
+            PropertyValue::String(s) => s.to_value(),
+        }
+    }
+
+    /// [`Self::to_glib_value`] converted to the type of `object`'s property
+    /// `name` where GLib can transform it (e.g. an `Int` into a `gint` or
+    /// `guint` property), since setting a property from a value of another
+    /// type panics. The value is returned unconverted if no such property
+    /// exists or no transform applies.
+    pub(crate) fn to_property_value(
+        &self,
+        object: &impl IsA<gst::glib::Object>,
+        name: &str,
+    ) -> gst::glib::Value {
+        let value = self.to_glib_value();
+        object
+            .find_property(name)
+            .and_then(|pspec| value.transform_with_type(pspec.value_type()).ok())
+            .unwrap_or(value)
+    }
+}
+
+/// A single element in a [`GraphSpec`]: an element factory name (e.g.
+/// `"filesrc"`, `"x264enc"`) plus the properties to set on it once created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeSpec {
+    /// Name used to address this node from an [`EdgeSpec`], and as the
+    /// created element's GStreamer object name.
+    pub name: String,
+    /// GStreamer element factory name, e.g. `"filesrc"`.
+    pub factory: String,
+    #[serde(default)]
+    pub properties: HashMap<String, PropertyValue>,
+}
+
+/// A link from a named source pad on one node to a named sink pad on
+/// another. `src_pad`/`sink_pad` name a pad template (e.g. `"src"`, or
+/// `"src_%u"` for a demuxer's request pads); `None` falls back to the
+/// element's default `"src"`/`"sink"` pad.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgeSpec {
+    pub from_node: String,
+    #[serde(default)]
+    pub src_pad: Option<String>,
+    pub to_node: String,
+    #[serde(default)]
+    pub sink_pad: Option<String>,
+}
+
+/// A pipeline described as a graph of nodes and the pad links between them,
+/// instead of a flat `gst-launch` string.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GraphSpec {
+    pub nodes: Vec<NodeSpec>,
+    pub edges: Vec<EdgeSpec>,
+}
+
+/// Build a `gst::Pipeline` from a [`GraphSpec`]: create each node's element
+/// via `ElementFactory::make`, apply its properties, add it to the
+/// pipeline bin, then link every edge.
+pub fn build_pipeline_from_graph(id: &str, spec: &GraphSpec) -> Result<gst::Pipeline> {
+    if spec.nodes.is_empty() {
+        return Err(GpopError::InvalidPipeline(
+            "Graph has no nodes".to_string(),
+        ));
+    }
+
+    let pipeline = gst::Pipeline::builder().name(id).build();
+    let mut elements: HashMap<String, gst::Element> = HashMap::with_capacity(spec.nodes.len());
+
+    for node in &spec.nodes {
+        let element = gst::ElementFactory::make(&node.factory)
+            .name(&node.name)
+            .build()
+            .map_err(|e| {
+                GpopError::InvalidPipeline(format!(
+                    "Failed to create node '{}' (factory '{}'): {}",
+                    node.name, node.factory, e
+                ))
+            })?;
+
+        for (prop, value) in &node.properties {
+            element.set_property_from_value(prop, &value.to_property_value(&element, prop));
+        }
+
+        pipeline.add(&element).map_err(|e| {
+            GpopError::InvalidPipeline(format!("Failed to add node '{}' to pipeline: {}", node.name, e))
+        })?;
+
+        elements.insert(node.name.clone(), element);
+    }
+
+    for edge in &spec.edges {
+        link_edge(&elements, edge)?;
+    }
+
+    Ok(pipeline)
+}
+
+/// Link one edge of a [`GraphSpec`]. The sink pad must already exist (or be
+/// requestable up front); the source pad may not - a demuxer's "sometimes"
+/// pads only appear once it has typefound the stream - in which case the
+/// link is deferred to the source element's `pad-added` signal.
+fn link_edge(elements: &HashMap<String, gst::Element>, edge: &EdgeSpec) -> Result<()> {
+    let src = elements.get(&edge.from_node).ok_or_else(|| {
+        GpopError::InvalidPipeline(format!("Edge references unknown node '{}'", edge.from_node))
+    })?;
+    let sink = elements.get(&edge.to_node).ok_or_else(|| {
+        GpopError::InvalidPipeline(format!("Edge references unknown node '{}'", edge.to_node))
+    })?;
+
+    link_elements(
+        &edge.from_node,
+        src,
+        edge.src_pad.as_deref(),
+        &edge.to_node,
+        sink,
+        edge.sink_pad.as_deref(),
+    )
+}
+
+/// Link a named (or default `"src"`/`"sink"`) pad on `src` to one on `sink`,
+/// shared by [`link_edge`] and `Pipeline::link` so both the declarative
+/// `GraphSpec` path and live incremental editing resolve "sometimes"/request
+/// pads the same way. `src_name`/`sink_name` are only used for error
+/// messages and the lazy-link log lines, since the elements themselves don't
+/// necessarily know the node names a caller addresses them by.
+pub(crate) fn link_elements(
+    src_name: &str,
+    src: &gst::Element,
+    src_pad: Option<&str>,
+    sink_name: &str,
+    sink: &gst::Element,
+    sink_pad: Option<&str>,
+) -> Result<()> {
+    let sink_pad_name = sink_pad.unwrap_or("sink");
+    let sink_pad = resolve_pad(sink, sink_pad_name).ok_or_else(|| {
+        GpopError::InvalidPipeline(format!("No sink pad '{}' on node '{}'", sink_pad_name, sink_name))
+    })?;
+
+    let src_pad_name = src_pad.unwrap_or("src");
+    if let Some(src_pad) = resolve_pad(src, src_pad_name) {
+        return src_pad.link(&sink_pad).map(|_| ()).map_err(|e| {
+            GpopError::InvalidPipeline(format!(
+                "Failed to link {}.{} -> {}.{}: {:?}",
+                src_name,
+                src_pad.name(),
+                sink_name,
+                sink_pad.name(),
+                e
+            ))
+        });
+    }
+
+    // The source pad doesn't exist yet ("sometimes" pad): link it the first
+    // time a matching pad shows up on this element instead.
+    let wanted_name = src_pad.map(str::to_string);
+    let from_node = src_name.to_string();
+    let to_node = sink_name.to_string();
+    src.connect_pad_added(move |_element, pad| {
+        if let Some(wanted) = &wanted_name {
+            if pad.name().as_str() != wanted.as_str() {
+                return;
+            }
+        }
+        if pad.is_linked() {
+            return;
+        }
+        match pad.link(&sink_pad) {
+            Ok(_) => debug!(
+                "Lazily linked {}.{} -> {}.{}",
+                from_node,
+                pad.name(),
+                to_node,
+                sink_pad.name()
+            ),
+            Err(e) => warn!(
+                "Failed to lazily link {}.{} -> {}.{}: {:?}",
+                from_node,
+                pad.name(),
+                to_node,
+                sink_pad.name(),
+                e
+            ),
+        }
+    });
+
+    Ok(())
+}
+
+/// Resolve a pad by name on `element`: an already-present ("always") pad if
+/// one matches, otherwise a request pad obtained from a matching request pad
+/// template (e.g. a muxer's `"sink_%u"`). Returns `None` if neither applies,
+/// which for a source pad means it's a "sometimes" pad that hasn't appeared
+/// yet and must be linked lazily instead.
+fn resolve_pad(element: &gst::Element, name: &str) -> Option<gst::Pad> {
+    element
+        .static_pad(name)
+        .or_else(|| element.request_pad_simple(name))
+}
@@ -0,0 +1,120 @@
+// graph_tests.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::collections::HashMap;
+
+use gstreamer::prelude::*;
+
+use super::graph::*;
+use crate::error::GpopError;
+
+fn init_gstreamer() {
+    let _ = gstreamer::init();
+}
+
+#[test]
+fn test_build_pipeline_from_graph_empty_nodes_fails() {
+    init_gstreamer();
+    let spec = GraphSpec::default();
+    let result = build_pipeline_from_graph("test", &spec);
+    assert!(result.is_err());
+    if let Err(GpopError::InvalidPipeline(msg)) = result {
+        assert!(msg.contains("no nodes"));
+    } else {
+        panic!("Expected InvalidPipeline error");
+    }
+}
+
+#[test]
+fn test_build_pipeline_from_graph_unknown_factory_fails() {
+    init_gstreamer();
+    let spec = GraphSpec {
+        nodes: vec![NodeSpec {
+            name: "src".to_string(),
+            factory: "not_a_real_element_xyz".to_string(),
+            properties: HashMap::new(),
+        }],
+        edges: vec![],
+    };
+
+    let result = build_pipeline_from_graph("test", &spec);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_build_pipeline_from_graph_links_always_pads() {
+    init_gstreamer();
+    let spec = GraphSpec {
+        nodes: vec![
+            NodeSpec {
+                name: "src".to_string(),
+                factory: "fakesrc".to_string(),
+                properties: HashMap::new(),
+            },
+            NodeSpec {
+                name: "sink".to_string(),
+                factory: "fakesink".to_string(),
+                properties: HashMap::new(),
+            },
+        ],
+        edges: vec![EdgeSpec {
+            from_node: "src".to_string(),
+            src_pad: None,
+            to_node: "sink".to_string(),
+            sink_pad: None,
+        }],
+    };
+
+    let pipeline = build_pipeline_from_graph("test", &spec).expect("graph should build");
+    assert_eq!(pipeline.children().len(), 2);
+}
+
+#[test]
+fn test_build_pipeline_from_graph_applies_properties() {
+    init_gstreamer();
+    let mut properties = HashMap::new();
+    properties.insert(
+        "num-buffers".to_string(),
+        PropertyValue::Int(7),
+    );
+    let spec = GraphSpec {
+        nodes: vec![NodeSpec {
+            name: "src".to_string(),
+            factory: "fakesrc".to_string(),
+            properties,
+        }],
+        edges: vec![],
+    };
+
+    let pipeline = build_pipeline_from_graph("test", &spec).expect("graph should build");
+    let element = pipeline
+        .by_name("src")
+        .expect("node should be added to the pipeline");
+    assert_eq!(element.property::<i32>("num-buffers"), 7);
+}
+
+#[test]
+fn test_build_pipeline_from_graph_unknown_edge_node_fails() {
+    init_gstreamer();
+    let spec = GraphSpec {
+        nodes: vec![NodeSpec {
+            name: "src".to_string(),
+            factory: "fakesrc".to_string(),
+            properties: HashMap::new(),
+        }],
+        edges: vec![EdgeSpec {
+            from_node: "src".to_string(),
+            src_pad: None,
+            to_node: "does_not_exist".to_string(),
+            sink_pad: None,
+        }],
+    };
+
+    let result = build_pipeline_from_graph("test", &spec);
+    assert!(result.is_err());
+}
@@ -6,16 +6,24 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+use gstreamer::{self as gst, prelude::*};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
 use tokio::sync::{Mutex, RwLock};
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
 use tracing::{info, warn};
 
 use super::{MAX_PIPELINES, SHUTDOWN_GRACE_PERIOD_MS};
 use crate::error::{GpopError, Result};
 use crate::gst::event::{EventSender, PipelineEvent, PipelineState};
-use crate::gst::pipeline::Pipeline;
+use crate::gst::graph::{EdgeSpec, GraphSpec, NodeSpec, PropertyValue};
+use crate::gst::caps::PadStreamInfo;
+use crate::gst::pipeline::{ElementInfo, Pipeline, PipelineStats, PropertyInfo};
+use crate::gst::playlist::{PlaylistMode, PlaylistState};
 
 pub struct PipelineInfo {
     pub id: String,
@@ -24,10 +32,84 @@ pub struct PipelineInfo {
     pub streaming: bool,
 }
 
+/// Why a [`PipelineManager::wait_for_eos`]/[`PipelineManager::wait_for_state`]
+/// call didn't resolve successfully.
+#[derive(Error, Debug)]
+pub enum WaitError {
+    #[error("pipeline '{0}' reported an error: {1}")]
+    PipelineError(String, String),
+    #[error("pipeline '{0}' is unsupported: {1}")]
+    Unsupported(String, String),
+    #[error("pipeline '{0}' was removed")]
+    PipelineRemoved(String),
+    #[error("timed out waiting for pipeline '{0}'")]
+    Timeout(String),
+    #[error("event channel closed before pipeline '{0}' resolved")]
+    ChannelClosed(String),
+}
+
+/// Maximum events a [`PipelineManager::batched_event_stream`] batch holds
+/// before it flushes early, even if its time window hasn't elapsed yet.
+const BATCHED_EVENT_STREAM_MAX: usize = 64;
+
+/// Collapse consecutive `StateChanged` events for the same `pipeline_id`
+/// within one batch down to just the latest state, leaving terminal events
+/// (`Eos`/`Error`/`Unsupported`/`PipelineRemoved`) untouched and in their
+/// original relative order.
+pub(crate) fn coalesce_state_changes(events: Vec<PipelineEvent>) -> Vec<PipelineEvent> {
+    let mut latest_state_changed: HashMap<String, usize> = HashMap::new();
+    let mut slots: Vec<Option<PipelineEvent>> = events.into_iter().map(Some).collect();
+
+    for i in 0..slots.len() {
+        if let Some(PipelineEvent::StateChanged { pipeline_id, .. }) = &slots[i] {
+            if let Some(previous) = latest_state_changed.insert(pipeline_id.clone(), i) {
+                slots[previous] = None;
+            }
+        }
+    }
+
+    slots.into_iter().flatten().collect()
+}
+
+/// Snapshot of a playlist pipeline's position, as returned by
+/// [`PipelineManager::get_playlist_info`].
+#[derive(serde::Serialize)]
+pub struct PlaylistInfo {
+    pub id: String,
+    pub state: PipelineState,
+    pub current_index: usize,
+    pub current_uri: String,
+    pub len: usize,
+    pub mode: PlaylistMode,
+    pub iterations: u32,
+}
+
+/// Automatic recovery configuration for a pipeline that errors or reaches
+/// EOS, set via [`PipelineManager::set_restart_policy`] and applied by
+/// [`start_restart_supervisor`]. Absent (the default, no entry in
+/// `PipelineManager`'s policy map) means the existing behavior: a dead
+/// pipeline just stays dead until a client intervenes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RestartPolicy {
+    pub on_error: bool,
+    pub on_eos: bool,
+    pub max_retries: u32,
+    pub backoff_ms: u64,
+}
+
 pub struct PipelineManager {
     pipelines: RwLock<HashMap<String, Arc<Mutex<Pipeline>>>>,
     event_tx: EventSender,
     next_id: AtomicU64,
+    /// Shared clock + base time for each named sync group, lazily created
+    /// the first time a pipeline joins it. Every pipeline in a group plays
+    /// against the same running time, so starting them a few milliseconds
+    /// apart (or restarting one later) doesn't leave it out of phase with
+    /// the others.
+    sync_groups: RwLock<HashMap<String, (gst::Clock, gst::ClockTime)>>,
+    /// Per-pipeline restart policies consulted by [`start_restart_supervisor`].
+    /// A missing entry means "never restart automatically".
+    restart_policies: RwLock<HashMap<String, RestartPolicy>>,
 }
 
 impl PipelineManager {
@@ -36,53 +118,78 @@ impl PipelineManager {
             pipelines: RwLock::new(HashMap::new()),
             event_tx,
             next_id: AtomicU64::new(0),
+            sync_groups: RwLock::new(HashMap::new()),
+            restart_policies: RwLock::new(HashMap::new()),
         }
     }
 
     pub async fn add_pipeline(&self, description: &str) -> Result<String> {
-        // Check pipeline limit before creating
-        {
-            let pipelines = self.pipelines.read().await;
-            if pipelines.len() >= MAX_PIPELINES {
-                return Err(GpopError::InvalidPipeline(format!(
-                    "Maximum number of pipelines ({}) reached",
-                    MAX_PIPELINES
-                )));
-            }
+        let id = self.reserve_id().await?;
+        let pipeline = Pipeline::new(id.clone(), description)?;
+        self.finish_add(id, pipeline, description.to_string()).await
+    }
+
+    /// Create a pipeline from a structured node/port graph instead of a
+    /// `gst-launch` description, e.g. for API clients assembling pipelines
+    /// programmatically. See [`crate::gst::graph::GraphSpec`].
+    pub async fn add_pipeline_from_graph(&self, spec: &GraphSpec) -> Result<String> {
+        let id = self.reserve_id().await?;
+        let pipeline = Pipeline::from_graph(id.clone(), spec)?;
+        let description = pipeline.description().to_string();
+        self.finish_add(id, pipeline, description).await
+    }
+
+    /// Allocate a fresh pipeline id, failing if `MAX_PIPELINES` is already
+    /// reached. Split out so both `add_pipeline` and
+    /// `add_pipeline_from_graph` validate the construction input (the
+    /// description/graph) before touching the pipeline table.
+    async fn reserve_id(&self) -> Result<String> {
+        let pipelines = self.pipelines.read().await;
+        if pipelines.len() >= MAX_PIPELINES {
+            return Err(GpopError::InvalidPipeline(format!(
+                "Maximum number of pipelines ({}) reached",
+                MAX_PIPELINES
+            )));
         }
+        drop(pipelines);
 
         // Use Relaxed ordering - we only need uniqueness, not synchronization
         // Using u64 makes overflow practically impossible (would take millions of years
         // at 1 billion pipelines per second)
         let id_num = self.next_id.fetch_add(1, Ordering::Relaxed);
+        Ok(id_num.to_string())
+    }
 
-        let id = id_num.to_string();
-
-        let pipeline = Pipeline::new(id.clone(), description)?;
+    /// Start the bus watcher for a freshly constructed pipeline, register it
+    /// under `id`, and publish the `PipelineAdded` event. Common tail shared
+    /// by `add_pipeline` and `add_pipeline_from_graph`.
+    async fn finish_add(&self, id: String, pipeline: Pipeline, description: String) -> Result<String> {
         let pipeline = Arc::new(Mutex::new(pipeline));
 
-        // Extract bus watch parameters synchronously to avoid race conditions
-        let (bus, shutdown_flag) = {
+        // Extract the bus synchronously to avoid race conditions
+        let (bus, seek_pending, progress, qos) = {
             let p = pipeline.lock().await;
             let bus = p
                 .bus()
                 .ok_or_else(|| GpopError::InvalidPipeline("Pipeline has no bus".to_string()))?;
-            (bus, p.shutdown_flag())
+            (bus, p.seek_pending(), p.progress_state(), p.qos_state())
         };
 
-        // Start bus watcher and get the task handle
-        let bus_task = Pipeline::start_bus_watch(
+        // Start the bus watcher and get its RAII guard
+        let bus_watch = Pipeline::start_bus_watch(
             bus,
             id.clone(),
             self.event_tx.clone(),
-            shutdown_flag,
             Arc::clone(&pipeline),
+            seek_pending,
+            progress,
+            qos,
         );
 
-        // Store the task handle synchronously
+        // Store the guard synchronously
         {
             let mut p = pipeline.lock().await;
-            p.set_bus_task(bus_task);
+            p.set_bus_watch(bus_watch);
         }
 
         {
@@ -96,7 +203,7 @@ impl PipelineManager {
             .event_tx
             .send(PipelineEvent::PipelineAdded {
                 pipeline_id: id.clone(),
-                description: description.to_string(),
+                description,
             })
             .is_err()
         {
@@ -106,10 +213,99 @@ impl PipelineManager {
         Ok(id)
     }
 
+    /// Create a pipeline that plays through `uris` gaplessly, advancing
+    /// automatically on EOS instead of being torn down and rebuilt between
+    /// entries (see [`crate::gst::playlist`]). `iterations` is how many
+    /// times the whole list repeats under `PlaylistMode::Finite` (ignored,
+    /// and the playlist loops forever, under `PlaylistMode::Infinite`).
+    pub async fn add_playlist(
+        &self,
+        uris: Vec<String>,
+        mode: PlaylistMode,
+        iterations: u32,
+    ) -> Result<String> {
+        let id = self.reserve_id().await?;
+        let playlist = PlaylistState::new(uris, mode, iterations)?;
+        let description = format!("playlist of {} entries", playlist.len());
+        let pipeline = Pipeline::new_playlist(id.clone(), playlist)?;
+        self.finish_add(id, pipeline, description).await
+    }
+
+    /// Snapshot of a playlist pipeline's current entry and position.
+    /// Fails with `GpopError::InvalidPipeline` if `id` isn't a playlist
+    /// pipeline (i.e. wasn't created via [`Self::add_playlist`]).
+    pub async fn get_playlist_info(&self, id: &str) -> Result<PlaylistInfo> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        let playlist = p
+            .playlist()
+            .ok_or_else(|| GpopError::InvalidPipeline(format!("pipeline '{}' is not a playlist", id)))?;
+
+        Ok(PlaylistInfo {
+            id: p.id().to_string(),
+            state: p.state(),
+            current_index: playlist.current_index(),
+            current_uri: playlist.current_uri().to_string(),
+            len: playlist.len(),
+            mode: playlist.mode(),
+            iterations: playlist.iterations(),
+        })
+    }
+
+    /// Manually advance a playlist pipeline to its next entry, the same
+    /// transition the bus watcher drives on EOS. Useful for a "skip" control
+    /// rather than waiting out the current entry. Emits
+    /// `PipelineEvent::EntryChanged`/`PlaylistEnded` exactly like the
+    /// automatic EOS path, and returns the `(index, uri)` now playing (or
+    /// `None` if the playlist has run through all of its iterations).
+    pub async fn playlist_next(&self, id: &str) -> Result<Option<(usize, String)>> {
+        let pipeline = self.get_pipeline(id).await?;
+        let mut p = pipeline.lock().await;
+        if p.playlist().is_none() {
+            return Err(GpopError::InvalidPipeline(format!(
+                "pipeline '{}' is not a playlist",
+                id
+            )));
+        }
+        let advanced = p.advance_playlist();
+        drop(p);
+
+        match &advanced {
+            Ok(Some((index, uri))) => {
+                let _ = self.event_tx.send(PipelineEvent::EntryChanged {
+                    pipeline_id: id.to_string(),
+                    index: *index,
+                    uri: uri.clone(),
+                });
+            }
+            Ok(None) => {
+                let _ = self.event_tx.send(PipelineEvent::PlaylistEnded {
+                    pipeline_id: id.to_string(),
+                });
+            }
+            Err(_) => {}
+        }
+
+        advanced
+    }
+
     pub async fn remove_pipeline(&self, id: &str) -> Result<()> {
         let mut pipelines = self.pipelines.write().await;
 
         if let Some(pipeline) = pipelines.remove(id) {
+            // Tear down the bus watch first: it holds a second `Arc` clone of
+            // this pipeline (see `finish_add`), so just dropping our own
+            // clone at the end of this function isn't enough to drop the
+            // pipeline - the watcher task's clone would keep it alive
+            // indefinitely. Dropping the guard here is what lets that task
+            // observe the shutdown signal and release its reference. Mirrors
+            // `shutdown`'s same two-step teardown.
+            {
+                let mut p = pipeline.lock().await;
+                p.stop_bus_watch();
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(SHUTDOWN_GRACE_PERIOD_MS)).await;
+
             {
                 let p = pipeline.lock().await;
                 p.stop()?;
@@ -218,6 +414,203 @@ impl PipelineManager {
         Ok(p.get_position())
     }
 
+    /// Seek a pipeline to an absolute position, in nanoseconds.
+    pub async fn seek(&self, id: &str, position_ns: u64, accurate: bool) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.seek(position_ns, accurate)
+    }
+
+    /// Seek a pipeline to an absolute position with explicit control over
+    /// flushing and an optional playback rate change, for scrubbing and
+    /// trick-mode UIs that want both in one request. See
+    /// [`Pipeline::seek_with_rate`] for clamping behaviour.
+    pub async fn seek_with_rate(
+        &self,
+        id: &str,
+        position_ns: u64,
+        flush: bool,
+        accurate: bool,
+        rate: Option<f64>,
+    ) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.seek_with_rate(position_ns, flush, accurate, rate)
+    }
+
+    /// Change a pipeline's playback rate for fast-forward/reverse trick-mode
+    /// playback.
+    pub async fn set_rate(&self, id: &str, rate: f64) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.set_rate(rate)
+    }
+
+    /// Step a pipeline forward by `frames` video frames while paused.
+    pub async fn step(&self, id: &str, frames: u64) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.step(frames)
+    }
+
+    /// Enable or disable the periodic `PipelineEvent::Progress` reporter for
+    /// a pipeline. `interval_ms` of `0` disables it.
+    pub async fn set_progress_reporting(&self, id: &str, interval_ms: u64) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        let interval = (interval_ms > 0).then(|| Duration::from_millis(interval_ms));
+        p.set_progress_interval(interval);
+        Ok(())
+    }
+
+    /// Get a pipeline's latest latency/QoS statistics (dropped frames,
+    /// jitter, congestion), as tracked from its bus messages.
+    pub async fn stats(&self, id: &str) -> Result<PipelineStats> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        Ok(p.stats())
+    }
+
+    /// List every element in a pipeline's tree.
+    pub async fn list_elements(&self, id: &str) -> Result<Vec<ElementInfo>> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        Ok(p.list_elements())
+    }
+
+    /// Negotiated caps (and RFC 6381 MIME codec, where derivable) of every
+    /// pad in a pipeline's tree.
+    pub async fn get_streams(&self, id: &str) -> Result<Vec<PadStreamInfo>> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        Ok(p.get_streams())
+    }
+
+    /// Add a single element to a running pipeline's graph without rebuilding
+    /// the whole thing. Only allowed while the pipeline is in `NULL`/`READY`.
+    pub async fn add_node(&self, id: &str, node: &NodeSpec) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.add_node(node)
+    }
+
+    /// Remove a named element from a pipeline's graph. Only allowed while
+    /// the pipeline is in `NULL`/`READY`.
+    pub async fn remove_node(&self, id: &str, element_name: &str) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.remove_node(element_name)
+    }
+
+    /// Link two nodes already present in a pipeline's graph. Only allowed
+    /// while the pipeline is in `NULL`/`READY`.
+    pub async fn link(&self, id: &str, edge: &EdgeSpec) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.link(edge)
+    }
+
+    /// Unlink two nodes in a pipeline's graph. Only allowed while the
+    /// pipeline is in `NULL`/`READY`.
+    pub async fn unlink(&self, id: &str, edge: &EdgeSpec) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.unlink(edge)
+    }
+
+    /// List every GObject property of a named element in a pipeline.
+    pub async fn element_properties(
+        &self,
+        id: &str,
+        element_name: &str,
+    ) -> Result<Vec<PropertyInfo>> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.element_properties(element_name)
+    }
+
+    /// List every GObject property of a named pad on a named element.
+    pub async fn pad_properties(
+        &self,
+        id: &str,
+        element_name: &str,
+        pad_name: &str,
+    ) -> Result<Vec<PropertyInfo>> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.pad_properties(element_name, pad_name)
+    }
+
+    /// Read a single property of a named element in a pipeline.
+    pub async fn get_element_property(
+        &self,
+        id: &str,
+        element_name: &str,
+        property_name: &str,
+    ) -> Result<serde_json::Value> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.get_property(element_name, property_name)
+    }
+
+    /// Set a single property of a named element in a pipeline.
+    pub async fn set_element_property(
+        &self,
+        id: &str,
+        element_name: &str,
+        property_name: &str,
+        value: &PropertyValue,
+    ) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.set_property(element_name, property_name, value)
+    }
+
+    /// Read a single property of a named pad on a named element.
+    pub async fn get_pad_property(
+        &self,
+        id: &str,
+        element_name: &str,
+        pad_name: &str,
+        property_name: &str,
+    ) -> Result<serde_json::Value> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.get_pad_property(element_name, pad_name, property_name)
+    }
+
+    /// Set a single property of a named pad on a named element.
+    pub async fn set_pad_property(
+        &self,
+        id: &str,
+        element_name: &str,
+        pad_name: &str,
+        property_name: &str,
+        value: &PropertyValue,
+    ) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let p = pipeline.lock().await;
+        p.set_pad_property(element_name, pad_name, property_name, value)
+    }
+
+    /// Enable (or reconfigure) delay-based adaptive bitrate control on a
+    /// named encoder element in a pipeline (see [`crate::gst::bitrate`]).
+    /// The controller reuses the pipeline's existing QoS jitter samples as
+    /// its congestion signal and pushes new targets onto the encoder's
+    /// `bitrate` property, emitting `PipelineEvent::BitrateChanged` whenever
+    /// the target actually moves.
+    pub async fn set_bitrate_limits(
+        &self,
+        id: &str,
+        encoder_name: &str,
+        min_bitrate_bps: u32,
+        max_bitrate_bps: u32,
+    ) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+        let mut p = pipeline.lock().await;
+        p.set_bitrate_limits(encoder_name, min_bitrate_bps, max_bitrate_bps)
+    }
+
     /// Update an existing pipeline with a new description.
     /// This stops the old pipeline, removes it, and creates a new one with the same ID.
     pub async fn update_pipeline(&self, id: &str, description: &str) -> Result<()> {
@@ -226,13 +619,13 @@ impl PipelineManager {
         let new_pipeline = Pipeline::new(id.to_string(), description)?;
         let new_pipeline = Arc::new(Mutex::new(new_pipeline));
 
-        // Extract bus watch parameters for the new pipeline
-        let (bus, shutdown_flag) = {
+        // Extract the bus for the new pipeline
+        let (bus, seek_pending, progress, qos) = {
             let p = new_pipeline.lock().await;
             let bus = p
                 .bus()
                 .ok_or_else(|| GpopError::InvalidPipeline("Pipeline has no bus".to_string()))?;
-            (bus, p.shutdown_flag())
+            (bus, p.seek_pending(), p.progress_state(), p.qos_state())
         };
 
         // Acquire write lock and perform atomic check-and-swap
@@ -247,25 +640,28 @@ impl PipelineManager {
         }
 
         // Start bus watcher for the new pipeline (after confirming old pipeline exists)
-        let bus_task = Pipeline::start_bus_watch(
+        let bus_watch = Pipeline::start_bus_watch(
             bus,
             id.to_string(),
             self.event_tx.clone(),
-            shutdown_flag,
             Arc::clone(&new_pipeline),
+            seek_pending,
+            progress,
+            qos,
         );
 
-        // Store the task handle
+        // Store the guard
         {
             let mut p = new_pipeline.lock().await;
-            p.set_bus_task(bus_task);
+            p.set_bus_watch(bus_watch);
         }
 
         // Stop and remove the old pipeline
         if let Some(old_pipeline) = pipelines.remove(id) {
-            let p = old_pipeline.lock().await;
+            let mut p = old_pipeline.lock().await;
+            p.stop_bus_watch();
             let _ = p.stop();
-            // Drop will clean up the bus task
+            // Drop will release the last reference once the lock above is gone
         }
 
         // Insert the new pipeline with the same ID
@@ -291,6 +687,181 @@ impl PipelineManager {
         Ok(())
     }
 
+    /// Set (or, with `None`, clear) the automatic restart policy for a
+    /// pipeline. Checked by [`start_restart_supervisor`] whenever that
+    /// pipeline reports `Error`/`Eos`; has no effect on its own until that
+    /// supervisor is running.
+    pub async fn set_restart_policy(&self, id: &str, policy: Option<RestartPolicy>) -> Result<()> {
+        // Verify the pipeline exists so a typo'd id doesn't silently
+        // register a policy that will never be consulted.
+        self.get_pipeline(id).await?;
+        let mut policies = self.restart_policies.write().await;
+        match policy {
+            Some(policy) => {
+                policies.insert(id.to_string(), policy);
+            }
+            None => {
+                policies.remove(id);
+            }
+        }
+        Ok(())
+    }
+
+    /// The restart policy currently registered for `id`, if any.
+    pub(crate) async fn restart_policy(&self, id: &str) -> Option<RestartPolicy> {
+        self.restart_policies.read().await.get(id).copied()
+    }
+
+    /// Bind a pipeline to a named sync group so it shares a clock and base
+    /// time with every other pipeline in that group, for sample-accurate
+    /// synchronized playback across independently-created pipelines. The
+    /// group's clock+base time is established by whichever pipeline joins
+    /// first; later joiners (and rejoins, e.g. after `update_pipeline`) pick
+    /// up the same pair.
+    pub async fn join_sync_group(&self, id: &str, group: &str) -> Result<()> {
+        let pipeline = self.get_pipeline(id).await?;
+
+        let (clock, base_time) = {
+            let mut groups = self.sync_groups.write().await;
+            if let Some((clock, base_time)) = groups.get(group) {
+                (clock.clone(), *base_time)
+            } else {
+                let clock = gst::SystemClock::obtain();
+                let base_time = clock.time().unwrap_or(gst::ClockTime::ZERO);
+                groups.insert(group.to_string(), (clock.clone(), base_time));
+                (clock, base_time)
+            }
+        };
+
+        {
+            let p = pipeline.lock().await;
+            p.set_sync_clock(&clock, base_time);
+        }
+
+        info!("Pipeline '{}' joined sync group '{}'", id, group);
+        Ok(())
+    }
+
+    /// Broadcast an event to subscribers. Exposed so subsystems outside
+    /// `PipelineManager` itself (e.g. the stats poller) can publish events
+    /// through the same channel as pipeline lifecycle events.
+    pub fn send_event(
+        &self,
+        event: PipelineEvent,
+    ) -> std::result::Result<usize, tokio::sync::broadcast::error::SendError<PipelineEvent>> {
+        self.event_tx.send(event)
+    }
+
+    /// Subscribe to the event bus as a [`Stream`] instead of a raw
+    /// [`tokio::sync::broadcast::Receiver`]. A subscriber that falls behind
+    /// and misses messages would normally see that surfaced as a `Lagged`
+    /// error on `recv()`; here it's transparently skipped instead, and the
+    /// stream simply ends once the sender side is dropped. Lets callers use
+    /// `tokio_stream::StreamExt` combinators (`filter`, `map`, `take`, ...)
+    /// instead of a hand-rolled `recv()`-in-a-loop.
+    pub fn event_stream(&self) -> impl Stream<Item = PipelineEvent> {
+        BroadcastStream::new(self.event_tx.subscribe()).filter_map(|event| event.ok())
+    }
+
+    /// Like `event_stream`, but groups events arriving within `window` into
+    /// a `Vec<PipelineEvent>`. A state transition (Null -> Ready -> Paused
+    /// -> Playing) floods the bus with `StateChanged` events in quick
+    /// succession; batches collapse consecutive ones for the same
+    /// `pipeline_id` down to just the latest state, so a UI subscriber gets
+    /// smooth, low-frequency updates instead of every intermediate step.
+    /// Terminal events (`Eos`/`Error`/`Unsupported`/`PipelineRemoved`) are
+    /// never collapsed and keep their relative order. A batch flushes when
+    /// `window` elapses or it reaches `BATCHED_EVENT_STREAM_MAX` events,
+    /// whichever comes first.
+    pub fn batched_event_stream(&self, window: Duration) -> impl Stream<Item = Vec<PipelineEvent>> {
+        self.event_stream()
+            .chunks_timeout(BATCHED_EVENT_STREAM_MAX, window)
+            .map(coalesce_state_changes)
+    }
+
+    /// Resolve once pipeline `id` reaches EOS, or reject as soon as it
+    /// errors, is found unsupported, or is removed - whichever happens
+    /// first - or when `timeout` elapses (waits forever if `timeout` is
+    /// `None`). Subscribes to the event bus rather than polling
+    /// `get_pipeline_info`, so callers get a clean
+    /// `manager.play(&id).await?; manager.wait_for_eos(&id, None).await?;`
+    /// flow instead of hand-rolling an event loop.
+    pub async fn wait_for_eos(
+        &self,
+        id: &str,
+        timeout: Option<Duration>,
+    ) -> std::result::Result<(), WaitError> {
+        self.wait_for(id, timeout, |event| match event {
+            PipelineEvent::Eos { pipeline_id } if pipeline_id == id => Some(Ok(())),
+            _ => Self::wait_failure(id, event),
+        })
+        .await
+    }
+
+    /// Resolve once pipeline `id` transitions to `state`, with the same
+    /// rejection/timeout semantics as `wait_for_eos`.
+    pub async fn wait_for_state(
+        &self,
+        id: &str,
+        state: PipelineState,
+        timeout: Option<Duration>,
+    ) -> std::result::Result<(), WaitError> {
+        self.wait_for(id, timeout, |event| match event {
+            PipelineEvent::StateChanged {
+                pipeline_id,
+                new_state,
+                ..
+            } if pipeline_id == id && *new_state == state => Some(Ok(())),
+            _ => Self::wait_failure(id, event),
+        })
+        .await
+    }
+
+    /// Shared driver for `wait_for_eos`/`wait_for_state`: poll the event
+    /// stream, handing each event to `matches` until it returns a verdict,
+    /// the stream ends, or `timeout` elapses.
+    async fn wait_for(
+        &self,
+        id: &str,
+        timeout: Option<Duration>,
+        matches: impl Fn(&PipelineEvent) -> Option<std::result::Result<(), WaitError>>,
+    ) -> std::result::Result<(), WaitError> {
+        let mut events = self.event_stream();
+        let wait = async {
+            while let Some(event) = events.next().await {
+                if let Some(result) = matches(&event) {
+                    return result;
+                }
+            }
+            Err(WaitError::ChannelClosed(id.to_string()))
+        };
+
+        match timeout {
+            Some(duration) => tokio::time::timeout(duration, wait)
+                .await
+                .unwrap_or_else(|_| Err(WaitError::Timeout(id.to_string()))),
+            None => wait.await,
+        }
+    }
+
+    /// Whether `event` reports a terminal failure for pipeline `id` that
+    /// `wait_for_eos`/`wait_for_state` should reject on. Returns `None` for
+    /// events unrelated to `id`, or that aren't failures.
+    fn wait_failure(id: &str, event: &PipelineEvent) -> Option<std::result::Result<(), WaitError>> {
+        match event {
+            PipelineEvent::Error { pipeline_id, message, .. } if pipeline_id == id => {
+                Some(Err(WaitError::PipelineError(id.to_string(), message.clone())))
+            }
+            PipelineEvent::Unsupported { pipeline_id, message } if pipeline_id == id => {
+                Some(Err(WaitError::Unsupported(id.to_string(), message.clone())))
+            }
+            PipelineEvent::PipelineRemoved { pipeline_id } if pipeline_id == id => {
+                Some(Err(WaitError::PipelineRemoved(id.to_string())))
+            }
+            _ => None,
+        }
+    }
+
     pub async fn shutdown(&self) {
         let pipelines_to_stop: Vec<_> = {
             let mut pipelines = self.pipelines.write().await;
@@ -298,12 +869,16 @@ impl PipelineManager {
         };
 
         for (id, pipeline) in pipelines_to_stop {
-            // Signal shutdown first (doesn't require lock as it uses atomic)
+            // Tear down the bus watch first: it holds a second `Arc` clone
+            // of this pipeline, so dropping its guard here is what lets the
+            // watcher task's own reference go away once it observes the
+            // shutdown signal.
             {
-                let p = pipeline.lock().await;
-                p.signal_shutdown();
+                let mut p = pipeline.lock().await;
+                p.stop_bus_watch();
             }
-            // Give bus watcher time to see the shutdown flag
+            // Give the watcher task a moment to actually unwind and drop
+            // its reference before we stop the pipeline out from under it.
             tokio::time::sleep(tokio::time::Duration::from_millis(SHUTDOWN_GRACE_PERIOD_MS)).await;
             // Now stop the pipeline
             {
@@ -314,3 +889,110 @@ impl PipelineManager {
         }
     }
 }
+
+/// Maximum exponent used for a restart policy's exponential backoff, so a
+/// pathologically large `max_retries` can't shift `backoff_ms` into an
+/// overflow panic.
+const RESTART_BACKOFF_MAX_SHIFT: u32 = 20;
+
+/// Watch `manager`'s event bus for `Error`/`Eos` on pipelines that carry a
+/// [`RestartPolicy`] and rebuild them in place (via [`PipelineManager::update_pipeline`]
+/// against their stored description) with exponential backoff, instead of
+/// leaving a dead or self-terminated pipeline registered forever. One task
+/// covers every pipeline on `manager`, since it is driven by the shared
+/// event stream rather than per-pipeline state; it is a no-op until a
+/// client calls [`PipelineManager::set_restart_policy`]. Emits
+/// `PipelineEvent::PipelineRestarted` on each successful rebuild, and a
+/// terminal `PipelineEvent::Error` once a pipeline's `max_retries` budget is
+/// exhausted.
+pub fn start_restart_supervisor(manager: Arc<PipelineManager>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let attempts: Arc<Mutex<HashMap<String, u32>>> = Arc::new(Mutex::new(HashMap::new()));
+        let mut events = Box::pin(manager.event_stream());
+
+        while let Some(event) = events.next().await {
+            let (pipeline_id, is_eos) = match &event {
+                PipelineEvent::Error { pipeline_id, .. } => (pipeline_id.clone(), false),
+                PipelineEvent::Eos { pipeline_id } => (pipeline_id.clone(), true),
+                _ => continue,
+            };
+
+            let Some(policy) = manager.restart_policy(&pipeline_id).await else {
+                continue;
+            };
+            if (is_eos && !policy.on_eos) || (!is_eos && !policy.on_error) {
+                continue;
+            }
+
+            let attempt = {
+                let mut attempts = attempts.lock().await;
+                let counter = attempts.entry(pipeline_id.clone()).or_insert(0);
+                *counter += 1;
+                *counter
+            };
+
+            if attempt > policy.max_retries {
+                warn!(
+                    "Pipeline '{}' exceeded its restart policy's max_retries ({}), giving up",
+                    pipeline_id, policy.max_retries
+                );
+                let _ = manager.send_event(PipelineEvent::Error {
+                    pipeline_id: pipeline_id.clone(),
+                    message: format!(
+                        "restart policy exhausted after {} attempts",
+                        policy.max_retries
+                    ),
+                    severity: crate::error::Severity::Fatal,
+                });
+                let _ = manager.set_restart_policy(&pipeline_id, None).await;
+                attempts.lock().await.remove(&pipeline_id);
+                continue;
+            }
+
+            // Back off and rebuild on its own task rather than inline in this
+            // loop: this is the single consumer of `manager`'s broadcast
+            // event stream, so sleeping here for pipeline A's backoff would
+            // stop draining the channel entirely - any other pipeline B's
+            // `Error`/`Eos` arriving meanwhile would sit unread until A's
+            // sleep finished, or get dropped as a `Lagged` gap if the
+            // channel filled up first, silently losing B's restart trigger.
+            let manager = Arc::clone(&manager);
+            let attempts = Arc::clone(&attempts);
+            tokio::spawn(async move {
+                let shift = (attempt - 1).min(RESTART_BACKOFF_MAX_SHIFT);
+                let backoff_ms = policy.backoff_ms.saturating_mul(1u64 << shift);
+                if backoff_ms > 0 {
+                    tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                }
+
+                let description = match manager.get_pipeline(&pipeline_id).await {
+                    Ok(pipeline) => pipeline.lock().await.description().to_string(),
+                    Err(_) => {
+                        // Removed out from under us while we were backing off.
+                        attempts.lock().await.remove(&pipeline_id);
+                        return;
+                    }
+                };
+
+                match manager.update_pipeline(&pipeline_id, &description).await {
+                    Ok(()) => {
+                        info!(
+                            "Restarted pipeline '{}' after {} (attempt {}/{})",
+                            pipeline_id,
+                            if is_eos { "eos" } else { "error" },
+                            attempt,
+                            policy.max_retries
+                        );
+                        let _ = manager.send_event(PipelineEvent::PipelineRestarted {
+                            pipeline_id: pipeline_id.clone(),
+                            attempt,
+                        });
+                    }
+                    Err(e) => {
+                        warn!("Failed to restart pipeline '{}': {}", pipeline_id, e);
+                    }
+                }
+            });
+        }
+    })
+}
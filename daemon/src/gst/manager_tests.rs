@@ -6,8 +6,11 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+use std::collections::HashMap;
+
 use super::manager::*;
 use crate::gst::event::{create_event_channel, PipelineEvent, PipelineState};
+use crate::gst::graph::{EdgeSpec, NodeSpec};
 
 fn init_gstreamer() {
     let _ = gstreamer::init();
@@ -336,4 +339,132 @@ async fn test_nonexistent_pipeline_errors() {
     assert!(manager.pause("nonexistent").await.is_err());
     assert!(manager.stop("nonexistent").await.is_err());
     assert!(manager.get_dot("nonexistent", None).await.is_err());
+    assert!(manager.seek("nonexistent", 0, true).await.is_err());
+    assert!(manager
+        .seek_with_rate("nonexistent", 0, true, true, Some(1.0))
+        .await
+        .is_err());
+    assert!(manager.set_rate("nonexistent", 1.0).await.is_err());
+    assert!(manager
+        .add_node(
+            "nonexistent",
+            &NodeSpec {
+                name: "n".to_string(),
+                factory: "identity".to_string(),
+                properties: HashMap::new(),
+            }
+        )
+        .await
+        .is_err());
+    assert!(manager.remove_node("nonexistent", "n").await.is_err());
+    assert!(manager
+        .link(
+            "nonexistent",
+            &EdgeSpec {
+                from_node: "a".to_string(),
+                src_pad: None,
+                to_node: "b".to_string(),
+                sink_pad: None,
+            }
+        )
+        .await
+        .is_err());
+    assert!(manager
+        .unlink(
+            "nonexistent",
+            &EdgeSpec {
+                from_node: "a".to_string(),
+                src_pad: None,
+                to_node: "b".to_string(),
+                sink_pad: None,
+            }
+        )
+        .await
+        .is_err());
+}
+
+#[test]
+fn test_coalesce_state_changes_keeps_only_latest_per_pipeline() {
+    let events = vec![
+        PipelineEvent::StateChanged {
+            pipeline_id: "1".to_string(),
+            old_state: PipelineState::Null,
+            new_state: PipelineState::Ready,
+        },
+        PipelineEvent::StateChanged {
+            pipeline_id: "2".to_string(),
+            old_state: PipelineState::Null,
+            new_state: PipelineState::Ready,
+        },
+        PipelineEvent::StateChanged {
+            pipeline_id: "1".to_string(),
+            old_state: PipelineState::Ready,
+            new_state: PipelineState::Paused,
+        },
+        PipelineEvent::StateChanged {
+            pipeline_id: "1".to_string(),
+            old_state: PipelineState::Paused,
+            new_state: PipelineState::Playing,
+        },
+    ];
+
+    let coalesced = coalesce_state_changes(events);
+
+    assert_eq!(coalesced.len(), 2);
+    match &coalesced[0] {
+        PipelineEvent::StateChanged {
+            pipeline_id,
+            new_state,
+            ..
+        } => {
+            assert_eq!(pipeline_id, "2");
+            assert_eq!(*new_state, PipelineState::Ready);
+        }
+        other => panic!("expected a StateChanged event, got {:?}", other),
+    }
+    match &coalesced[1] {
+        PipelineEvent::StateChanged {
+            pipeline_id,
+            new_state,
+            ..
+        } => {
+            assert_eq!(pipeline_id, "1");
+            assert_eq!(*new_state, PipelineState::Playing);
+        }
+        other => panic!("expected a StateChanged event, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_coalesce_state_changes_preserves_terminal_events() {
+    let events = vec![
+        PipelineEvent::StateChanged {
+            pipeline_id: "1".to_string(),
+            old_state: PipelineState::Null,
+            new_state: PipelineState::Ready,
+        },
+        PipelineEvent::StateChanged {
+            pipeline_id: "1".to_string(),
+            old_state: PipelineState::Ready,
+            new_state: PipelineState::Playing,
+        },
+        PipelineEvent::Eos {
+            pipeline_id: "1".to_string(),
+        },
+        PipelineEvent::PipelineRemoved {
+            pipeline_id: "2".to_string(),
+        },
+    ];
+
+    let coalesced = coalesce_state_changes(events);
+
+    assert_eq!(coalesced.len(), 3);
+    assert!(matches!(
+        &coalesced[0],
+        PipelineEvent::StateChanged { new_state: PipelineState::Playing, .. }
+    ));
+    assert!(matches!(&coalesced[1], PipelineEvent::Eos { pipeline_id } if pipeline_id == "1"));
+    assert!(
+        matches!(&coalesced[2], PipelineEvent::PipelineRemoved { pipeline_id } if pipeline_id == "2")
+    );
 }
@@ -6,13 +6,27 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+pub mod bitrate;
+pub mod caps;
 pub mod event;
+pub mod graph;
 pub mod manager;
 pub mod pipeline;
+pub mod playlist;
+pub mod stats;
 
+pub use bitrate::BitrateController;
+pub use caps::PadStreamInfo;
 pub use event::{create_event_channel, EventReceiver, EventSender, PipelineEvent, PipelineState};
-pub use manager::{PipelineInfo, PipelineManager};
-pub use pipeline::Pipeline;
+pub use graph::{build_pipeline_from_graph, EdgeSpec, GraphSpec, NodeSpec, PropertyValue};
+pub use manager::{
+    start_restart_supervisor, PipelineInfo, PipelineManager, PlaylistInfo, RestartPolicy,
+};
+pub use pipeline::{
+    BusWatchGuard, ElementInfo, Pipeline, PipelineStats, ProgressState, PropertyInfo, QosState,
+};
+pub use playlist::{PlaylistMode, PlaylistState};
+pub use stats::{collect_pipeline_stats, glib_value_to_json, start_stats_poller};
 
 /// Grace period in milliseconds to wait for bus watcher to shutdown
 pub const SHUTDOWN_GRACE_PERIOD_MS: u64 = 150;
@@ -20,9 +34,15 @@ pub const SHUTDOWN_GRACE_PERIOD_MS: u64 = 150;
 /// Maximum number of pipelines that can be created to prevent resource exhaustion
 pub const MAX_PIPELINES: usize = 100;
 
+#[cfg(test)]
+mod bitrate_tests;
+
 #[cfg(test)]
 mod event_tests;
 
+#[cfg(test)]
+mod graph_tests;
+
 #[cfg(test)]
 mod manager_tests;
 
@@ -31,3 +51,6 @@ mod pipeline_tests;
 
 #[cfg(test)]
 mod playback_mode_tests;
+
+#[cfg(test)]
+mod playlist_tests;
@@ -6,19 +6,130 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+use futures_util::StreamExt;
 use gstreamer::prelude::*;
 use gstreamer::{self as gst, DebugGraphDetails};
-use std::sync::atomic::{AtomicBool, Ordering};
+use gstreamer_pbutils::MissingPluginMessage;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::{oneshot, Mutex};
 use tracing::{debug, error, info, warn};
 
-use crate::error::{GpopError, Result};
+use crate::error::{GpopError, Result, Severity};
+use crate::gst::bitrate::BitrateController;
 use crate::gst::event::{EventSender, PipelineEvent, PipelineState};
+use crate::gst::graph::{
+    build_pipeline_from_graph, link_elements, EdgeSpec, GraphSpec, NodeSpec, PropertyValue,
+};
+use crate::gst::playlist::PlaylistState;
+use crate::gst::stats::glib_value_to_json;
+
+/// Metadata for one element in a pipeline's tree, as returned by
+/// [`Pipeline::list_elements`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ElementInfo {
+    pub name: String,
+    pub factory: String,
+}
+
+/// A single GObject property's metadata and value, as returned by
+/// [`Pipeline::element_properties`] and [`Pipeline::pad_properties`].
+/// `default_value`/`current_value` are `None` when the property's type has
+/// no JSON representation (see [`glib_value_to_json`]), not when the value
+/// itself is absent.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PropertyInfo {
+    pub name: String,
+    pub type_name: String,
+    pub readable: bool,
+    pub writable: bool,
+    /// Whether GStreamer allows setting this property while the element is
+    /// `PLAYING` (`GST_PARAM_CONTROLLABLE`), e.g. `volume` or `location` on
+    /// some sources - as opposed to properties only settable in `NULL`/`READY`.
+    pub controllable: bool,
+    pub default_value: Option<serde_json::Value>,
+    pub current_value: Option<serde_json::Value>,
+}
+
+/// Walk `object`'s GObject properties into a [`PropertyInfo`] per property.
+fn properties_of(object: &gst::glib::Object) -> Vec<PropertyInfo> {
+    object
+        .list_properties()
+        .iter()
+        .map(|pspec| {
+            let flags = pspec.flags();
+            let readable = flags.contains(gst::glib::ParamFlags::READABLE);
+            let current_value = readable
+                .then(|| object.property_value(pspec.name()))
+                .as_ref()
+                .and_then(glib_value_to_json);
+
+            PropertyInfo {
+                name: pspec.name().to_string(),
+                type_name: pspec.value_type().name().to_string(),
+                readable,
+                writable: flags.contains(gst::glib::ParamFlags::WRITABLE),
+                controllable: flags.contains(gst::PARAM_FLAG_CONTROLLABLE),
+                default_value: glib_value_to_json(&pspec.default_value()),
+                current_value,
+            }
+        })
+        .collect()
+}
+
+/// Read a single GObject property as JSON. Returns `PropertyNotFound` both
+/// when the property doesn't exist and when it has no JSON representation,
+/// since either way there's nothing to hand back to the caller.
+fn get_object_property(object: &gst::glib::Object, name: &str) -> Result<serde_json::Value> {
+    if object.find_property(name).is_none() {
+        return Err(GpopError::PropertyNotFound(name.to_string()));
+    }
+    glib_value_to_json(&object.property_value(name)).ok_or_else(|| {
+        GpopError::PropertyNotFound(format!("'{}' has no JSON-representable value", name))
+    })
+}
+
+/// Set a single GObject property from a [`PropertyValue`].
+fn set_object_property(object: &gst::glib::Object, name: &str, value: &PropertyValue) -> Result<()> {
+    if object.find_property(name).is_none() {
+        return Err(GpopError::PropertyNotFound(name.to_string()));
+    }
+    object.set_property_from_value(name, &value.to_property_value(object, name));
+    Ok(())
+}
+
+/// Recursively collect every element in a pipeline's tree (including
+/// elements inside nested bins) into `out`.
+fn collect_elements(element: &gst::Element, out: &mut Vec<ElementInfo>) {
+    out.push(ElementInfo {
+        name: element.name().to_string(),
+        factory: element
+            .factory()
+            .map(|f| f.name().to_string())
+            .unwrap_or_default(),
+    });
+
+    if let Some(bin) = element.downcast_ref::<gst::Bin>() {
+        for child in bin.iterate_elements().into_iter().flatten() {
+            collect_elements(&child, out);
+        }
+    }
+}
 
 /// Maximum length for pipeline descriptions to prevent memory exhaustion
 pub const MAX_PIPELINE_DESCRIPTION_LENGTH: usize = 64 * 1024; // 64KB
 
+/// Fraction of `duration_ns` that `position_ns` represents, clamped to
+/// `0.0..=1.0` (position can briefly exceed duration during seeks), or
+/// `None` if either is unknown or the duration is zero.
+pub fn position_progress(position_ns: Option<u64>, duration_ns: Option<u64>) -> Option<f64> {
+    match (position_ns, duration_ns) {
+        (Some(pos), Some(dur)) if dur > 0 => Some((pos as f64 / dur as f64).clamp(0.0, 1.0)),
+        _ => None,
+    }
+}
+
 /// Check if a GStreamer error indicates unsupported media (missing codec, format, etc.)
 /// Returns Some with a descriptive message if it's a media error, None otherwise.
 pub fn is_media_not_supported_error(error: &gst::glib::Error) -> Option<String> {
@@ -58,13 +169,195 @@ pub fn is_media_not_supported_error(error: &gst::glib::Error) -> Option<String>
 /// Timeout for state changes in seconds
 pub const STATE_CHANGE_TIMEOUT_SECS: u64 = 30;
 
+/// RAII guard for a pipeline's async bus-watch task. Holding this keeps the
+/// task (and the `BusStream` it owns) alive; dropping it `abort`s the task,
+/// and then drops `_shutdown_tx`, which also wakes the task's `select!` loop
+/// so it stops pulling from the bus should it still get to run. This replaces the old `AtomicBool` shutdown flag
+/// polled every 100ms plus a manual `task.abort()` call in `Pipeline::drop`.
+pub struct BusWatchGuard {
+    task: tokio::task::JoinHandle<()>,
+    _shutdown_tx: oneshot::Sender<()>,
+}
+
+impl Drop for BusWatchGuard {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Shared, lock-free configuration for a pipeline's opt-in progress
+/// reporter, read by the bus watcher on every loop iteration. `interval_ms`
+/// of `0` means reporting is disabled; `playing` is kept in sync with the
+/// pipeline's actual state by the bus watcher itself (on `StateChanged`,
+/// `Eos` and `Error`) so idle/paused/stopped pipelines incur no polling
+/// cost even while reporting is enabled.
+#[derive(Default)]
+pub struct ProgressState {
+    interval_ms: AtomicU64,
+    playing: AtomicBool,
+}
+
+impl ProgressState {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn interval(&self) -> Option<Duration> {
+        match self.interval_ms.load(Ordering::Acquire) {
+            0 => None,
+            ms => Some(Duration::from_millis(ms)),
+        }
+    }
+
+    fn set_interval(&self, interval: Option<Duration>) {
+        let ms = interval.map(|d| d.as_millis() as u64).unwrap_or(0);
+        self.interval_ms.store(ms, Ordering::Release);
+    }
+
+    fn is_playing(&self) -> bool {
+        self.playing.load(Ordering::Acquire)
+    }
+
+    fn set_playing(&self, playing: bool) {
+        self.playing.store(playing, Ordering::Release);
+    }
+}
+
+/// Number of recent QoS jitter samples kept for the congestion trend.
+const JITTER_WINDOW_SIZE: usize = 20;
+
+/// Minimum linear-regression slope (nanoseconds of jitter growth per second
+/// of running time) before a sustained trend is reported as congestion,
+/// rather than ordinary sample-to-sample noise.
+const CONGESTION_SLOPE_THRESHOLD_NS_PER_SEC: f64 = 1.0;
+
+/// Aggregated QoS/latency runtime metrics for a pipeline, as returned by
+/// [`Pipeline::stats`].
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct PipelineStats {
+    /// Total buffers processed, per the most recent QoS message for any
+    /// element (QoS counts are already cumulative per-element).
+    pub processed_frames: u64,
+    /// Total buffers dropped, same caveat as `processed_frames`.
+    pub dropped_frames: u64,
+    /// Current pipeline latency, from the last `Latency` bus message.
+    pub latency_ns: Option<u64>,
+    /// Most recent QoS jitter sample, in nanoseconds (negative means the
+    /// buffer arrived early relative to its running time).
+    pub jitter_ns: Option<i64>,
+    /// True when the sliding window of recent jitter samples has a
+    /// sustained positive slope - growing delay, a sign of congestion on a
+    /// live/network source - modeled the way GCC-style bandwidth
+    /// estimators track delay trend.
+    pub congestion: bool,
+}
+
+/// Mutable accumulator backing [`PipelineStats`], updated by the bus watcher
+/// as `Qos`/`Latency` messages arrive. Held behind a plain (non-async)
+/// `Mutex` since every access is a short, non-blocking read/update.
+#[derive(Default)]
+pub struct QosState {
+    processed_frames: u64,
+    dropped_frames: u64,
+    latency_ns: Option<u64>,
+    jitter_ns: Option<i64>,
+    /// `(running_time_secs, jitter_ns)` samples, oldest first, capped at
+    /// `JITTER_WINDOW_SIZE`.
+    jitter_window: std::collections::VecDeque<(f64, f64)>,
+}
+
+impl QosState {
+    fn record_qos(&mut self, running_time_secs: f64, jitter_ns: i64, processed: u64, dropped: u64) {
+        self.processed_frames = processed;
+        self.dropped_frames = dropped;
+        self.jitter_ns = Some(jitter_ns);
+
+        if self.jitter_window.len() == JITTER_WINDOW_SIZE {
+            self.jitter_window.pop_front();
+        }
+        self.jitter_window.push_back((running_time_secs, jitter_ns as f64));
+    }
+
+    fn record_latency(&mut self, latency_ns: u64) {
+        self.latency_ns = Some(latency_ns);
+    }
+
+    /// Least-squares slope of the jitter window: `(n*Σxy - Σx*Σy) / (n*Σx² - (Σx)²)`.
+    fn jitter_slope(&self) -> Option<f64> {
+        let n = self.jitter_window.len();
+        if n < 2 {
+            return None;
+        }
+        let n_f = n as f64;
+        let (sum_x, sum_y, sum_xy, sum_x2) = self.jitter_window.iter().fold(
+            (0.0, 0.0, 0.0, 0.0),
+            |(sx, sy, sxy, sx2), &(x, y)| (sx + x, sy + y, sxy + x * y, sx2 + x * x),
+        );
+        let denom = n_f * sum_x2 - sum_x * sum_x;
+        if denom.abs() < f64::EPSILON {
+            return None;
+        }
+        Some((n_f * sum_xy - sum_x * sum_y) / denom)
+    }
+
+    fn snapshot(&self) -> PipelineStats {
+        let congestion = self
+            .jitter_slope()
+            .map(|slope| slope > CONGESTION_SLOPE_THRESHOLD_NS_PER_SEC)
+            .unwrap_or(false);
+
+        PipelineStats {
+            processed_frames: self.processed_frames,
+            dropped_frames: self.dropped_frames,
+            latency_ns: self.latency_ns,
+            jitter_ns: self.jitter_ns,
+            congestion,
+        }
+    }
+}
+
+/// Query a pipeline's current latency via a `GST_QUERY_LATENCY`, returning
+/// the minimum latency in nanoseconds if the query succeeds.
+fn query_latency_ns(pipeline: &gst::Pipeline) -> Option<u64> {
+    let mut query = gst::query::Latency::new();
+    if pipeline.query(&mut query) {
+        let (_live, min, _max) = query.result();
+        Some(min.nseconds())
+    } else {
+        None
+    }
+}
+
 pub struct Pipeline {
     id: String,
     description: String,
     pipeline: gst::Pipeline,
-    bus_task: Option<tokio::task::JoinHandle<()>>,
-    /// Flag to signal the bus watcher to stop
-    shutdown_flag: Arc<AtomicBool>,
+    /// Owns the bus-watch task's lifetime. `None` until `set_bus_watch` is
+    /// called; dropping this (directly via `stop_bus_watch`, or implicitly
+    /// when the `Pipeline` itself is dropped) tears the watcher down via
+    /// `BusWatchGuard`'s `Drop` impl, with no separate shutdown call needed.
+    bus_watch: Option<BusWatchGuard>,
+    progress: Arc<ProgressState>,
+    qos: Arc<std::sync::Mutex<QosState>>,
+    /// Set by `seek`/`set_rate`/`step` right after the event is accepted,
+    /// and cleared by the bus watcher once the resulting async state change
+    /// settles, so the `AsyncDone`/`Error` bus messages it produces can be
+    /// reported as `PipelineEvent::SeekDone`/`SeekFailed` instead of being
+    /// mistaken for an unrelated preroll or pipeline error.
+    seek_pending: Arc<AtomicBool>,
+    /// Last rate applied via `set_rate` or an explicit `seek_with_rate`
+    /// rate, stored as the bit pattern of an `f64` so a plain `seek`'s
+    /// `rate: None` can resume at it instead of silently resetting trick-mode
+    /// playback back to normal speed. Starts at `1.0`.
+    current_rate: AtomicU64,
+    /// Present for pipelines created via [`Pipeline::new_playlist`]: tracks
+    /// the sequence of URIs being played and the current position, so the
+    /// bus watcher can advance on EOS instead of tearing the pipeline down.
+    playlist: Option<PlaylistState>,
+    /// Present once `set_bitrate_limits` has been called: the named encoder
+    /// element to push new bitrate targets to, and the controller tracking
+    /// congestion from the same QoS jitter samples that feed `qos` above.
+    bitrate: Option<(String, BitrateController)>,
 }
 
 impl Pipeline {
@@ -79,8 +372,8 @@ impl Pipeline {
 
         // Validate description length
         if description.len() > MAX_PIPELINE_DESCRIPTION_LENGTH {
-            return Err(GpopError::InvalidPipeline(format!(
-                "Pipeline description too long: {} bytes (max: {} bytes)",
+            return Err(GpopError::DescriptionTooLong(format!(
+                "{} bytes (max: {} bytes)",
                 description.len(),
                 MAX_PIPELINE_DESCRIPTION_LENGTH
             )));
@@ -107,79 +400,187 @@ impl Pipeline {
             id,
             description: description.to_string(),
             pipeline,
-            bus_task: None,
-            shutdown_flag: Arc::new(AtomicBool::new(false)),
+            bus_watch: None,
+            progress: Arc::new(ProgressState::new()),
+            qos: Arc::new(std::sync::Mutex::new(QosState::default())),
+            seek_pending: Arc::new(AtomicBool::new(false)),
+            current_rate: AtomicU64::new(1.0f64.to_bits()),
+            playlist: None,
+            bitrate: None,
+        })
+    }
+
+    /// Build a pipeline that plays through a sequence of URIs gaplessly,
+    /// advancing automatically on EOS instead of being torn down between
+    /// entries. Modeled on GStreamer's `uriplaylistbin`: under the hood this
+    /// is a single `playbin3` named `playlist_src`, whose `uri` property the
+    /// bus watcher swaps out (via [`Pipeline::advance_playlist`]) as the
+    /// playlist advances.
+    pub fn new_playlist(id: String, playlist: PlaylistState) -> Result<Self> {
+        let description = format!("playbin3 name=playlist_src uri=\"{}\"", playlist.current_uri());
+        let mut pipeline = Self::new(id, &description)?;
+        pipeline.playlist = Some(playlist);
+        Ok(pipeline)
+    }
+
+    /// Build a pipeline from a structured node/port graph instead of a
+    /// `gst-launch` description. See [`crate::gst::graph`] for the
+    /// `GraphSpec` shape.
+    pub fn from_graph(id: String, spec: &GraphSpec) -> Result<Self> {
+        let pipeline = build_pipeline_from_graph(&id, spec)?;
+
+        info!(
+            "Created pipeline '{}' from graph ({} nodes, {} edges)",
+            id,
+            spec.nodes.len(),
+            spec.edges.len()
+        );
+
+        Ok(Self {
+            id,
+            description: format!("<graph: {} nodes, {} edges>", spec.nodes.len(), spec.edges.len()),
+            pipeline,
+            bus_watch: None,
+            progress: Arc::new(ProgressState::new()),
+            qos: Arc::new(std::sync::Mutex::new(QosState::default())),
+            seek_pending: Arc::new(AtomicBool::new(false)),
+            current_rate: AtomicU64::new(1.0f64.to_bits()),
+            playlist: None,
+            bitrate: None,
         })
     }
 
     /// Start the bus watcher task for this pipeline.
-    /// The bus, pipeline ID, event sender, and shutdown flag are extracted synchronously
+    /// The bus, pipeline ID, and event sender are extracted synchronously
     /// before spawning to avoid race conditions with pipeline destruction.
+    /// Messages are delivered as soon as gstreamer-rs's `BusStream` wakes the
+    /// task, instead of the task having to wake up and poll for them itself
+    /// - no dedicated blocking thread, no up-to-100ms delivery latency.
+    /// This also means the per-pipeline task is purely reactive: with no
+    /// progress reporting enabled it never wakes on its own, so the timer
+    /// count a deployment pays for scales with the number of pipelines that
+    /// opted into progress reporting, not with the number of open pipelines.
     pub fn start_bus_watch(
         bus: gst::Bus,
         id: String,
         event_tx: EventSender,
-        shutdown_flag: Arc<AtomicBool>,
         pipeline: Arc<Mutex<Self>>,
-    ) -> tokio::task::JoinHandle<()> {
+        seek_pending: Arc<AtomicBool>,
+        progress: Arc<ProgressState>,
+        qos: Arc<std::sync::Mutex<QosState>>,
+    ) -> BusWatchGuard {
         let pipeline_clone = Arc::clone(&pipeline);
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+
+        let task = tokio::spawn(async move {
+            let mut messages = bus.stream();
+
+            // Rebuilt only when the configured interval actually changes, so
+            // a chatty bus doesn't keep resetting (and thus starving) the
+            // progress cadence.
+            let mut progress_ticker: Option<tokio::time::Interval> = None;
+            let mut progress_interval_ms = 0u64;
+
+            // Set once a `missing-plugin` element message has produced an
+            // `Unsupported` event for this stream, so the generic "no
+            // suitable plugins found" `Error` GStreamer posts right after it
+            // doesn't get sent too - we already reported the richer reason.
+            let mut unsupported_reported = false;
 
-        tokio::spawn(async move {
             loop {
-                // Check shutdown flag first (use Acquire to synchronize with Release store)
-                if shutdown_flag.load(Ordering::Acquire) {
-                    debug!("Bus watcher for pipeline '{}' received shutdown signal", id);
-                    break;
+                let configured = progress.interval().map(|d| d.as_millis() as u64).unwrap_or(0);
+                if configured != progress_interval_ms {
+                    progress_interval_ms = configured;
+                    progress_ticker = progress.interval().map(|interval| {
+                        let mut ticker = tokio::time::interval(interval);
+                        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                        ticker
+                    });
                 }
 
-                // Clone for use in spawn_blocking (bus is Send + Sync)
-                let bus_clone = bus.clone();
-                let shutdown_clone = Arc::clone(&shutdown_flag);
-
-                // Use spawn_blocking to avoid blocking the async runtime
-                let msg = match tokio::task::spawn_blocking(move || {
-                    // Check shutdown flag again inside blocking context
-                    if shutdown_clone.load(Ordering::Acquire) {
-                        return None;
+                let msg = tokio::select! {
+                    _ = &mut shutdown_rx => {
+                        debug!("Bus watcher for pipeline '{}' received shutdown signal", id);
+                        break;
                     }
-                    let timeout = gst::ClockTime::from_mseconds(100);
-                    bus_clone.timed_pop(timeout)
-                })
-                .await
-                {
-                    Ok(msg) => msg,
-                    Err(e) => {
-                        // spawn_blocking panicked or was cancelled - log and continue
-                        warn!(
-                            "Bus watcher spawn_blocking failed for pipeline '{}': {}",
-                            id, e
-                        );
+                    _ = async {
+                        match progress_ticker.as_mut() {
+                            Some(ticker) if progress.is_playing() => { ticker.tick().await; }
+                            _ => std::future::pending::<()>().await,
+                        }
+                    } => {
+                        let p = pipeline_clone.lock().await;
+                        let (position_ns, duration_ns) = p.get_position();
+                        drop(p);
+                        // Suppress the tick entirely during pre-roll (no
+                        // position yet) rather than sending `None` spam a
+                        // progress-bar client would have to filter out.
+                        if position_ns.is_some()
+                            && event_tx
+                                .send(PipelineEvent::Progress {
+                                    pipeline_id: id.clone(),
+                                    position_ns,
+                                    duration_ns,
+                                    progress: position_progress(position_ns, duration_ns),
+                                })
+                                .is_err()
+                        {
+                            warn!(
+                                "Failed to send progress event for pipeline '{}': no receivers",
+                                id
+                            );
+                        }
                         continue;
                     }
+                    msg = messages.next() => msg,
                 };
 
-                if let Some(msg) = msg {
-                    match msg.view() {
-                        gst::MessageView::Error(err) => {
-                            let gst_error = err.error();
-                            let error_msg =
-                                format!("{}: {}", gst_error, err.debug().unwrap_or_default());
-
-                            // Check if this is a media-related error
-                            let event = if is_media_not_supported_error(&gst_error).is_some() {
-                                warn!("Pipeline '{}' unsupported media: {}", id, error_msg);
-                                PipelineEvent::Unsupported {
-                                    pipeline_id: id.clone(),
-                                    message: error_msg,
-                                }
-                            } else {
-                                error!("Pipeline '{}' error: {}", id, error_msg);
-                                PipelineEvent::Error {
-                                    pipeline_id: id.clone(),
-                                    message: error_msg,
-                                }
-                            };
+                let Some(msg) = msg else {
+                    debug!("Bus watcher for pipeline '{}' stream closed", id);
+                    break;
+                };
 
+                match msg.view() {
+                    gst::MessageView::Error(err) => {
+                        progress.set_playing(false);
+                        let gst_error = err.error();
+                        let error_msg =
+                            format!("{}: {}", gst_error, err.debug().unwrap_or_default());
+
+                        // Check if this is a media-related error
+                        let event = if seek_pending.swap(false, Ordering::AcqRel) {
+                            warn!("Pipeline '{}' seek failed: {}", id, error_msg);
+                            Some(PipelineEvent::SeekFailed {
+                                pipeline_id: id.clone(),
+                                message: error_msg,
+                            })
+                        } else if unsupported_reported {
+                            // A missing-plugin element message already produced a
+                            // richer `Unsupported` event for this stream; this is
+                            // just the generic "no suitable plugins found" error
+                            // GStreamer posts right after it, and would be
+                            // redundant with what we already sent.
+                            debug!(
+                                "Pipeline '{}' suppressing error after missing-plugin: {}",
+                                id, error_msg
+                            );
+                            None
+                        } else if is_media_not_supported_error(&gst_error).is_some() {
+                            warn!("Pipeline '{}' unsupported media: {}", id, error_msg);
+                            Some(PipelineEvent::Unsupported {
+                                pipeline_id: id.clone(),
+                                message: error_msg,
+                            })
+                        } else {
+                            error!("Pipeline '{}' error: {}", id, error_msg);
+                            Some(PipelineEvent::Error {
+                                pipeline_id: id.clone(),
+                                message: error_msg,
+                                severity: Severity::Fatal,
+                            })
+                        };
+
+                        if let Some(event) = event {
                             if event_tx.send(event).is_err() {
                                 warn!(
                                     "Failed to send error event for pipeline '{}': no receivers",
@@ -187,57 +588,246 @@ impl Pipeline {
                                 );
                             }
                         }
-                        gst::MessageView::Warning(warning) => {
-                            warn!(
-                                "Pipeline '{}' warning: {}",
+                    }
+                    gst::MessageView::AsyncDone(_) => {
+                        if seek_pending.swap(false, Ordering::AcqRel) {
+                            let position = pipeline_clone.lock().await.get_position().0;
+                            debug!(
+                                "Pipeline '{}' seek settled at position {:?}",
+                                id, position
+                            );
+                            if event_tx
+                                .send(PipelineEvent::SeekDone {
+                                    pipeline_id: id.clone(),
+                                    position_ns: position,
+                                })
+                                .is_err()
+                            {
+                                warn!(
+                                    "Failed to send seek done event for pipeline '{}': no receivers",
+                                    id
+                                );
+                            }
+                        }
+                    }
+                    gst::MessageView::Warning(warning) => {
+                        warn!(
+                            "Pipeline '{}' warning: {}",
+                            id,
+                            warning.debug().unwrap_or_default()
+                        );
+                    }
+                    gst::MessageView::Qos(qos_msg) => {
+                        let (_live, running_time, _stream_time, _timestamp, _duration) =
+                            qos_msg.get();
+                        let (jitter, _proportion, _quality) = qos_msg.values();
+                        let (_format, processed, dropped) = qos_msg.stats();
+
+                        let running_time_secs = running_time
+                            .map(|t| t.nseconds() as f64 / 1_000_000_000.0)
+                            .unwrap_or(0.0);
+
+                        qos.lock()
+                            .unwrap()
+                            .record_qos(running_time_secs, jitter, processed, dropped);
+
+                        let mut p = pipeline_clone.lock().await;
+                        let changed = p.feed_bitrate_sample(running_time_secs, jitter);
+                        drop(p);
+
+                        match changed {
+                            Ok(Some((encoder_name, bitrate_bps))) => {
+                                if event_tx
+                                    .send(PipelineEvent::BitrateChanged {
+                                        pipeline_id: id.clone(),
+                                        encoder_name,
+                                        bitrate_bps,
+                                    })
+                                    .is_err()
+                                {
+                                    warn!(
+                                        "Failed to send bitrate_changed event for pipeline '{}': no receivers",
+                                        id
+                                    );
+                                }
+                            }
+                            Ok(None) => {}
+                            Err(e) => {
+                                warn!("Pipeline '{}' failed to apply adaptive bitrate: {}", id, e);
+                            }
+                        }
+                    }
+                    gst::MessageView::Latency(_) => {
+                        let p = pipeline_clone.lock().await;
+                        if let Some(latency_ns) = query_latency_ns(&p.pipeline) {
+                            drop(p);
+                            qos.lock().unwrap().record_latency(latency_ns);
+                        }
+                    }
+                    gst::MessageView::Element(element_msg) => {
+                        let structure_name = element_msg
+                            .structure()
+                            .map(|s| s.name().to_string())
+                            .unwrap_or_default();
+
+                        if structure_name == "missing-plugin" {
+                            // GStreamer posts this when an element/decoder/
+                            // demuxer/etc. the pipeline needs isn't installed.
+                            // It's followed by a generic "no suitable plugins
+                            // found" `Error`, which `unsupported_reported`
+                            // suppresses in favor of this richer description.
+                            let description = MissingPluginMessage::parse(&msg)
+                                .map(|m| m.description().to_string())
+                                .unwrap_or_else(|_| structure_name.clone());
+
+                            warn!("Pipeline '{}' missing plugin: {}", id, description);
+                            unsupported_reported = true;
+                            progress.set_playing(false);
+
+                            if event_tx
+                                .send(PipelineEvent::Unsupported {
+                                    pipeline_id: id.clone(),
+                                    message: description,
+                                })
+                                .is_err()
+                            {
+                                warn!(
+                                    "Failed to send unsupported event for pipeline '{}': no receivers",
+                                    id
+                                );
+                            }
+                        } else {
+                            // Application/element-specific messages have no
+                            // common schema across plugins, so we just note
+                            // we've seen one rather than guessing at a shape.
+                            debug!(
+                                "Pipeline '{}' element message from '{}': {}",
                                 id,
-                                warning.debug().unwrap_or_default()
+                                element_msg.src().map(|s| s.name()).unwrap_or_default(),
+                                structure_name
                             );
                         }
-                        gst::MessageView::Eos(_) => {
-                            info!("Pipeline '{}' reached end of stream", id);
+                    }
+                    gst::MessageView::Eos(_) => {
+                        let mut p = pipeline_clone.lock().await;
+                        let is_playlist = p.playlist().is_some();
+
+                        // A single entry's URI can be bad (missing file, dead
+                        // host, ...), in which case `advance_playlist` errors
+                        // out on that one entry rather than the whole
+                        // session: report it as `Unsupported` and keep
+                        // skipping forward until an entry plays or the
+                        // playlist is exhausted.
+                        let mut advanced = if is_playlist { p.advance_playlist() } else { Ok(None) };
+                        while let Err(e) = &advanced {
+                            warn!(
+                                "Pipeline '{}' skipping unplayable playlist entry: {}",
+                                id, e
+                            );
                             if event_tx
-                                .send(PipelineEvent::Eos {
+                                .send(PipelineEvent::Unsupported {
                                     pipeline_id: id.clone(),
+                                    message: e.to_string(),
                                 })
                                 .is_err()
                             {
                                 warn!(
-                                    "Failed to send EOS event for pipeline '{}': no receivers",
+                                    "Failed to send unsupported event for pipeline '{}': no receivers",
                                     id
                                 );
                             }
+                            advanced = p.advance_playlist();
+                        }
+                        drop(p);
+
+                        match advanced {
+                            Ok(Some((index, uri))) => {
+                                info!(
+                                    "Pipeline '{}' playlist advancing to entry {} ({})",
+                                    id, index, uri
+                                );
+                                if event_tx
+                                    .send(PipelineEvent::EntryChanged {
+                                        pipeline_id: id.clone(),
+                                        index,
+                                        uri,
+                                    })
+                                    .is_err()
+                                {
+                                    warn!(
+                                        "Failed to send entry_changed event for pipeline '{}': no receivers",
+                                        id
+                                    );
+                                }
+                            }
+                            Ok(None) if is_playlist => {
+                                progress.set_playing(false);
+                                info!("Pipeline '{}' playlist ended", id);
+                                if event_tx
+                                    .send(PipelineEvent::PlaylistEnded {
+                                        pipeline_id: id.clone(),
+                                    })
+                                    .is_err()
+                                {
+                                    warn!(
+                                        "Failed to send playlist_ended event for pipeline '{}': no receivers",
+                                        id
+                                    );
+                                }
+                            }
+                            Ok(None) => {
+                                progress.set_playing(false);
+                                info!("Pipeline '{}' reached end of stream", id);
+                                if event_tx
+                                    .send(PipelineEvent::Eos {
+                                        pipeline_id: id.clone(),
+                                    })
+                                    .is_err()
+                                {
+                                    warn!(
+                                        "Failed to send EOS event for pipeline '{}': no receivers",
+                                        id
+                                    );
+                                }
+                            }
+                            Err(_) => unreachable!("the while loop above only exits on Ok"),
                         }
-                        gst::MessageView::StateChanged(state_changed) => {
-                            if let Some(src) = msg.src() {
-                                let p = pipeline_clone.lock().await;
-                                if src == p.pipeline.upcast_ref::<gst::Object>() {
-                                    let old = PipelineState::from(state_changed.old());
-                                    let new = PipelineState::from(state_changed.current());
-                                    debug!("Pipeline '{}' state changed: {} -> {}", id, old, new);
-                                    if event_tx
-                                        .send(PipelineEvent::StateChanged {
-                                            pipeline_id: id.clone(),
-                                            old_state: old,
-                                            new_state: new,
-                                        })
-                                        .is_err()
-                                    {
-                                        warn!(
-                                            "Failed to send state change event for pipeline '{}': no receivers",
-                                            id
-                                        );
-                                    }
+                    }
+                    gst::MessageView::StateChanged(state_changed) => {
+                        if let Some(src) = msg.src() {
+                            let p = pipeline_clone.lock().await;
+                            if src == p.pipeline.upcast_ref::<gst::Object>() {
+                                let old = PipelineState::from(state_changed.old());
+                                let new = PipelineState::from(state_changed.current());
+                                progress.set_playing(matches!(new, PipelineState::Playing));
+                                debug!("Pipeline '{}' state changed: {} -> {}", id, old, new);
+                                if event_tx
+                                    .send(PipelineEvent::StateChanged {
+                                        pipeline_id: id.clone(),
+                                        old_state: old,
+                                        new_state: new,
+                                    })
+                                    .is_err()
+                                {
+                                    warn!(
+                                        "Failed to send state change event for pipeline '{}': no receivers",
+                                        id
+                                    );
                                 }
                             }
                         }
-                        _ => {}
                     }
+                    _ => {}
                 }
             }
 
             debug!("Bus watcher for pipeline '{}' stopped", id);
-        })
+        });
+
+        BusWatchGuard {
+            task,
+            _shutdown_tx: shutdown_tx,
+        }
     }
 
     /// Get the GStreamer bus for this pipeline
@@ -245,14 +835,47 @@ impl Pipeline {
         self.pipeline.bus()
     }
 
-    /// Set the bus task handle
-    pub fn set_bus_task(&mut self, task: tokio::task::JoinHandle<()>) {
-        self.bus_task = Some(task);
+    /// Clone of the flag `seek`/`set_rate`/`step` set to mark the next
+    /// `AsyncDone`/`Error` bus message as the outcome of a trick-mode
+    /// request, for the bus watcher to consume. Needed because
+    /// `start_bus_watch` takes its own `Arc` clone of this pipeline and runs
+    /// on a separate task, so it can't read `self.seek_pending` directly.
+    pub fn seek_pending(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.seek_pending)
+    }
+
+    /// Clone of this pipeline's progress-reporter config, for the bus
+    /// watcher to read on its own task (see `seek_pending` for why this is
+    /// a shared handle rather than a direct field access).
+    pub fn progress_state(&self) -> Arc<ProgressState> {
+        Arc::clone(&self.progress)
+    }
+
+    /// Enable (`Some(interval)`) or disable (`None`) the opt-in progress
+    /// reporter. Takes effect on the bus watcher's next loop iteration, and
+    /// only produces events while the pipeline is actually `Playing`.
+    pub fn set_progress_interval(&self, interval: Option<Duration>) {
+        self.progress.set_interval(interval);
     }
 
-    /// Get the shutdown flag
-    pub fn shutdown_flag(&self) -> Arc<AtomicBool> {
-        Arc::clone(&self.shutdown_flag)
+    /// Clone of this pipeline's QoS accumulator, for the bus watcher to
+    /// update from its own task (see `seek_pending` for why this is a
+    /// shared handle rather than a direct field access).
+    pub fn qos_state(&self) -> Arc<std::sync::Mutex<QosState>> {
+        Arc::clone(&self.qos)
+    }
+
+    /// Snapshot of this pipeline's aggregated QoS/latency/jitter metrics,
+    /// built from `Qos` and `Latency` bus messages observed so far.
+    pub fn stats(&self) -> PipelineStats {
+        self.qos.lock().unwrap().snapshot()
+    }
+
+    /// Store the bus-watch guard returned by `start_bus_watch`, so it lives
+    /// as long as this pipeline and is dropped (tearing down the watch)
+    /// together with it.
+    pub fn set_bus_watch(&mut self, guard: BusWatchGuard) {
+        self.bus_watch = Some(guard);
     }
 
     pub fn id(&self) -> &str {
@@ -348,9 +971,457 @@ impl Pipeline {
         (position, duration)
     }
 
+    /// Seek to an absolute position. `accurate` selects `SeekFlags::ACCURATE`
+    /// (exact but possibly slower, e.g. for scrubbing to a precise frame)
+    /// over the default `SeekFlags::KEY_UNIT` (snap to the nearest
+    /// keyframe). Completion/failure is reported asynchronously as a
+    /// `PipelineEvent::SeekDone`/`SeekFailed` once the bus watcher observes
+    /// the resulting state settle.
+    pub fn seek(&self, position_ns: u64, accurate: bool) -> Result<()> {
+        self.seek_with_rate(position_ns, true, accurate, None)
+    }
+
+    /// Seek to an absolute position with full control over flushing and
+    /// playback rate in one go. `position_ns` is clamped to `[0, duration]`
+    /// when the duration is known, so an out-of-range target (e.g. past
+    /// EOS) degrades to a clamp instead of a rejected seek. `rate` combines
+    /// the position change with a [`set_rate`](Self::set_rate)-style trick
+    /// mode change in a single seek event; `None` keeps the last rate
+    /// applied via `set_rate` or a prior `seek_with_rate` call (`1.0` if
+    /// neither has ever been called).
+    pub fn seek_with_rate(
+        &self,
+        position_ns: u64,
+        flush: bool,
+        accurate: bool,
+        rate: Option<f64>,
+    ) -> Result<()> {
+        let duration_ns = self
+            .pipeline
+            .query_duration::<gst::ClockTime>()
+            .map(|d| d.nseconds());
+        let clamped_ns = match duration_ns {
+            Some(duration_ns) => position_ns.min(duration_ns),
+            None => position_ns,
+        };
+        let target = gst::ClockTime::from_nseconds(clamped_ns);
+
+        let mut flags = if accurate {
+            gst::SeekFlags::ACCURATE
+        } else {
+            gst::SeekFlags::KEY_UNIT
+        };
+        if flush {
+            flags |= gst::SeekFlags::FLUSH;
+        }
+
+        let rate = rate.unwrap_or_else(|| f64::from_bits(self.current_rate.load(Ordering::Acquire)));
+        let seek_event = if rate >= 0.0 {
+            gst::event::Seek::new(
+                rate,
+                flags,
+                gst::SeekType::Set,
+                target,
+                gst::SeekType::None,
+                gst::ClockTime::NONE,
+            )
+        } else {
+            // Reverse playback: play from the start up to the target
+            // position, with the negative rate making it run backward.
+            gst::event::Seek::new(
+                rate,
+                flags,
+                gst::SeekType::Set,
+                gst::ClockTime::ZERO,
+                gst::SeekType::Set,
+                target,
+            )
+        };
+
+        if !self.pipeline.send_event(seek_event) {
+            return Err(GpopError::StateChangeFailed(format!(
+                "Seek failed for pipeline '{}'",
+                self.id
+            )));
+        }
+
+        self.current_rate.store(rate.to_bits(), Ordering::Release);
+        self.seek_pending.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    /// Change the playback rate for fast-forward/reverse trick-mode
+    /// playback. A negative `rate` plays backward. Built on a full seek
+    /// event that preserves the pipeline's current position, as recommended
+    /// by the GStreamer seeking docs for rate changes.
+    pub fn set_rate(&self, rate: f64) -> Result<()> {
+        let position = self.pipeline.query_position::<gst::ClockTime>().ok_or_else(|| {
+            GpopError::StateChangeFailed(format!(
+                "Failed to query position for pipeline '{}'",
+                self.id
+            ))
+        })?;
+
+        let flags = gst::SeekFlags::FLUSH | gst::SeekFlags::KEY_UNIT;
+        let seek_event = if rate >= 0.0 {
+            gst::event::Seek::new(
+                rate,
+                flags,
+                gst::SeekType::Set,
+                position,
+                gst::SeekType::None,
+                gst::ClockTime::NONE,
+            )
+        } else {
+            // Reverse playback: play from the start up to the current
+            // position, with the negative rate making it run backward.
+            gst::event::Seek::new(
+                rate,
+                flags,
+                gst::SeekType::Set,
+                gst::ClockTime::ZERO,
+                gst::SeekType::Set,
+                position,
+            )
+        };
+
+        if !self.pipeline.send_event(seek_event) {
+            return Err(GpopError::StateChangeFailed(format!(
+                "Failed to set rate {} for pipeline '{}'",
+                rate, self.id
+            )));
+        }
+
+        self.current_rate.store(rate.to_bits(), Ordering::Release);
+        self.seek_pending.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    /// Step `frames` video frames forward while paused, for frame-accurate
+    /// scrubbing.
+    pub fn step(&self, frames: u64) -> Result<()> {
+        let step_event = gst::event::Step::new(gst::format::Buffers(frames), 1.0, true, false);
+
+        if !self.pipeline.send_event(step_event) {
+            return Err(GpopError::StateChangeFailed(format!(
+                "Step failed for pipeline '{}'",
+                self.id
+            )));
+        }
+
+        self.seek_pending.store(true, Ordering::Release);
+        Ok(())
+    }
+
     /// Signal the bus watcher to stop
-    pub fn signal_shutdown(&self) {
-        self.shutdown_flag.store(true, Ordering::Release);
+    /// Proactively tear down the bus watch - stopping its task and dropping
+    /// the bus stream - without waiting for the whole `Pipeline` to be
+    /// dropped. Needed because the bus-watch task itself holds a second
+    /// `Arc` clone of this pipeline (to look up its own state on
+    /// `StateChanged`), so `Pipeline`'s own `Drop` only runs once the task
+    /// has already exited.
+    pub fn stop_bus_watch(&mut self) {
+        self.bus_watch = None;
+    }
+
+    /// Get the underlying GStreamer pipeline object, for subsystems (e.g.
+    /// the stats poller) that need direct element access.
+    pub fn gst_pipeline(&self) -> &gst::Pipeline {
+        &self.pipeline
+    }
+
+    /// Bind this pipeline to a shared clock and base time so its running
+    /// time stays aligned with every other pipeline in the same sync group,
+    /// instead of each free-running on its own internally-selected clock
+    /// with an independent base time. `set_start_time(NONE)` stops the next
+    /// state change from overwriting the base time we just set.
+    pub fn set_sync_clock(&self, clock: &gst::Clock, base_time: gst::ClockTime) {
+        self.pipeline.use_clock(Some(clock));
+        self.pipeline.set_start_time(gst::ClockTime::NONE);
+        self.pipeline.set_base_time(base_time);
+    }
+
+    /// List every element in this pipeline's tree, recursing into bins.
+    pub fn list_elements(&self) -> Vec<ElementInfo> {
+        let mut out = Vec::new();
+        collect_elements(self.pipeline.upcast_ref::<gst::Element>(), &mut out);
+        out
+    }
+
+    /// Negotiated caps of every pad in the pipeline's tree, with an RFC 6381
+    /// MIME codec string for pads whose caps describe one. See
+    /// [`crate::gst::caps`].
+    pub fn get_streams(&self) -> Vec<crate::gst::caps::PadStreamInfo> {
+        let mut out = Vec::new();
+        crate::gst::caps::collect_pad_streams(self.pipeline.upcast_ref::<gst::Element>(), &mut out);
+        out
+    }
+
+    /// Reject a live-editing call (`add_node`/`remove_node`/`link`/`unlink`)
+    /// unless the pipeline is stopped. `NULL`/`READY` are the only states
+    /// GStreamer allows adding/removing elements or linking/unlinking pads
+    /// in without first blocking the affected pads, which these simple
+    /// incremental-edit methods don't attempt - a caller wanting to edit a
+    /// running or paused graph needs to stop it first.
+    fn require_editable(&self) -> Result<()> {
+        match self.state() {
+            PipelineState::Null | PipelineState::Ready => Ok(()),
+            state => Err(GpopError::InvalidPipeline(format!(
+                "Cannot edit pipeline '{}' while in state {} - stop it first",
+                self.id, state
+            ))),
+        }
+    }
+
+    /// Add a single element to this pipeline from a [`NodeSpec`], for
+    /// incremental graph editing instead of rebuilding the whole pipeline.
+    /// Only allowed in `NULL`/`READY`; see [`Pipeline::require_editable`].
+    pub fn add_node(&self, node: &NodeSpec) -> Result<()> {
+        self.require_editable()?;
+
+        let element = gst::ElementFactory::make(&node.factory)
+            .name(&node.name)
+            .build()
+            .map_err(|e| {
+                GpopError::InvalidPipeline(format!(
+                    "Failed to create node '{}' (factory '{}'): {}",
+                    node.name, node.factory, e
+                ))
+            })?;
+
+        for (prop, value) in &node.properties {
+            element.set_property_from_value(prop, &value.to_property_value(&element, prop));
+        }
+
+        self.pipeline.add(&element).map_err(|e| {
+            GpopError::InvalidPipeline(format!("Failed to add node '{}' to pipeline: {}", node.name, e))
+        })
+    }
+
+    /// Remove a named element from this pipeline, setting it to `NULL`
+    /// first so any pads it holds are unlinked cleanly. Only allowed in
+    /// `NULL`/`READY`; see [`Pipeline::require_editable`].
+    pub fn remove_node(&self, element_name: &str) -> Result<()> {
+        self.require_editable()?;
+
+        let element = self.find_element(element_name)?;
+        element.set_state(gst::State::Null).map_err(|e| {
+            GpopError::InvalidPipeline(format!(
+                "Failed to stop node '{}' before removal: {}",
+                element_name, e
+            ))
+        })?;
+        self.pipeline.remove(&element).map_err(|e| {
+            GpopError::InvalidPipeline(format!(
+                "Failed to remove node '{}' from pipeline: {}",
+                element_name, e
+            ))
+        })
+    }
+
+    /// Link two already-added nodes per an [`EdgeSpec`]. Only allowed in
+    /// `NULL`/`READY`; see [`Pipeline::require_editable`].
+    pub fn link(&self, edge: &EdgeSpec) -> Result<()> {
+        self.require_editable()?;
+
+        let src = self.find_element(&edge.from_node)?;
+        let sink = self.find_element(&edge.to_node)?;
+        link_elements(
+            &edge.from_node,
+            &src,
+            edge.src_pad.as_deref(),
+            &edge.to_node,
+            &sink,
+            edge.sink_pad.as_deref(),
+        )
+    }
+
+    /// Unlink two nodes per an [`EdgeSpec`]. Only allowed in `NULL`/`READY`;
+    /// see [`Pipeline::require_editable`].
+    pub fn unlink(&self, edge: &EdgeSpec) -> Result<()> {
+        self.require_editable()?;
+
+        let src_pad_name = edge.src_pad.as_deref().unwrap_or("src");
+        let sink_pad_name = edge.sink_pad.as_deref().unwrap_or("sink");
+        let src_pad = self.find_pad(&edge.from_node, src_pad_name)?;
+        let sink_pad = self.find_pad(&edge.to_node, sink_pad_name)?;
+
+        src_pad.unlink(&sink_pad).map_err(|e| {
+            GpopError::InvalidPipeline(format!(
+                "Failed to unlink {}.{} -> {}.{}: {:?}",
+                edge.from_node, src_pad_name, edge.to_node, sink_pad_name, e
+            ))
+        })
+    }
+
+    /// Find an element anywhere in the pipeline's tree by its object name.
+    fn find_element(&self, element_name: &str) -> Result<gst::Element> {
+        self.pipeline
+            .by_name(element_name)
+            .ok_or_else(|| GpopError::ElementNotFound(element_name.to_string()))
+    }
+
+    /// Find a pad on a named element. Looks up a static pad first, falling
+    /// back to an already-requested "sometimes"/request pad with that name;
+    /// a request pad that hasn't been created yet (e.g. before linking) is
+    /// not visible here.
+    fn find_pad(&self, element_name: &str, pad_name: &str) -> Result<gst::Pad> {
+        let element = self.find_element(element_name)?;
+        element
+            .static_pad(pad_name)
+            .or_else(|| element.pads().into_iter().find(|p| p.name() == pad_name))
+            .ok_or_else(|| {
+                GpopError::PropertyNotFound(format!(
+                    "pad '{}' on element '{}'",
+                    pad_name, element_name
+                ))
+            })
+    }
+
+    /// List every GObject property of a named element, with its metadata
+    /// and current value.
+    pub fn element_properties(&self, element_name: &str) -> Result<Vec<PropertyInfo>> {
+        let element = self.find_element(element_name)?;
+        Ok(properties_of(element.upcast_ref::<gst::glib::Object>()))
+    }
+
+    /// List every GObject property of a named pad on a named element.
+    pub fn pad_properties(&self, element_name: &str, pad_name: &str) -> Result<Vec<PropertyInfo>> {
+        let pad = self.find_pad(element_name, pad_name)?;
+        Ok(properties_of(pad.upcast_ref::<gst::glib::Object>()))
+    }
+
+    /// Read a single property of a named element.
+    pub fn get_property(&self, element_name: &str, property_name: &str) -> Result<serde_json::Value> {
+        let element = self.find_element(element_name)?;
+        get_object_property(element.upcast_ref::<gst::glib::Object>(), property_name)
+    }
+
+    /// Set a single property of a named element.
+    pub fn set_property(
+        &self,
+        element_name: &str,
+        property_name: &str,
+        value: &PropertyValue,
+    ) -> Result<()> {
+        let element = self.find_element(element_name)?;
+        set_object_property(element.upcast_ref::<gst::glib::Object>(), property_name, value)
+    }
+
+    /// Read a single property of a named pad on a named element.
+    pub fn get_pad_property(
+        &self,
+        element_name: &str,
+        pad_name: &str,
+        property_name: &str,
+    ) -> Result<serde_json::Value> {
+        let pad = self.find_pad(element_name, pad_name)?;
+        get_object_property(pad.upcast_ref::<gst::glib::Object>(), property_name)
+    }
+
+    /// Set a single property of a named pad on a named element.
+    pub fn set_pad_property(
+        &self,
+        element_name: &str,
+        pad_name: &str,
+        property_name: &str,
+        value: &PropertyValue,
+    ) -> Result<()> {
+        let pad = self.find_pad(element_name, pad_name)?;
+        set_object_property(pad.upcast_ref::<gst::glib::Object>(), property_name, value)
+    }
+
+    /// This pipeline's playlist state, if it was created via
+    /// [`Pipeline::new_playlist`].
+    pub fn playlist(&self) -> Option<&PlaylistState> {
+        self.playlist.as_ref()
+    }
+
+    /// Advance this pipeline's playlist (if any) past the entry that just
+    /// reached EOS, swapping `playlist_src`'s `uri` property to the next
+    /// entry and cycling the pipeline back through READY to PLAYING instead
+    /// of tearing it down, mirroring how `uriplaylistbin` advances gaplessly
+    /// between entries.
+    ///
+    /// Returns the `(index, uri)` now playing, or `None` if this pipeline
+    /// has no playlist, or the playlist has run through all of its
+    /// configured iterations.
+    pub fn advance_playlist(&mut self) -> Result<Option<(usize, String)>> {
+        let Some(playlist) = self.playlist.as_mut() else {
+            return Ok(None);
+        };
+
+        let Some(next_uri) = playlist.advance() else {
+            return Ok(None);
+        };
+
+        self.set_property("playlist_src", "uri", &PropertyValue::String(next_uri.clone()))?;
+        self.pipeline
+            .set_state(gst::State::Ready)
+            .map_err(|e| GpopError::InvalidPipeline(e.to_string()))?;
+        self.pipeline
+            .set_state(gst::State::Playing)
+            .map_err(|e| GpopError::InvalidPipeline(e.to_string()))?;
+
+        let index = self.playlist.as_ref().expect("checked above").current_index();
+        Ok(Some((index, next_uri)))
+    }
+
+    /// Enable (or reconfigure) delay-based adaptive bitrate control, pushing
+    /// target bitrates onto the named encoder element's `bitrate` property
+    /// as congestion is observed. Reconfiguring an already-enabled encoder
+    /// keeps the controller's current target and trend state; switching to
+    /// a different encoder starts a fresh controller at `min_bitrate_bps`.
+    pub fn set_bitrate_limits(
+        &mut self,
+        encoder_name: &str,
+        min_bitrate_bps: u32,
+        max_bitrate_bps: u32,
+    ) -> Result<()> {
+        self.find_element(encoder_name)?;
+
+        match &mut self.bitrate {
+            Some((name, controller)) if name == encoder_name => {
+                controller.set_bitrate_limits(min_bitrate_bps, max_bitrate_bps);
+            }
+            _ => {
+                self.bitrate = Some((
+                    encoder_name.to_string(),
+                    BitrateController::new(min_bitrate_bps, max_bitrate_bps, min_bitrate_bps),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Feed one QoS-derived delay sample into the bitrate controller (if
+    /// enabled via [`Pipeline::set_bitrate_limits`]), reusing the same
+    /// `(running_time, jitter)` pair that feeds `QosState`'s congestion
+    /// trend: `running_time_secs` stands in for when the frame was sent,
+    /// and `running_time_secs + jitter` for when it was judged to have
+    /// arrived. Returns the encoder name and new target bitrate if the
+    /// controller's target changed, after pushing it onto the encoder.
+    pub fn feed_bitrate_sample(
+        &mut self,
+        running_time_secs: f64,
+        jitter_ns: i64,
+    ) -> Result<Option<(String, u32)>> {
+        let Some((encoder_name, controller)) = self.bitrate.as_mut() else {
+            return Ok(None);
+        };
+
+        let send_time_ms = running_time_secs * 1000.0;
+        let arrival_time_ms = send_time_ms + (jitter_ns as f64 / 1_000_000.0);
+
+        let previous = controller.target_bitrate_bps();
+        let updated = controller.on_packet_group(send_time_ms, arrival_time_ms);
+        if updated == previous {
+            return Ok(None);
+        }
+
+        let encoder_name = encoder_name.clone();
+        self.set_property(&encoder_name, "bitrate", &PropertyValue::Int(updated as i64))?;
+        Ok(Some((encoder_name, updated)))
     }
 }
 
@@ -358,15 +1429,10 @@ impl Drop for Pipeline {
     fn drop(&mut self) {
         debug!("Dropping pipeline '{}'", self.id);
 
-        // Signal bus watcher to stop (use Release to synchronize with Acquire load)
-        self.shutdown_flag.store(true, Ordering::Release);
-
         // Set pipeline to Null state
         let _ = self.pipeline.set_state(gst::State::Null);
 
-        // Abort the bus task if it exists
-        if let Some(task) = self.bus_task.take() {
-            task.abort();
-        }
+        // `bus_watch`, if still set, is torn down right after this by its
+        // own field drop (see `BusWatchGuard`'s `Drop` impl).
     }
 }
@@ -6,8 +6,11 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+use std::collections::HashMap;
+
 use super::pipeline::*;
 use crate::error::GpopError;
+use crate::gst::graph::{EdgeSpec, NodeSpec};
 
 fn init_gstreamer() {
     let _ = gstreamer::init();
@@ -205,13 +208,109 @@ fn test_pipeline_state_changes() {
 // =============================================================================
 
 #[test]
-fn test_pipeline_shutdown_flag() {
+fn test_stop_bus_watch_without_a_watch_is_a_noop() {
+    init_gstreamer();
+    let mut pipeline = Pipeline::new("test".to_string(), "fakesrc ! fakesink").unwrap();
+    // No bus watch has been started (that requires a tokio runtime, set up
+    // by `PipelineManager` instead); stopping one that was never set should
+    // just be a no-op rather than panic.
+    pipeline.stop_bus_watch();
+}
+
+// =============================================================================
+// Live graph editing tests (add_node/remove_node/link/unlink)
+// =============================================================================
+
+#[test]
+fn test_add_node_attaches_element() {
+    init_gstreamer();
+    let pipeline = Pipeline::new("test".to_string(), "fakesrc ! fakesink").unwrap();
+
+    pipeline
+        .add_node(&NodeSpec {
+            name: "extra".to_string(),
+            factory: "identity".to_string(),
+            properties: HashMap::new(),
+        })
+        .expect("add_node should succeed while stopped");
+
+    assert!(pipeline.list_elements().iter().any(|e| e.name == "extra"));
+}
+
+#[test]
+fn test_remove_node_detaches_linked_element() {
+    init_gstreamer();
+    let pipeline = Pipeline::new(
+        "test".to_string(),
+        "fakesrc name=src ! identity name=mid ! fakesink name=sink",
+    )
+    .unwrap();
+
+    pipeline
+        .remove_node("mid")
+        .expect("remove_node should succeed while stopped");
+
+    assert!(!pipeline.list_elements().iter().any(|e| e.name == "mid"));
+}
+
+#[test]
+fn test_link_and_unlink_round_trip() {
+    init_gstreamer();
+    let pipeline = Pipeline::new("test".to_string(), "fakesrc name=src").unwrap();
+    pipeline
+        .add_node(&NodeSpec {
+            name: "sink".to_string(),
+            factory: "fakesink".to_string(),
+            properties: HashMap::new(),
+        })
+        .unwrap();
+
+    let edge = EdgeSpec {
+        from_node: "src".to_string(),
+        src_pad: None,
+        to_node: "sink".to_string(),
+        sink_pad: None,
+    };
+
+    pipeline.link(&edge).expect("link should succeed while stopped");
+    pipeline.unlink(&edge).expect("unlink should succeed while stopped");
+}
+
+#[test]
+fn test_live_editing_rejected_while_playing() {
     init_gstreamer();
     let pipeline = Pipeline::new("test".to_string(), "fakesrc ! fakesink").unwrap();
-    let flag = pipeline.shutdown_flag();
-    assert!(!flag.load(std::sync::atomic::Ordering::Acquire));
+    pipeline.play().expect("pipeline should start playing");
+    assert_eq!(pipeline.state(), super::event::PipelineState::Playing);
+
+    let node = NodeSpec {
+        name: "extra".to_string(),
+        factory: "identity".to_string(),
+        properties: HashMap::new(),
+    };
+    let edge = EdgeSpec {
+        from_node: "src".to_string(),
+        src_pad: None,
+        to_node: "sink".to_string(),
+        sink_pad: None,
+    };
 
-    pipeline.signal_shutdown();
+    assert!(matches!(
+        pipeline.add_node(&node),
+        Err(GpopError::InvalidPipeline(_))
+    ));
+    assert!(matches!(
+        pipeline.remove_node("fakesink0"),
+        Err(GpopError::InvalidPipeline(_))
+    ));
+    assert!(matches!(
+        pipeline.link(&edge),
+        Err(GpopError::InvalidPipeline(_))
+    ));
+    assert!(matches!(
+        pipeline.unlink(&edge),
+        Err(GpopError::InvalidPipeline(_))
+    ));
 
-    assert!(flag.load(std::sync::atomic::Ordering::Acquire));
+    pipeline.stop().unwrap();
 }
@@ -6,58 +6,62 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use std::collections::HashSet;
 use std::time::Duration;
 
+use futures_util::future::join_all;
+use tokio_stream::{Stream, StreamExt};
+
 use super::manager::*;
-use crate::gst::event::{create_event_channel, EventReceiver, PipelineEvent};
+use crate::gst::event::{create_event_channel, PipelineEvent};
 
 fn init_gstreamer() {
     let _ = gstreamer::init();
 }
 
-/// Collect pipeline-completion events (Eos, Error, Unsupported, PipelineRemoved)
-/// until `expected_count` are received, or timeout expires.
-/// Panics on timeout if `require_all` is true; otherwise returns what was collected.
+/// A pipeline-completion event: one of the terminal/lifecycle events tests
+/// actually care about, as opposed to chatty ones like `StateChanged` or
+/// `PipelineAdded`.
+fn is_completion_event(event: &PipelineEvent) -> bool {
+    matches!(
+        event,
+        PipelineEvent::Eos { .. }
+            | PipelineEvent::Error { .. }
+            | PipelineEvent::Unsupported { .. }
+            | PipelineEvent::PipelineRemoved { .. }
+    )
+}
+
+/// Collect pipeline-completion events until `expected_count` are received,
+/// or timeout expires. Panics on timeout.
 async fn wait_for_events(
-    rx: &mut EventReceiver,
+    events: impl Stream<Item = PipelineEvent> + Unpin,
     expected_count: usize,
     timeout_secs: u64,
 ) -> Vec<PipelineEvent> {
-    collect_events(rx, expected_count, timeout_secs, true).await
+    collect_events(events, expected_count, timeout_secs, true).await
 }
 
+/// Like `wait_for_events`, but on timeout returns whatever was collected so
+/// far instead of panicking, when `require_all` is false.
 async fn collect_events(
-    rx: &mut EventReceiver,
+    mut events: impl Stream<Item = PipelineEvent> + Unpin,
     expected_count: usize,
     timeout_secs: u64,
     require_all: bool,
 ) -> Vec<PipelineEvent> {
-    let mut events = Vec::new();
+    let mut collected = Vec::new();
     let deadline = tokio::time::Instant::now() + Duration::from_secs(timeout_secs);
 
-    while events.len() < expected_count {
-        match tokio::time::timeout_at(deadline, rx.recv()).await {
-            Ok(Ok(event)) => match &event {
-                PipelineEvent::Eos { .. }
-                | PipelineEvent::Error { .. }
-                | PipelineEvent::Unsupported { .. }
-                | PipelineEvent::PipelineRemoved { .. } => {
-                    events.push(event);
-                }
-                _ => {} // Ignore StateChanged, PipelineAdded, etc.
-            },
-            Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(_))) => {
-                continue;
-            }
-            Ok(Err(tokio::sync::broadcast::error::RecvError::Closed)) => {
-                break;
-            }
+    while collected.len() < expected_count {
+        match tokio::time::timeout_at(deadline, events.next()).await {
+            Ok(Some(event)) if is_completion_event(&event) => collected.push(event),
+            Ok(Some(_)) => continue, // Ignore StateChanged, PipelineAdded, etc.
+            Ok(None) => break,       // Sender dropped, stream ended
             Err(_) => {
                 if require_all {
                     panic!(
                         "Timed out waiting for events: got {}/{} after {}s",
-                        events.len(),
+                        collected.len(),
                         expected_count,
                         timeout_secs
                     );
@@ -67,14 +71,13 @@ async fn collect_events(
         }
     }
 
-    events
+    collected
 }
 
 #[tokio::test]
 async fn test_single_pipeline_eos() {
     init_gstreamer();
     let (tx, _rx) = create_event_channel();
-    let mut event_rx = tx.subscribe();
     let manager = PipelineManager::new(tx);
 
     let id = manager
@@ -84,15 +87,10 @@ async fn test_single_pipeline_eos() {
 
     manager.play(&id).await.unwrap();
 
-    let events = wait_for_events(&mut event_rx, 1, 10).await;
-
-    assert_eq!(events.len(), 1);
-    match &events[0] {
-        PipelineEvent::Eos { pipeline_id } => {
-            assert_eq!(pipeline_id, &id);
-        }
-        other => panic!("Expected Eos event, got {:?}", other),
-    }
+    manager
+        .wait_for_eos(&id, Some(Duration::from_secs(10)))
+        .await
+        .unwrap();
 
     manager.shutdown().await;
 }
@@ -101,7 +99,6 @@ async fn test_single_pipeline_eos() {
 async fn test_multiple_pipelines_all_eos() {
     init_gstreamer();
     let (tx, _rx) = create_event_channel();
-    let mut event_rx = tx.subscribe();
     let manager = PipelineManager::new(tx);
 
     let mut ids = Vec::new();
@@ -117,18 +114,14 @@ async fn test_multiple_pipelines_all_eos() {
         manager.play(id).await.unwrap();
     }
 
-    let events = wait_for_events(&mut event_rx, 3, 10).await;
-
-    let eos_ids: HashSet<String> = events
-        .iter()
-        .filter_map(|e| match e {
-            PipelineEvent::Eos { pipeline_id } => Some(pipeline_id.clone()),
-            _ => None,
-        })
-        .collect();
-
-    let expected_ids: HashSet<String> = ids.into_iter().collect();
-    assert_eq!(eos_ids, expected_ids, "All 3 pipelines should reach EOS");
+    let results = join_all(
+        ids.iter()
+            .map(|id| manager.wait_for_eos(id, Some(Duration::from_secs(10)))),
+    )
+    .await;
+    for (id, result) in ids.iter().zip(results) {
+        result.unwrap_or_else(|e| panic!("pipeline '{}' should reach EOS: {}", id, e));
+    }
 
     manager.shutdown().await;
 }
@@ -137,7 +130,6 @@ async fn test_multiple_pipelines_all_eos() {
 async fn test_mixed_eos_and_error() {
     init_gstreamer();
     let (tx, _rx) = create_event_channel();
-    let mut event_rx = tx.subscribe();
     let manager = PipelineManager::new(tx);
 
     // This pipeline will reach EOS
@@ -154,42 +146,19 @@ async fn test_mixed_eos_and_error() {
 
     manager.play(&good_id).await.unwrap();
 
-    // The bad pipeline fails at set_state(Playing), confirming it errors
+    // The bad pipeline fails at set_state(Playing), confirming it errors.
+    // It may also emit a bus Error event, but that's racy with timing, so
+    // we don't assert on it here - only that the good pipeline is unaffected.
     let bad_play_result = manager.play(&bad_id).await;
     assert!(
         bad_play_result.is_err(),
         "Playing a pipeline with non-existent file should fail"
     );
 
-    // The bus watcher may emit Error events for the bad pipeline before
-    // the good pipeline reaches EOS. Collect events generously — the bus
-    // may emit multiple errors, so we don't require an exact count.
-    let events = collect_events(&mut event_rx, 4, 3, false).await;
-
-    let mut got_eos = false;
-    let mut got_error_for_bad = false;
-    for event in &events {
-        match event {
-            PipelineEvent::Eos { pipeline_id } if pipeline_id == &good_id => {
-                got_eos = true;
-            }
-            PipelineEvent::Error { pipeline_id, .. } if pipeline_id == &bad_id => {
-                got_error_for_bad = true;
-            }
-            _ => {}
-        }
-    }
-
-    assert!(
-        got_eos,
-        "Good pipeline should reach EOS despite bad pipeline failing"
-    );
-    // The bad pipeline's synchronous play() failure is the primary error path.
-    // A bus Error event may or may not arrive depending on timing, so we only
-    // log it rather than requiring it.
-    if got_error_for_bad {
-        // Expected in most runs — the bus emits an error before state change completes
-    }
+    manager
+        .wait_for_eos(&good_id, Some(Duration::from_secs(3)))
+        .await
+        .expect("Good pipeline should reach EOS despite bad pipeline failing");
 
     manager.shutdown().await;
 }
@@ -198,8 +167,8 @@ async fn test_mixed_eos_and_error() {
 async fn test_pipeline_removed_during_playback() {
     init_gstreamer();
     let (tx, _rx) = create_event_channel();
-    let mut event_rx = tx.subscribe();
     let manager = PipelineManager::new(tx);
+    let events = manager.event_stream();
 
     let id1 = manager
         .add_pipeline("videotestsrc num-buffers=10 ! fakesink")
@@ -218,7 +187,7 @@ async fn test_pipeline_removed_during_playback() {
     tokio::time::sleep(Duration::from_millis(100)).await;
     manager.remove_pipeline(&id2).await.unwrap();
 
-    let events = wait_for_events(&mut event_rx, 2, 10).await;
+    let events = wait_for_events(events, 2, 10).await;
 
     let mut got_eos_id1 = false;
     let mut got_removed_id2 = false;
@@ -248,7 +217,6 @@ async fn test_pipeline_removed_during_playback() {
 async fn test_multiple_pipelines_different_durations() {
     init_gstreamer();
     let (tx, _rx) = create_event_channel();
-    let mut event_rx = tx.subscribe();
     let manager = PipelineManager::new(tx);
 
     // Short pipeline: 10 buffers
@@ -266,21 +234,14 @@ async fn test_multiple_pipelines_different_durations() {
     manager.play(&short_id).await.unwrap();
     manager.play(&long_id).await.unwrap();
 
-    let events = wait_for_events(&mut event_rx, 2, 10).await;
-
-    let eos_ids: HashSet<String> = events
-        .iter()
-        .filter_map(|e| match e {
-            PipelineEvent::Eos { pipeline_id } => Some(pipeline_id.clone()),
-            _ => None,
-        })
-        .collect();
-
-    assert!(
-        eos_ids.contains(&short_id),
-        "Short pipeline should reach EOS"
-    );
-    assert!(eos_ids.contains(&long_id), "Long pipeline should reach EOS");
+    manager
+        .wait_for_eos(&short_id, Some(Duration::from_secs(10)))
+        .await
+        .expect("Short pipeline should reach EOS");
+    manager
+        .wait_for_eos(&long_id, Some(Duration::from_secs(10)))
+        .await
+        .expect("Long pipeline should reach EOS");
 
     manager.shutdown().await;
 }
@@ -293,8 +254,8 @@ async fn test_multiple_pipelines_different_durations() {
 async fn test_unsupported_event_tracked() {
     init_gstreamer();
     let (tx, _rx) = create_event_channel();
-    let mut event_rx = tx.subscribe();
     let manager = PipelineManager::new(tx.clone());
+    let events = manager.event_stream();
 
     // Create a pipeline that will reach EOS
     let good_id = manager
@@ -316,7 +277,7 @@ async fn test_unsupported_event_tracked() {
         message: "missing codec: test".to_string(),
     });
 
-    let events = collect_events(&mut event_rx, 3, 5, false).await;
+    let events = collect_events(events, 3, 5, false).await;
 
     let mut got_eos = false;
     let mut got_unsupported = false;
@@ -0,0 +1,98 @@
+// playlist.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{GpopError, Result};
+
+/// How a playlist pipeline behaves once it reaches its last entry. Modeled
+/// on GStreamer's `uriplaylistbin`, which plays through its `uris` list
+/// gaplessly and can optionally repeat it instead of ending after one pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaylistMode {
+    /// Play through the list `iterations` times, then end.
+    Finite,
+    /// Ignore `iterations` and loop the playlist forever.
+    Infinite,
+}
+
+/// Tracks a playlist pipeline's URIs and current position, so the bus
+/// watcher can advance to the next entry on EOS instead of tearing the
+/// pipeline down. Owned by the [`Pipeline`](super::pipeline::Pipeline) it
+/// drives.
+#[derive(Debug, Clone)]
+pub struct PlaylistState {
+    uris: Vec<String>,
+    mode: PlaylistMode,
+    iterations: u32,
+    index: usize,
+    completed_iterations: u32,
+}
+
+impl PlaylistState {
+    pub fn new(uris: Vec<String>, mode: PlaylistMode, iterations: u32) -> Result<Self> {
+        if uris.is_empty() {
+            return Err(GpopError::InvalidPipeline(
+                "Playlist must contain at least one URI".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            uris,
+            mode,
+            iterations: iterations.max(1),
+            index: 0,
+            completed_iterations: 0,
+        })
+    }
+
+    pub fn current_uri(&self) -> &str {
+        &self.uris[self.index]
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn len(&self) -> usize {
+        self.uris.len()
+    }
+
+    pub fn mode(&self) -> PlaylistMode {
+        self.mode
+    }
+
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Advance past the entry that just reached EOS. Returns the next URI
+    /// to play, or `None` once the playlist has run through all of its
+    /// configured iterations (or forever, for `PlaylistMode::Infinite`,
+    /// this never returns `None`).
+    pub fn advance(&mut self) -> Option<String> {
+        if self.index + 1 < self.uris.len() {
+            self.index += 1;
+            return Some(self.uris[self.index].clone());
+        }
+
+        // Reached the end of one pass through the playlist.
+        self.completed_iterations += 1;
+        let keep_going = match self.mode {
+            PlaylistMode::Infinite => true,
+            PlaylistMode::Finite => self.completed_iterations < self.iterations,
+        };
+        if !keep_going {
+            return None;
+        }
+
+        self.index = 0;
+        Some(self.uris[0].clone())
+    }
+}
@@ -0,0 +1,71 @@
+// playlist_tests.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use super::playlist::*;
+
+fn uris(n: usize) -> Vec<String> {
+    (0..n).map(|i| format!("file:///media/{}.mp4", i)).collect()
+}
+
+#[test]
+fn test_playlist_state_rejects_empty() {
+    assert!(PlaylistState::new(vec![], PlaylistMode::Finite, 1).is_err());
+}
+
+#[test]
+fn test_playlist_state_advances_through_entries() {
+    let mut playlist = PlaylistState::new(uris(3), PlaylistMode::Finite, 1).unwrap();
+    assert_eq!(playlist.current_index(), 0);
+    assert_eq!(playlist.current_uri(), "file:///media/0.mp4");
+
+    assert_eq!(playlist.advance().as_deref(), Some("file:///media/1.mp4"));
+    assert_eq!(playlist.current_index(), 1);
+
+    assert_eq!(playlist.advance().as_deref(), Some("file:///media/2.mp4"));
+    assert_eq!(playlist.current_index(), 2);
+
+    // One iteration only: the playlist ends after the last entry.
+    assert_eq!(playlist.advance(), None);
+}
+
+#[test]
+fn test_playlist_state_finite_repeats_iterations_times() {
+    let mut playlist = PlaylistState::new(uris(2), PlaylistMode::Finite, 2).unwrap();
+
+    assert_eq!(playlist.advance().as_deref(), Some("file:///media/1.mp4"));
+    // Wraps back to the first entry for the second iteration.
+    assert_eq!(playlist.advance().as_deref(), Some("file:///media/0.mp4"));
+    assert_eq!(playlist.advance().as_deref(), Some("file:///media/1.mp4"));
+    // Second iteration exhausted.
+    assert_eq!(playlist.advance(), None);
+}
+
+#[test]
+fn test_playlist_state_infinite_never_ends() {
+    let mut playlist = PlaylistState::new(uris(2), PlaylistMode::Infinite, 1).unwrap();
+
+    for _ in 0..10 {
+        assert!(playlist.advance().is_some());
+    }
+}
+
+#[test]
+fn test_playlist_state_single_entry_finite() {
+    let mut playlist = PlaylistState::new(uris(1), PlaylistMode::Finite, 1).unwrap();
+    assert_eq!(playlist.advance(), None);
+}
+
+#[test]
+fn test_playlist_mode_serde_round_trip() {
+    let json = serde_json::to_string(&PlaylistMode::Finite).unwrap();
+    assert_eq!(json, "\"finite\"");
+    assert_eq!(
+        serde_json::from_str::<PlaylistMode>("\"infinite\"").unwrap(),
+        PlaylistMode::Infinite
+    );
+}
@@ -0,0 +1,176 @@
+// stats.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use gstreamer::glib;
+use gstreamer::prelude::*;
+use gstreamer::{self as gst};
+use serde_json::{Map, Value};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+use crate::gst::event::{PipelineEvent, PipelineState};
+use crate::gst::manager::PipelineManager;
+
+/// Recursively convert a GLib/GStreamer value into JSON.
+///
+/// Scalars map onto their natural JSON representation; `gst::Structure`
+/// becomes a JSON object (recursing on each field) and `gst::Array` /
+/// `gst::ValueArray` become JSON arrays (recursing on each entry).
+/// Types with no sensible JSON representation (objects, boxed types,
+/// enums, ...) are skipped by returning `None`.
+pub fn glib_value_to_json(value: &glib::Value) -> Option<Value> {
+    match value.type_() {
+        glib::Type::STRING => value.get::<Option<String>>().ok().flatten().map(Value::from),
+        glib::Type::BOOL => value.get::<bool>().ok().map(Value::from),
+        glib::Type::I32 => value.get::<i32>().ok().map(Value::from),
+        glib::Type::U32 => value.get::<u32>().ok().map(Value::from),
+        glib::Type::I64 => value.get::<i64>().ok().map(Value::from),
+        glib::Type::U64 => value.get::<u64>().ok().map(Value::from),
+        glib::Type::F32 => value.get::<f32>().ok().map(|f| Value::from(f as f64)),
+        glib::Type::F64 => value.get::<f64>().ok().map(Value::from),
+        ty if ty == gst::Structure::static_type() => value
+            .get::<Option<gst::Structure>>()
+            .ok()
+            .flatten()
+            .map(|s| structure_to_json(&s)),
+        ty if ty == gst::Array::static_type() => value.get::<gst::Array>().ok().map(|arr| {
+            Value::Array(
+                arr.as_slice()
+                    .iter()
+                    .filter_map(glib_value_to_json)
+                    .collect(),
+            )
+        }),
+        ty if ty == gst::ValueArray::static_type() => {
+            value.get::<gst::ValueArray>().ok().map(|arr| {
+                Value::Array(
+                    arr.as_slice()
+                        .iter()
+                        .filter_map(glib_value_to_json)
+                        .collect(),
+                )
+            })
+        }
+        ty if ty.is_a(glib::Type::FLAGS) => glib::FlagsClass::new(ty).map(|class| {
+            let bits = value.get::<u32>().unwrap_or(0);
+            let nick = (0..u32::BITS)
+                .map(|bit| 1u32 << bit)
+                .filter(|bit| bits & bit != 0)
+                .filter_map(|bit| class.value(bit))
+                .map(|v| v.nick())
+                .collect::<Vec<_>>()
+                .join("+");
+            Value::from(nick)
+        }),
+        _ => None,
+    }
+}
+
+/// Convert a `gst::Structure` into a JSON object, skipping fields whose
+/// value has no JSON representation. Takes a `StructureRef` rather than an
+/// owned `Structure` so it also accepts the borrowed structures returned by
+/// `gst::Caps::structure`.
+pub(crate) fn structure_to_json(structure: &gst::StructureRef) -> Value {
+    let mut map = Map::new();
+    for (field, value) in structure.iter() {
+        if let Some(json) = glib_value_to_json(value) {
+            map.insert(field.to_string(), json);
+        }
+    }
+    Value::Object(map)
+}
+
+/// Sample a pipeline's element tree (recursing into bins) into a JSON
+/// snapshot: each element contributes its name, current state, and every
+/// readable property that `glib_value_to_json` can represent.
+pub fn collect_pipeline_stats(pipeline: &gst::Pipeline) -> Value {
+    let mut elements = Vec::new();
+    collect_element_stats(pipeline.upcast_ref::<gst::Element>(), &mut elements);
+    let mut map = Map::new();
+    map.insert("elements".to_string(), Value::Array(elements));
+    Value::Object(map)
+}
+
+fn collect_element_stats(element: &gst::Element, out: &mut Vec<Value>) {
+    let (_result, current, _pending) = element.state(gst::ClockTime::ZERO);
+
+    let mut properties = Map::new();
+    for pspec in element.list_properties() {
+        if !pspec.flags().contains(glib::ParamFlags::READABLE) {
+            continue;
+        }
+        let value = element.property_value(pspec.name());
+        if let Some(json) = glib_value_to_json(&value) {
+            properties.insert(pspec.name().to_string(), json);
+        }
+    }
+
+    out.push(serde_json::json!({
+        "name": element.name().to_string(),
+        "state": PipelineState::from(current).to_string(),
+        "properties": properties,
+    }));
+
+    if let Some(bin) = element.downcast_ref::<gst::Bin>() {
+        for child in bin.iterate_elements().into_iter().flatten() {
+            collect_element_stats(&child, out);
+        }
+    }
+}
+
+/// Periodically sample a pipeline's stats and stream them to subscribers as
+/// a `Stats` event, analogous to the bus watcher but timer-driven instead of
+/// message-driven. Stops on its own once the pipeline is removed.
+pub fn start_stats_poller(
+    manager: std::sync::Arc<PipelineManager>,
+    pipeline_id: String,
+    interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+
+            let pipeline = match manager.get_pipeline(&pipeline_id).await {
+                Ok(p) => p,
+                Err(_) => {
+                    debug!(
+                        "Stats poller for pipeline '{}' stopping: pipeline removed",
+                        pipeline_id
+                    );
+                    break;
+                }
+            };
+
+            let stats = {
+                let p = pipeline.lock().await;
+                let mut stats = collect_pipeline_stats(p.gst_pipeline());
+                if let Value::Object(map) = &mut stats {
+                    if let Ok(qos) = serde_json::to_value(p.stats()) {
+                        map.insert("qos".to_string(), qos);
+                    }
+                }
+                stats
+            };
+
+            if manager
+                .send_event(PipelineEvent::Stats {
+                    pipeline_id: pipeline_id.clone(),
+                    stats,
+                })
+                .is_err()
+            {
+                warn!(
+                    "Failed to send stats event for pipeline '{}': no receivers",
+                    pipeline_id
+                );
+            }
+        }
+    })
+}
@@ -6,6 +6,8 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+#[cfg(unix)]
+pub mod control;
 #[cfg(target_os = "linux")]
 pub mod dbus;
 pub mod error;
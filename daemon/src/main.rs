@@ -18,7 +18,9 @@ use gpop::gst::PipelineEvent;
 
 #[cfg(target_os = "linux")]
 use gpop::dbus::{run_dbus_event_forwarder, DbusServer};
-use gpop::gst::{create_event_channel, PipelineManager};
+#[cfg(unix)]
+use gpop::control::ControlSocketServer;
+use gpop::gst::{create_event_channel, start_restart_supervisor, PipelineManager};
 use gpop::websocket::WebSocketServer;
 
 #[derive(Parser, Debug)]
@@ -60,6 +62,33 @@ struct Args {
     /// If not specified, all origins are allowed. Use for CSRF protection in browser contexts.
     #[arg(long = "allowed-origin")]
     allowed_origins: Vec<String>,
+
+    /// Enable the local IPC transport (Unix domain socket / Windows named pipe) in
+    /// addition to TCP. Serves the same JSON-RPC protocol, authenticated by
+    /// filesystem permissions instead of the API key.
+    #[arg(long)]
+    ipc: bool,
+
+    /// Path to the IPC socket / named pipe, used when --ipc is set
+    #[arg(long, default_value = gpop::websocket::DEFAULT_IPC_PATH)]
+    ipc_path: String,
+
+    /// Enable the local control socket: a compact bincode-encoded sibling of
+    /// the JSON-RPC transports, for shell scripts and sidecar tools on the
+    /// same host
+    #[cfg(unix)]
+    #[arg(long)]
+    control_socket: bool,
+
+    /// Path to the control socket, used when --control-socket is set
+    #[cfg(unix)]
+    #[arg(long, default_value = gpop::control::DEFAULT_CONTROL_SOCKET_PATH)]
+    control_socket_path: String,
+
+    /// Permissions applied to the control socket file, as an octal string (e.g. "600")
+    #[cfg(unix)]
+    #[arg(long, default_value = "600")]
+    control_socket_permissions: String,
 }
 
 #[tokio::main]
@@ -83,11 +112,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Validate that at least one interface is enabled
     #[cfg(target_os = "linux")]
-    if args.no_dbus && args.no_websocket {
-        error!("At least one interface (DBus or WebSocket) must be enabled");
+    if args.no_dbus && args.no_websocket && !args.control_socket {
+        error!("At least one interface (DBus, WebSocket or control socket) must be enabled");
+        std::process::exit(1);
+    }
+    #[cfg(all(unix, not(target_os = "linux")))]
+    if args.no_websocket && !args.control_socket {
+        error!("At least one interface (WebSocket or control socket) must be enabled");
         std::process::exit(1);
     }
 
+    // Parse the control socket permissions as an octal string
+    #[cfg(unix)]
+    let control_socket_permissions =
+        match u32::from_str_radix(&args.control_socket_permissions, 8) {
+            Ok(mode) => mode,
+            Err(e) => {
+                error!(
+                    "Invalid --control-socket-permissions '{}': {}",
+                    args.control_socket_permissions, e
+                );
+                std::process::exit(1);
+            }
+        };
+
     // Initialize GStreamer
     gstreamer::init()?;
     info!("GStreamer initialized");
@@ -98,6 +146,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create pipeline manager
     let manager = Arc::new(PipelineManager::new(event_tx.clone()));
 
+    // Watch for errors/EOS on pipelines with a restart policy and rebuild
+    // them automatically; a no-op until a client calls set_restart_policy.
+    start_restart_supervisor(Arc::clone(&manager));
+
     // Create initial pipelines
     let mut initial_pipeline_ids: Vec<String> = Vec::new();
     for desc in &args.pipelines {
@@ -170,7 +222,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         } else {
             Some(args.allowed_origins.clone())
         };
-        let ws_server = WebSocketServer::new(
+        let mut ws_server = WebSocketServer::new(
             addr,
             Arc::clone(&manager),
             args.api_key.clone(),
@@ -184,6 +236,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         if let Some(ref origins) = allowed_origins {
             info!("WebSocket origin validation enabled for: {:?}", origins);
         }
+        if args.ipc {
+            ws_server = ws_server.with_ipc_path(args.ipc_path.clone());
+            info!("Local IPC transport enabled at {}", args.ipc_path);
+        }
 
         Some(tokio::spawn(async move {
             if let Err(e) = ws_server.run(ws_event_rx).await {
@@ -195,6 +251,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         None
     };
 
+    // Start control socket server
+    #[cfg(unix)]
+    let control_server = if args.control_socket {
+        let server = Arc::new(ControlSocketServer::new(
+            args.control_socket_path.clone(),
+            control_socket_permissions,
+            Arc::clone(&manager),
+        ));
+        let server_clone = Arc::clone(&server);
+        tokio::spawn(async move {
+            if let Err(e) = server_clone.run().await {
+                error!("Control socket server error: {}", e);
+            }
+        });
+        info!("Control socket enabled at {}", args.control_socket_path);
+        Some(server)
+    } else {
+        None
+    };
+
     // Exit codes matching GStreamer convention (gst-launch MR !10088)
     const EXIT_CODE_ERROR: i32 = 1;
     const EXIT_CODE_UNSUPPORTED: i32 = 69; // EX_UNAVAILABLE
@@ -241,6 +317,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         PipelineEvent::Error {
                             pipeline_id,
                             message,
+                            ..
                         } => {
                             if pending.remove(pipeline_id) {
                                 had_error = true;
@@ -401,6 +478,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         handle.abort();
     }
 
+    // Unlink the control socket so a stale file doesn't linger (Unix only)
+    #[cfg(unix)]
+    if let Some(server) = control_server {
+        server.unlink();
+    }
+
     // DBus connection will be dropped automatically (Linux only)
     #[cfg(target_os = "linux")]
     drop(dbus_server);
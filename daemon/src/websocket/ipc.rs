@@ -0,0 +1,248 @@
+// ipc.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Local IPC transport (Unix domain socket on Unix, named pipe on Windows).
+//!
+//! Unlike the TCP transport in [`super::server`], this speaks the
+//! `Request`/`Response`/batch JSON-RPC frames directly, newline-delimited,
+//! with no WebSocket upgrade - following the same reasoning as ethers-rs's
+//! IPC provider: a local, filesystem-permission-guarded socket has no need
+//! for the HTTP handshake a browser-facing WebSocket requires, and skipping
+//! it removes a round trip from every connection. Dispatch is otherwise
+//! identical to the TCP transport: the same `ManagerInterface`, the same
+//! broadcast `ClientMap` so subscribed events reach IPC clients too.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, error, info, warn};
+
+use crate::error::Result;
+use crate::gst::PipelineManager;
+
+use super::manager::ManagerInterface;
+use super::protocol::{Request, Response};
+use super::server::{
+    dispatch_batch, dispatch_request, next_ipc_client_id, ClientEntry, ClientId, ClientMap,
+};
+use super::{CLIENT_MESSAGE_BUFFER, MAX_CONCURRENT_CLIENTS};
+
+/// Run the local IPC accept loop until the listener itself fails to bind or
+/// is torn down. Each accepted connection is handed to
+/// [`handle_raw_connection`], keyed by a unique `ClientId::Ipc` so it shares
+/// the broadcast `ClientMap` with TCP clients.
+pub(super) async fn run_ipc_listener(
+    path: PathBuf,
+    clients: ClientMap,
+    manager: Arc<PipelineManager>,
+) -> Result<()> {
+    #[cfg(unix)]
+    {
+        run_unix_listener(path, clients, manager).await
+    }
+    #[cfg(windows)]
+    {
+        run_named_pipe_listener(path, clients, manager).await
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = (path, clients, manager);
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+async fn run_unix_listener(
+    path: PathBuf,
+    clients: ClientMap,
+    manager: Arc<PipelineManager>,
+) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::net::UnixListener;
+
+    // A stale socket file left behind by a previous (crashed) run would
+    // otherwise make bind() fail with "address in use".
+    if path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+
+    let listener = UnixListener::bind(&path)?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    info!("IPC server listening on unix socket {:?}", path);
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let clients = Arc::clone(&clients);
+                let manager = Arc::clone(&manager);
+                let id = ClientId::Ipc(next_ipc_client_id());
+                tokio::spawn(handle_raw_connection(stream, id, clients, manager));
+            }
+            Err(e) => {
+                error!("Failed to accept IPC connection: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+async fn run_named_pipe_listener(
+    path: PathBuf,
+    clients: ClientMap,
+    manager: Arc<PipelineManager>,
+) -> Result<()> {
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    let pipe_name = path.to_string_lossy().to_string();
+    info!("IPC server listening on named pipe {}", pipe_name);
+
+    let mut server = ServerOptions::new().create(&pipe_name)?;
+    loop {
+        server.connect().await?;
+        let connected = server;
+        server = ServerOptions::new().create(&pipe_name)?;
+
+        let clients = Arc::clone(&clients);
+        let manager = Arc::clone(&manager);
+        let id = ClientId::Ipc(next_ipc_client_id());
+        tokio::spawn(handle_raw_connection(connected, id, clients, manager));
+    }
+}
+
+/// Serialize `value` and push it to `id`'s outgoing channel as a line of
+/// text. A no-op if the client has already disconnected.
+async fn send_line<T: serde::Serialize>(clients: &ClientMap, id: ClientId, value: &T) {
+    if let Some(entry) = clients.get(&id) {
+        let text = serde_json::to_string(value).unwrap_or_else(|e| {
+            error!("JSON serialization failed: {}", e);
+            r#"{"jsonrpc":"2.0","id":"unknown","error":{"code":-32603,"message":"Internal serialization error"}}"#.to_string()
+        });
+        let _ = entry.tx.try_send(Message::Text(text.into()));
+    }
+}
+
+/// Handle one local IPC connection over any duplex byte stream (Unix socket,
+/// named pipe): read newline-delimited JSON-RPC frames, dispatch each
+/// through the same path as the TCP transport (`ManagerInterface`,
+/// `subscribe`/`unsubscribe`, batches), and write back one JSON line per
+/// reply. Pushed notifications arrive on the same per-client channel as
+/// everything else and are written out the same way.
+async fn handle_raw_connection<S>(
+    stream: S,
+    id: ClientId,
+    clients: ClientMap,
+    manager: Arc<PipelineManager>,
+) where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    info!("New IPC connection from {}", id);
+
+    if clients.len() >= MAX_CONCURRENT_CLIENTS {
+        warn!(
+            "Max clients ({}) reached, rejecting IPC connection {}",
+            MAX_CONCURRENT_CLIENTS, id
+        );
+        return;
+    }
+
+    let (read_half, mut write_half) = tokio::io::split(stream);
+    let mut lines = BufReader::new(read_half).lines();
+    let (tx, mut rx) = mpsc::channel::<Message>(CLIENT_MESSAGE_BUFFER);
+
+    // CBOR binary framing is negotiated over the WebSocket subprotocol
+    // header, which this transport has none of, so IPC clients are always
+    // plain JSON text.
+    clients.insert(id, ClientEntry::new(tx, false));
+    let handler = ManagerInterface::new(manager, true);
+
+    let writer_task = tokio::spawn(async move {
+        while let Some(msg) = rx.recv().await {
+            let Message::Text(text) = msg else {
+                continue;
+            };
+            if write_half.write_all(text.as_bytes()).await.is_err()
+                || write_half.write_all(b"\n").await.is_err()
+                || write_half.flush().await.is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    loop {
+        match lines.next_line().await {
+            Ok(Some(line)) => {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                debug!("Received from {}: {}", id, line);
+
+                let value: serde_json::Value = match serde_json::from_str(&line) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        error!("Failed to parse IPC request from {}: {}", id, e);
+                        let response = Response::parse_error(
+                            "unknown".to_string(),
+                            format!("Parse error: {}", e),
+                        );
+                        send_line(&clients, id, &response).await;
+                        continue;
+                    }
+                };
+
+                match value {
+                    serde_json::Value::Array(elements) => {
+                        let replies = dispatch_batch(&handler, &clients, id, elements).await;
+                        send_line(&clients, id, &replies).await;
+                    }
+                    serde_json::Value::Object(_) => {
+                        let request = match serde_json::from_value::<Request>(value) {
+                            Ok(req) => req,
+                            Err(e) => {
+                                error!("Failed to parse IPC request from {}: {}", id, e);
+                                let response = Response::parse_error(
+                                    "unknown".to_string(),
+                                    format!("Parse error: {}", e),
+                                );
+                                send_line(&clients, id, &response).await;
+                                continue;
+                            }
+                        };
+                        // `binary: false` here always yields `Message::Text`.
+                        let reply = dispatch_request(&handler, &clients, id, false, request).await;
+                        if let Some(entry) = clients.get(&id) {
+                            let _ = entry.tx.try_send(reply);
+                        }
+                    }
+                    _ => {
+                        let response = Response::parse_error(
+                            "unknown".to_string(),
+                            "Invalid Request: expected a JSON object or an array of requests"
+                                .to_string(),
+                        );
+                        send_line(&clients, id, &response).await;
+                    }
+                }
+            }
+            Ok(None) => {
+                info!("IPC client {} disconnected", id);
+                break;
+            }
+            Err(e) => {
+                error!("Error reading from IPC client {}: {}", id, e);
+                break;
+            }
+        }
+    }
+
+    clients.remove(&id);
+    writer_task.abort();
+}
@@ -0,0 +1,56 @@
+// ipc_tests.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::sync::Arc;
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+
+use crate::gst::{create_event_channel, PipelineManager};
+
+use super::ipc::run_ipc_listener;
+use super::server::ClientRegistry;
+
+/// Drives one request/response round trip over a real Unix domain socket, to
+/// prove the IPC transport speaks the same newline-delimited JSON-RPC frames
+/// as the TCP/WebSocket path (`ManagerInterface`, shared `dispatch_request`),
+/// not just that it compiles.
+#[tokio::test]
+async fn test_ipc_listener_dispatches_json_rpc_request() {
+    let path = std::env::temp_dir().join(format!("gpop-ipc-test-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let (event_tx, _event_rx) = create_event_channel();
+    let manager = Arc::new(PipelineManager::new(event_tx));
+    let clients = ClientRegistry::for_test();
+
+    let listener_path = path.clone();
+    tokio::spawn(run_ipc_listener(listener_path, clients, manager));
+
+    // Give the listener a moment to bind before connecting.
+    let mut stream = loop {
+        match UnixStream::connect(&path).await {
+            Ok(stream) => break stream,
+            Err(_) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+        }
+    };
+
+    stream
+        .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":\"1\",\"method\":\"list_pipelines\",\"params\":{}}\n")
+        .await
+        .unwrap();
+
+    let mut line = String::new();
+    BufReader::new(&mut stream).read_line(&mut line).await.unwrap();
+
+    let response: serde_json::Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(response["id"], "1");
+    assert_eq!(response["result"]["pipelines"], serde_json::json!([]));
+
+    let _ = std::fs::remove_file(&path);
+}
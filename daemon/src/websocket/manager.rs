@@ -6,14 +6,18 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
-use tracing::{debug, error};
+use std::time::Duration;
+use tracing::{debug, error, warn};
 
-use crate::gst::{PipelineManager, PipelineState};
+use crate::gst::{start_stats_poller, PipelineManager, PipelineState, RestartPolicy};
 
 use super::pipeline::*;
 use super::protocol::*;
-use super::DEFAULT_PIPELINE_ID;
+use super::{DEFAULT_PIPELINE_ID, DEFAULT_PROGRESS_INTERVAL_MS, DEFAULT_STATS_POLL_INTERVAL_MS};
 
 /// Safely convert a serializable value to a JSON Value.
 /// Returns an internal error response if serialization fails (should never happen for well-typed structs).
@@ -31,37 +35,343 @@ fn to_json_value<T: serde::Serialize>(id: String, value: &T) -> Response {
     }
 }
 
+type HandlerFuture<'a> = Pin<Box<dyn Future<Output = Response> + Send + 'a>>;
+type HandlerFn = for<'a> fn(&'a ManagerInterface, Request) -> HandlerFuture<'a>;
+
+/// A method registered in `ManagerInterface`'s dispatch table: its handler,
+/// plus the description returned by `list_methods` so tooling and the web UI
+/// can feature-detect daemon capabilities instead of hard-coding them.
+struct MethodEntry {
+    handler: HandlerFn,
+    description: &'static str,
+}
+
 /// WebSocket interface for managing pipelines.
 /// This is the WebSocket equivalent of the DBus `ManagerInterface`.
 pub struct ManagerInterface {
     manager: Arc<PipelineManager>,
+    methods: HashMap<&'static str, MethodEntry>,
+    /// Whether the local IPC transport is enabled on this daemon, reported
+    /// to clients via `handshake` for capability discovery.
+    ipc_enabled: bool,
 }
 
 impl ManagerInterface {
-    pub fn new(manager: Arc<PipelineManager>) -> Self {
-        Self { manager }
+    pub fn new(manager: Arc<PipelineManager>, ipc_enabled: bool) -> Self {
+        Self {
+            manager,
+            methods: Self::build_registry(),
+            ipc_enabled,
+        }
+    }
+
+    /// Build the method dispatch table. Adding a method here is the only
+    /// step needed to make it reachable from `handle` and show up in
+    /// `list_methods` - unlike `snapshot`, `subscribe` and `unsubscribe`,
+    /// which `server.rs` special-cases ahead of `handle` and so are never
+    /// registered here.
+    fn build_registry() -> HashMap<&'static str, MethodEntry> {
+        let mut methods: HashMap<&'static str, MethodEntry> = HashMap::new();
+
+        methods.insert(
+            "list_pipelines",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.list_pipelines(req.id).await }),
+                description: "List all managed pipelines",
+            },
+        );
+        methods.insert(
+            "create_pipeline",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.create_pipeline(req).await }),
+                description: "Create a new pipeline from a gst-launch description",
+            },
+        );
+        methods.insert(
+            "create_pipeline_from_graph",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.create_pipeline_from_graph(req).await }),
+                description: "Create a new pipeline from a structured node/port graph",
+            },
+        );
+        methods.insert(
+            "remove_pipeline",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.remove_pipeline(req).await }),
+                description: "Remove a pipeline",
+            },
+        );
+        methods.insert(
+            "get_pipeline_info",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.get_pipeline_info(req).await }),
+                description: "Get information about a pipeline",
+            },
+        );
+        methods.insert(
+            "set_state",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.set_state(req).await }),
+                description: "Set a pipeline's GStreamer state directly",
+            },
+        );
+        methods.insert(
+            "play",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.play(req).await }),
+                description: "Set a pipeline to the Playing state",
+            },
+        );
+        methods.insert(
+            "pause",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.pause(req).await }),
+                description: "Set a pipeline to the Paused state",
+            },
+        );
+        methods.insert(
+            "stop",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.stop(req).await }),
+                description: "Set a pipeline to the Null state",
+            },
+        );
+        methods.insert(
+            "get_position",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.get_position(req).await }),
+                description: "Get a pipeline's current position and duration",
+            },
+        );
+        methods.insert(
+            "update_pipeline",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.update_pipeline(req).await }),
+                description: "Replace a pipeline's description in place",
+            },
+        );
+        methods.insert(
+            "seek",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.seek(req).await }),
+                description: "Seek a pipeline to an absolute position or a fraction of its duration",
+            },
+        );
+        methods.insert(
+            "set_progress_reporting",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.set_progress_reporting(req).await }),
+                description: "Enable or disable periodic position/progress events for a pipeline",
+            },
+        );
+        methods.insert(
+            "set_restart_policy",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.set_restart_policy(req).await }),
+                description: "Automatically rebuild a pipeline after an error or EOS",
+            },
+        );
+        methods.insert(
+            "get_version",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.get_version(req.id) }),
+                description: "Get the daemon's version",
+            },
+        );
+        methods.insert(
+            "get_info",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.get_info(req.id) }),
+                description: "Get daemon and GStreamer version info",
+            },
+        );
+        methods.insert(
+            "get_pipeline_count",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.get_pipeline_count(req.id).await }),
+                description: "Get the number of managed pipelines",
+            },
+        );
+        methods.insert(
+            "stats.subscribe",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.stats_subscribe(req).await }),
+                description: "Start a periodic stats poller for a pipeline",
+            },
+        );
+        methods.insert(
+            "join_sync_group",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.join_sync_group(req).await }),
+                description: "Bind a pipeline to a shared clock/base-time group for synchronized playback",
+            },
+        );
+        methods.insert(
+            "handshake",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.handshake(req) }),
+                description: "Negotiate protocol version and discover server capabilities before issuing other calls",
+            },
+        );
+        methods.insert(
+            "list_methods",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.list_methods(req.id) }),
+                description: "List the RPC methods this daemon supports",
+            },
+        );
+        methods.insert(
+            "add_playlist",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.add_playlist(req).await }),
+                description: "Create a gapless auto-advancing playlist pipeline from a list of URIs",
+            },
+        );
+        methods.insert(
+            "get_playlist_info",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.get_playlist_info(req).await }),
+                description: "Get the current entry and progress of a playlist pipeline",
+            },
+        );
+        methods.insert(
+            "playlist_next",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.playlist_next(req).await }),
+                description: "Manually skip a playlist pipeline to its next entry",
+            },
+        );
+        methods.insert(
+            "get_streams",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.get_streams(req).await }),
+                description: "Get negotiated caps and RFC 6381 MIME codec strings for every pad in a pipeline",
+            },
+        );
+        methods.insert(
+            "list_element_properties",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.list_element_properties(req).await }),
+                description: "List a named element's GObject properties (name, type, flags, default and current value)",
+            },
+        );
+        methods.insert(
+            "get_element_property",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.get_element_property(req).await }),
+                description: "Read a single element property's current value",
+            },
+        );
+        methods.insert(
+            "set_element_property",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.set_element_property(req).await }),
+                description: "Set a single element property without rebuilding the pipeline",
+            },
+        );
+        methods.insert(
+            "set_bitrate_limits",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.set_bitrate_limits(req).await }),
+                description: "Enable delay-based adaptive bitrate control on a named encoder element",
+            },
+        );
+        methods.insert(
+            "add_node",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.add_node(req).await }),
+                description: "Add an element to a pipeline's graph without rebuilding it (NULL/READY only)",
+            },
+        );
+        methods.insert(
+            "remove_node",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.remove_node(req).await }),
+                description: "Remove a named element from a pipeline's graph (NULL/READY only)",
+            },
+        );
+        methods.insert(
+            "link",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.link(req).await }),
+                description: "Link two nodes in a pipeline's graph (NULL/READY only)",
+            },
+        );
+        methods.insert(
+            "unlink",
+            MethodEntry {
+                handler: |m, req| Box::pin(async move { m.unlink(req).await }),
+                description: "Unlink two nodes in a pipeline's graph (NULL/READY only)",
+            },
+        );
+
+        methods
     }
 
     pub async fn handle(&self, request: Request) -> Response {
         debug!("Handling request: {} (id: {})", request.method, request.id);
 
-        match request.method.as_str() {
-            "list_pipelines" => self.list_pipelines(request.id).await,
-            "create_pipeline" => self.create_pipeline(request).await,
-            "remove_pipeline" => self.remove_pipeline(request).await,
-            "get_pipeline_info" => self.get_pipeline_info(request).await,
-            "set_state" => self.set_state(request).await,
-            "play" => self.play(request).await,
-            "pause" => self.pause(request).await,
-            "stop" => self.stop(request).await,
-            "get_position" => self.get_position(request).await,
-            "update_pipeline" => self.update_pipeline(request).await,
-            "get_version" => self.get_version(request.id),
-            "get_info" => self.get_info(request.id),
-            "get_pipeline_count" => self.get_pipeline_count(request.id).await,
-            // snapshot is handled separately in server.rs
-            _ => Response::method_not_found(request.id, &request.method),
+        match self.methods.get(request.method.as_str()) {
+            Some(entry) => (entry.handler)(self, request).await,
+            None => Response::method_not_found(request.id, &request.method),
+        }
+    }
+
+    /// List every registered method and its description, so tooling and the
+    /// web UI can feature-detect daemon capabilities instead of hard-coding
+    /// them. Sorted by name for a stable, diffable response.
+    fn list_methods(&self, id: String) -> Response {
+        let mut methods: Vec<MethodInfo> = self
+            .methods
+            .iter()
+            .map(|(name, entry)| MethodInfo {
+                name: name.to_string(),
+                description: entry.description.to_string(),
+            })
+            .collect();
+        methods.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let result = ListMethodsResult { methods };
+        to_json_value(id, &result)
+    }
+
+    /// Negotiate protocol compatibility and report capabilities that aren't
+    /// discoverable from `list_methods` alone (snapshot/subscribe bypass the
+    /// dispatch table; IPC availability is transport-, not method-, level).
+    /// Rejects an incompatible `client_protocol_version` with
+    /// `PROTOCOL_VERSION_MISMATCH` instead of letting the client fail later
+    /// on an unknown method or field.
+    fn handshake(&self, request: Request) -> Response {
+        let params: HandshakeParams =
+            serde_json::from_value(request.params).unwrap_or_default();
+
+        if let Some(client_version) = params.client_protocol_version {
+            if client_version != GPOP_PROTOCOL_VERSION {
+                return Response::error(
+                    request.id,
+                    error_codes::PROTOCOL_VERSION_MISMATCH,
+                    format!(
+                        "Protocol version mismatch: server is {}, client is {}",
+                        GPOP_PROTOCOL_VERSION, client_version
+                    ),
+                );
+            }
         }
+
+        let mut methods: Vec<String> = self.methods.keys().map(|m| m.to_string()).collect();
+        methods.sort();
+
+        let result = HandshakeResult {
+            protocol_version: GPOP_PROTOCOL_VERSION,
+            daemon_version: env!("CARGO_PKG_VERSION").to_string(),
+            methods,
+            features: Features {
+                snapshot: true,
+                subscribe: true,
+                ipc: self.ipc_enabled,
+            },
+        };
+        to_json_value(request.id, &result)
     }
 
     /// Get the daemon version
@@ -114,6 +424,37 @@ impl ManagerInterface {
         };
 
         match self.manager.add_pipeline(&params.description).await {
+            Ok(pipeline_id) => {
+                if let Some(interval_ms) = params.progress_interval_ms {
+                    if let Err(e) = self
+                        .manager
+                        .set_progress_reporting(&pipeline_id, interval_ms)
+                        .await
+                    {
+                        warn!(
+                            "Failed to enable progress reporting for pipeline '{}': {}",
+                            pipeline_id, e
+                        );
+                    }
+                }
+                let result = PipelineCreatedResult { pipeline_id };
+                to_json_value(request.id, &result)
+            }
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    /// Create a pipeline from a structured node/port graph instead of a
+    /// `gst-launch` description.
+    async fn create_pipeline_from_graph(&self, request: Request) -> Response {
+        let params: CreatePipelineFromGraphParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self.manager.add_pipeline_from_graph(&params.graph).await {
             Ok(pipeline_id) => {
                 let result = PipelineCreatedResult { pipeline_id };
                 to_json_value(request.id, &result)
@@ -263,14 +604,8 @@ impl ManagerInterface {
 
         match self.manager.get_position(&pipeline_id).await {
             Ok((position_ns, duration_ns)) => {
-                let progress = match (position_ns, duration_ns) {
-                    (Some(pos), Some(dur)) if dur > 0 => {
-                        // Clamp progress to 0.0..=1.0 range
-                        // (position can briefly exceed duration during seeks)
-                        Some((pos as f64 / dur as f64).clamp(0.0, 1.0))
-                    }
-                    _ => None,
-                };
+                let progress =
+                    crate::gst::pipeline::position_progress(position_ns, duration_ns);
 
                 let result = PositionResult {
                     position_ns,
@@ -283,6 +618,116 @@ impl ManagerInterface {
         }
     }
 
+    /// Seek a pipeline to an absolute position or a fraction of its known
+    /// duration, optionally combined with a playback rate change for
+    /// scrubbing/trick-mode. A position past the known duration is clamped
+    /// to EOS rather than rejected. Completion/failure is reported
+    /// asynchronously via `PipelineEvent::SeekDone`/`SeekFailed` once the
+    /// bus watcher observes the resulting state settle (e.g. a live source
+    /// with no duration answers with `SeekFailed`, not an RPC error, since
+    /// the seek was accepted and only failed once GStreamer processed it).
+    async fn seek(&self, request: Request) -> Response {
+        let params: SeekParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        let position_ns = if let Some(position_ns) = params.position_ns {
+            position_ns
+        } else if let Some(fraction) = params.fraction {
+            if !(0.0..=1.0).contains(&fraction) {
+                return Response::invalid_params(
+                    request.id,
+                    format!("fraction must be between 0.0 and 1.0, got {}", fraction),
+                );
+            }
+            let (_, duration_ns) = match self.manager.get_position(&params.pipeline_id).await {
+                Ok(position) => position,
+                Err(e) => return Response::from_gpop_error(request.id, &e),
+            };
+            let Some(duration_ns) = duration_ns else {
+                return Response::invalid_params(
+                    request.id,
+                    "cannot seek by fraction: pipeline has no known duration".to_string(),
+                );
+            };
+            (fraction * duration_ns as f64) as u64
+        } else {
+            return Response::invalid_params(
+                request.id,
+                "seek requires either position_ns or fraction".to_string(),
+            );
+        };
+
+        match self
+            .manager
+            .seek_with_rate(
+                &params.pipeline_id,
+                position_ns,
+                params.flush.unwrap_or(true),
+                params.accurate.unwrap_or(false),
+                params.rate,
+            )
+            .await
+        {
+            Ok(()) => to_json_value(request.id, &SuccessResult { success: true }),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    /// Enable or disable periodic `PipelineEvent::Progress` reporting for a
+    /// pipeline after creation. See `progress_interval_ms` on
+    /// `create_pipeline` to enable it up front instead.
+    async fn set_progress_reporting(&self, request: Request) -> Response {
+        let params: SetProgressReportingParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        let interval_ms = params.interval_ms.unwrap_or(DEFAULT_PROGRESS_INTERVAL_MS);
+        match self
+            .manager
+            .set_progress_reporting(&params.pipeline_id, interval_ms)
+            .await
+        {
+            Ok(()) => to_json_value(request.id, &SuccessResult { success: true }),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    /// Set a pipeline's automatic restart policy; a `PipelineRestarted`
+    /// event is emitted on each rebuild once the supervisor task (started
+    /// alongside the daemon's `PipelineManager`) acts on it. Passing
+    /// `on_error: false, on_eos: false` (the defaults) leaves the policy
+    /// registered but effectively inert.
+    async fn set_restart_policy(&self, request: Request) -> Response {
+        let params: SetRestartPolicyParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        let policy = RestartPolicy {
+            on_error: params.on_error,
+            on_eos: params.on_eos,
+            max_retries: params.max_retries,
+            backoff_ms: params.backoff_ms,
+        };
+        match self
+            .manager
+            .set_restart_policy(&params.pipeline_id, Some(policy))
+            .await
+        {
+            Ok(()) => to_json_value(request.id, &SuccessResult { success: true }),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
     async fn update_pipeline(&self, request: Request) -> Response {
         let params: UpdatePipelineParams = match serde_json::from_value(request.params) {
             Ok(p) => p,
@@ -303,4 +748,285 @@ impl ManagerInterface {
             Err(e) => Response::from_gpop_error(request.id, &e),
         }
     }
+
+    /// Start a periodic stats poller for a pipeline, streaming `Stats`
+    /// events to subscribers every `interval_ms` until the pipeline is
+    /// removed. Complements the one-shot `snapshot` request.
+    async fn stats_subscribe(&self, request: Request) -> Response {
+        let params: StatsSubscribeParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        // Fail fast if the pipeline doesn't exist rather than polling forever.
+        if let Err(e) = self.manager.get_pipeline_info(&params.pipeline_id).await {
+            return Response::from_gpop_error(request.id, &e);
+        }
+
+        let interval_ms = params.interval_ms.unwrap_or(DEFAULT_STATS_POLL_INTERVAL_MS);
+        start_stats_poller(
+            Arc::clone(&self.manager),
+            params.pipeline_id.clone(),
+            Duration::from_millis(interval_ms),
+        );
+
+        let result = StatsSubscribeResult {
+            pipeline_id: params.pipeline_id,
+            interval_ms,
+        };
+        to_json_value(request.id, &result)
+    }
+
+    /// Create a playlist pipeline that auto-advances through `uris` on EOS
+    /// without tearing the pipeline down between entries.
+    async fn add_playlist(&self, request: Request) -> Response {
+        let params: AddPlaylistParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self
+            .manager
+            .add_playlist(params.uris, params.mode, params.iterations.unwrap_or(1))
+            .await
+        {
+            Ok(pipeline_id) => {
+                let result = PipelineCreatedResult { pipeline_id };
+                to_json_value(request.id, &result)
+            }
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    async fn get_playlist_info(&self, request: Request) -> Response {
+        let params: PipelineIdParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self.manager.get_playlist_info(&params.pipeline_id).await {
+            Ok(info) => to_json_value(request.id, &PlaylistInfoResult::from(info)),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    /// Skip a playlist pipeline straight to its next entry instead of
+    /// waiting for the current one to reach EOS.
+    async fn playlist_next(&self, request: Request) -> Response {
+        let params: PipelineIdParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self.manager.playlist_next(&params.pipeline_id).await {
+            Ok(entry) => to_json_value(request.id, &PlaylistNextResult { entry }),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    /// Negotiated caps of every pad in a pipeline's tree, as JSON, with an
+    /// RFC 6381 MIME codec string alongside each where one is derivable.
+    async fn get_streams(&self, request: Request) -> Response {
+        let params: PipelineIdParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self.manager.get_streams(&params.pipeline_id).await {
+            Ok(streams) => to_json_value(request.id, &GetStreamsResult { streams }),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    /// List a named element's GObject properties, for tweaking a single
+    /// element by name while the pipeline runs instead of rebuilding it.
+    async fn list_element_properties(&self, request: Request) -> Response {
+        let params: ElementPropertiesParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self
+            .manager
+            .element_properties(&params.pipeline_id, &params.element_name)
+            .await
+        {
+            Ok(properties) => {
+                let result = ElementPropertiesResult { properties };
+                to_json_value(request.id, &result)
+            }
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    async fn get_element_property(&self, request: Request) -> Response {
+        let params: GetElementPropertyParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self
+            .manager
+            .get_element_property(&params.pipeline_id, &params.element_name, &params.property_name)
+            .await
+        {
+            Ok(value) => {
+                let result = PropertyValueResult { value };
+                to_json_value(request.id, &result)
+            }
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    async fn set_element_property(&self, request: Request) -> Response {
+        let params: SetElementPropertyParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self
+            .manager
+            .set_element_property(
+                &params.pipeline_id,
+                &params.element_name,
+                &params.property_name,
+                &params.value,
+            )
+            .await
+        {
+            Ok(()) => {
+                let result = SuccessResult { success: true };
+                to_json_value(request.id, &result)
+            }
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    /// Enable (or reconfigure) delay-based adaptive bitrate control on a
+    /// named encoder element, reusing the pipeline's QoS jitter samples as
+    /// its congestion signal (see [`crate::gst::bitrate`]).
+    async fn set_bitrate_limits(&self, request: Request) -> Response {
+        let params: SetBitrateLimitsParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self
+            .manager
+            .set_bitrate_limits(
+                &params.pipeline_id,
+                &params.encoder_name,
+                params.min_bitrate_bps,
+                params.max_bitrate_bps,
+            )
+            .await
+        {
+            Ok(()) => {
+                let result = SuccessResult { success: true };
+                to_json_value(request.id, &result)
+            }
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    async fn add_node(&self, request: Request) -> Response {
+        let params: AddNodeParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self.manager.add_node(&params.pipeline_id, &params.node).await {
+            Ok(()) => to_json_value(request.id, &SuccessResult { success: true }),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    async fn remove_node(&self, request: Request) -> Response {
+        let params: RemoveNodeParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self
+            .manager
+            .remove_node(&params.pipeline_id, &params.element_name)
+            .await
+        {
+            Ok(()) => to_json_value(request.id, &SuccessResult { success: true }),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    async fn link(&self, request: Request) -> Response {
+        let params: LinkParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self.manager.link(&params.pipeline_id, &params.edge).await {
+            Ok(()) => to_json_value(request.id, &SuccessResult { success: true }),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    async fn unlink(&self, request: Request) -> Response {
+        let params: LinkParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self.manager.unlink(&params.pipeline_id, &params.edge).await {
+            Ok(()) => to_json_value(request.id, &SuccessResult { success: true }),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
+
+    /// Bind a pipeline to a named sync group so its playback stays aligned
+    /// with every other pipeline already in that group.
+    async fn join_sync_group(&self, request: Request) -> Response {
+        let params: JoinSyncGroupParams = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::invalid_params(request.id, format!("Invalid params: {}", e))
+            }
+        };
+
+        match self
+            .manager
+            .join_sync_group(&params.pipeline_id, &params.group)
+            .await
+        {
+            Ok(()) => {
+                let result = SuccessResult { success: true };
+                to_json_value(request.id, &result)
+            }
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    }
 }
@@ -6,6 +6,7 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+mod ipc;
 pub mod manager;
 pub mod pipeline;
 pub mod protocol;
@@ -20,6 +21,10 @@ pub const MAX_CONCURRENT_CLIENTS: usize = 1000;
 /// Buffer size for per-client message channels
 pub const CLIENT_MESSAGE_BUFFER: usize = 256;
 
+/// Number of consecutive failed broadcast sends before a client is treated as
+/// dead and dropped from the broadcast map.
+pub const MAX_CONSECUTIVE_SEND_FAILURES: u32 = 5;
+
 /// Default WebSocket port
 pub const DEFAULT_WEBSOCKET_PORT: u16 = 9000;
 
@@ -29,5 +34,26 @@ pub const DEFAULT_BIND_ADDRESS: &str = "127.0.0.1";
 /// Default pipeline ID when not specified
 pub const DEFAULT_PIPELINE_ID: &str = "0";
 
+/// Default poll period for `stats.subscribe` when not specified, in milliseconds
+pub const DEFAULT_STATS_POLL_INTERVAL_MS: u64 = 1000;
+
+/// Default tick period for `set_progress_reporting`/`create_pipeline`'s
+/// `progress_interval_ms` when not specified, in milliseconds
+pub const DEFAULT_PROGRESS_INTERVAL_MS: u64 = 500;
+
+/// Default path for the local IPC socket (Unix domain socket) / named pipe
+#[cfg(unix)]
+pub const DEFAULT_IPC_PATH: &str = "/tmp/gpop.sock";
+
+/// Default path for the local IPC named pipe on Windows
+#[cfg(windows)]
+pub const DEFAULT_IPC_PATH: &str = r"\\.\pipe\gpop";
+
 #[cfg(test)]
 mod protocol_tests;
+
+#[cfg(test)]
+mod server_tests;
+
+#[cfg(test)]
+mod ipc_tests;
@@ -14,7 +14,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::gst::PipelineState;
+use crate::gst::{EdgeSpec, NodeSpec, PipelineState, PlaylistMode, PropertyValue};
 
 // Request parameter types for pipeline operations
 
@@ -49,6 +49,135 @@ pub struct SnapshotParams {
     pub details: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct JoinSyncGroupParams {
+    pub pipeline_id: String,
+    /// Name of the sync group to join; pipelines sharing a name share a
+    /// clock and base time.
+    pub group: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatsSubscribeParams {
+    pub pipeline_id: String,
+    /// Poll period in milliseconds. Defaults to `DEFAULT_STATS_POLL_INTERVAL_MS` if omitted.
+    #[serde(default)]
+    pub interval_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ElementPropertiesParams {
+    pub pipeline_id: String,
+    pub element_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetElementPropertyParams {
+    pub pipeline_id: String,
+    pub element_name: String,
+    pub property_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetElementPropertyParams {
+    pub pipeline_id: String,
+    pub element_name: String,
+    pub property_name: String,
+    pub value: PropertyValue,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AddNodeParams {
+    pub pipeline_id: String,
+    pub node: NodeSpec,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RemoveNodeParams {
+    pub pipeline_id: String,
+    pub element_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinkParams {
+    pub pipeline_id: String,
+    pub edge: EdgeSpec,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetBitrateLimitsParams {
+    pub pipeline_id: String,
+    pub encoder_name: String,
+    pub min_bitrate_bps: u32,
+    pub max_bitrate_bps: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SeekParams {
+    pub pipeline_id: String,
+    /// Absolute target position in nanoseconds. Mutually exclusive with
+    /// `fraction`; one of the two is required.
+    #[serde(default)]
+    pub position_ns: Option<u64>,
+    /// Target position as a fraction (`0.0..=1.0`) of the pipeline's known
+    /// duration. Mutually exclusive with `position_ns`.
+    #[serde(default)]
+    pub fraction: Option<f64>,
+    /// `true` for an exact (possibly slower) seek, `false` to snap to the
+    /// nearest keyframe. Defaults to `false`.
+    #[serde(default)]
+    pub accurate: Option<bool>,
+    /// Whether to flush buffered data for an immediate seek. Defaults to
+    /// `true`; set `false` for a non-flushing seek on pipelines that need
+    /// to preserve already-queued data.
+    #[serde(default)]
+    pub flush: Option<bool>,
+    /// Playback rate to apply together with the seek (negative for
+    /// reverse/trick-mode playback). Omit to keep the current rate.
+    #[serde(default)]
+    pub rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetProgressReportingParams {
+    pub pipeline_id: String,
+    /// Reporting interval in milliseconds. Defaults to
+    /// `DEFAULT_PROGRESS_INTERVAL_MS` if omitted; an explicit `0` disables
+    /// periodic `PipelineEvent::Progress` reporting for this pipeline.
+    #[serde(default)]
+    pub interval_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AddPlaylistParams {
+    pub uris: Vec<String>,
+    pub mode: PlaylistMode,
+    /// Number of times to play through the playlist in `finite` mode.
+    /// Ignored in `infinite` mode. Defaults to 1 if omitted.
+    #[serde(default)]
+    pub iterations: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetRestartPolicyParams {
+    pub pipeline_id: String,
+    /// Automatically rebuild the pipeline from its stored description if it
+    /// reports a bus error. Defaults to `false`.
+    #[serde(default)]
+    pub on_error: bool,
+    /// Automatically rebuild the pipeline from its stored description once
+    /// it reaches EOS. Defaults to `false`.
+    #[serde(default)]
+    pub on_eos: bool,
+    /// Give up once this many restart attempts have been made.
+    #[serde(default)]
+    pub max_retries: u32,
+    /// Base delay before the first restart attempt; doubles on each
+    /// subsequent attempt. Defaults to `0` (restart immediately).
+    #[serde(default)]
+    pub backoff_ms: u64,
+}
+
 // Response result types for pipeline operations
 
 #[derive(Debug, Clone, Serialize)]
@@ -97,3 +226,56 @@ pub struct PositionResult {
 pub struct SuccessResult {
     pub success: bool,
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsSubscribeResult {
+    pub pipeline_id: String,
+    pub interval_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ElementPropertiesResult {
+    pub properties: Vec<crate::gst::PropertyInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PropertyValueResult {
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlaylistInfoResult {
+    pub id: String,
+    pub state: PipelineState,
+    pub current_index: usize,
+    pub current_uri: String,
+    pub len: usize,
+    pub mode: PlaylistMode,
+    pub iterations: u32,
+}
+
+impl From<crate::gst::PlaylistInfo> for PlaylistInfoResult {
+    fn from(info: crate::gst::PlaylistInfo) -> Self {
+        Self {
+            id: info.id,
+            state: info.state,
+            current_index: info.current_index,
+            current_uri: info.current_uri,
+            len: info.len,
+            mode: info.mode,
+            iterations: info.iterations,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlaylistNextResult {
+    /// `(index, uri)` now playing, or `None` if the playlist has run through
+    /// all of its iterations and the pipeline has reached end of stream.
+    pub entry: Option<(usize, String)>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GetStreamsResult {
+    pub streams: Vec<crate::gst::PadStreamInfo>,
+}
@@ -14,6 +14,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::error::Severity;
+
 /// JSON-RPC 2.0 standard error codes
 pub mod error_codes {
     /// Parse error - Invalid JSON was received
@@ -42,11 +44,21 @@ pub mod error_codes {
     pub const DESCRIPTION_TOO_LONG: i32 = -32004;
     /// Media not supported (missing codec, unsupported format, hardware limitation)
     pub const MEDIA_NOT_SUPPORTED: i32 = -32005;
+    /// `handshake` rejected a client that declared an incompatible
+    /// `client_protocol_version`
+    pub const PROTOCOL_VERSION_MISMATCH: i32 = -32006;
 }
 
 /// JSON-RPC 2.0 version string
 pub const JSONRPC_VERSION: &str = "2.0";
 
+/// This daemon's application-level protocol version, distinct from
+/// `JSONRPC_VERSION` (the JSON-RPC envelope spec, which never changes):
+/// bumped whenever a change to method semantics or event shapes could break
+/// an older client. Clients should call `handshake` before relying on
+/// anything beyond `list_methods`.
+pub const GPOP_PROTOCOL_VERSION: u32 = 1;
+
 fn default_jsonrpc_version() -> String {
     JSONRPC_VERSION.to_string()
 }
@@ -80,12 +92,54 @@ pub struct Response {
     pub result: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ErrorInfo>,
+    /// Whether this reply is a `Success`, a recoverable `Failure`, or a
+    /// `Fatal` condition the client shouldn't keep issuing requests over.
+    /// Carried on every reply, not just errors, so a client can branch on
+    /// this field alone instead of also checking `result`/`error`.
+    pub severity: Severity,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ErrorInfo {
     pub code: i32,
     pub message: String,
+    /// Present only for errors derived from a `GpopError`, where retriability
+    /// is known; absent for protocol-level errors (parse/invalid request/
+    /// method not found) where "retry" isn't a meaningful question.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<ErrorData>,
+}
+
+/// Retry guidance for a JSON-RPC error, so a client can tell whether
+/// reissuing the call stands a chance instead of always having to guess.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorData {
+    pub retriable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after_ms: Option<u64>,
+}
+
+/// A JSON-RPC 2.0 notification: a server-pushed message carrying `method` +
+/// `params`, like a `Request`, but with no `id` field at all (not even
+/// `null`) so a client can never mistake it for a response to one of its own
+/// calls. Used to push pipeline events (`state_changed`, `eos`, `error`,
+/// `stats`, ...) to clients subscribed to the matching topic, instead of
+/// them having to poll for changes.
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub jsonrpc: &'static str,
+    pub method: String,
+    pub params: Value,
+}
+
+impl Notification {
+    pub fn new(method: impl Into<String>, params: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            method: method.into(),
+            params,
+        }
+    }
 }
 
 impl Response {
@@ -95,6 +149,7 @@ impl Response {
             id,
             result: Some(result),
             error: None,
+            severity: Severity::Success,
         }
     }
 
@@ -103,7 +158,12 @@ impl Response {
             jsonrpc: JSONRPC_VERSION,
             id,
             result: None,
-            error: Some(ErrorInfo { code, message }),
+            error: Some(ErrorInfo {
+                code,
+                message,
+                data: None,
+            }),
+            severity: severity_for_code(code),
         }
     }
 
@@ -150,13 +210,43 @@ impl Response {
                 format!("Pipeline not found: {}", pid),
             ),
             GpopError::InvalidPipeline(msg) => (error_codes::PIPELINE_CREATION_FAILED, msg.clone()),
+            GpopError::DescriptionTooLong(msg) => {
+                (error_codes::DESCRIPTION_TOO_LONG, msg.clone())
+            }
             GpopError::StateChangeFailed(msg) => (error_codes::STATE_CHANGE_FAILED, msg.clone()),
             GpopError::MediaNotSupported(msg) => (error_codes::MEDIA_NOT_SUPPORTED, msg.clone()),
             GpopError::GStreamer(msg) => (error_codes::GSTREAMER_ERROR, msg.clone()),
             _ => (error_codes::INTERNAL_ERROR, err.to_string()),
         };
 
-        Self::error(id, code, message)
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            id,
+            result: None,
+            error: Some(ErrorInfo {
+                code,
+                message,
+                data: Some(ErrorData {
+                    retriable: err.is_retriable(),
+                    retry_after_ms: err.retry_after_ms(),
+                }),
+            }),
+            severity: err.severity(),
+        }
+    }
+}
+
+/// Classify a protocol-level error code (one with no originating `GpopError`
+/// to consult) as `Failure` or `Fatal`. Malformed JSON, a malformed request
+/// envelope, and an internal serialization bug all indicate something wrong
+/// with this exchange rather than just this request, so they're `Fatal`;
+/// everything else (bad method name, bad params) is a recoverable `Failure`.
+fn severity_for_code(code: i32) -> Severity {
+    match code {
+        error_codes::PARSE_ERROR | error_codes::INVALID_REQUEST | error_codes::INTERNAL_ERROR => {
+            Severity::Fatal
+        }
+        _ => Severity::Failure,
     }
 }
 
@@ -165,6 +255,37 @@ impl Response {
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreatePipelineParams {
     pub description: String,
+    /// Opt-in periodic `PipelineEvent::Progress` reporting interval, in
+    /// milliseconds, enabled right after the pipeline is created. Omit to
+    /// leave progress reporting off, as for `create_pipeline` callers that
+    /// never set it via `set_progress_reporting` either.
+    #[serde(default)]
+    pub progress_interval_ms: Option<u64>,
+}
+
+/// Parameters for `create_pipeline_from_graph`: a structured node/port graph
+/// instead of a `gst-launch` description.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreatePipelineFromGraphParams {
+    pub graph: crate::gst::GraphSpec,
+}
+
+/// Parameters for `subscribe`. Omitting both fields means "all
+/// pipelines"/"all event kinds" respectively; omitting both together is the
+/// wildcard subscription that preserves the old firehose behavior.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SubscriptionParams {
+    #[serde(default)]
+    pub pipeline_id: Option<String>,
+    #[serde(default)]
+    pub event_kind: Option<String>,
+}
+
+/// Parameters for `unsubscribe`: the id returned by the `subscribe` call
+/// being cancelled.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UnsubscribeParams {
+    pub subscription_id: u64,
 }
 
 // Manager-level response result types
@@ -174,11 +295,43 @@ pub struct PipelineCreatedResult {
     pub pipeline_id: String,
 }
 
+/// Result of a `subscribe` call: an id the client can later pass to
+/// `unsubscribe` to stop receiving notifications for this topic.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubscribeResult {
+    pub subscription_id: u64,
+}
+
+/// Result of an `unsubscribe` call: whether a subscription with that id was
+/// actually found and removed.
+#[derive(Debug, Clone, Serialize)]
+pub struct UnsubscribeResult {
+    pub success: bool,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ListPipelinesResult {
     pub pipelines: Vec<super::pipeline::PipelineInfoResult>,
 }
 
+/// Per-connection health, so operators can see which clients are struggling
+/// to keep up with event delivery before they get dropped outright.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionStat {
+    pub id: String,
+    /// Lifetime count of events dropped for this client because its queue
+    /// was full.
+    pub dropped_events: usize,
+    /// Consecutive failed sends since the last successful one; the
+    /// connection is torn down once this hits `MAX_CONSECUTIVE_SEND_FAILURES`.
+    pub consecutive_failures: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionStatsResult {
+    pub connections: Vec<ConnectionStat>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct VersionResult {
     pub version: String,
@@ -188,3 +341,52 @@ pub struct VersionResult {
 pub struct PipelineCountResult {
     pub count: usize,
 }
+
+/// One entry in `list_methods`' result: enough for a tool or the web UI to
+/// feature-detect a method before calling it.
+#[derive(Debug, Clone, Serialize)]
+pub struct MethodInfo {
+    pub name: String,
+    pub description: String,
+}
+
+/// Result of `list_methods`: every method registered in
+/// `ManagerInterface`'s dispatch table. Does not include `snapshot`,
+/// `subscribe` and `unsubscribe`, which `server.rs` dispatches directly
+/// without going through that table.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListMethodsResult {
+    pub methods: Vec<MethodInfo>,
+}
+
+/// Parameters for `handshake`. `client_protocol_version`, if given, is
+/// checked against `GPOP_PROTOCOL_VERSION` and rejected with
+/// `PROTOCOL_VERSION_MISMATCH` rather than failing obscurely later on a
+/// method or field the server doesn't have.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HandshakeParams {
+    #[serde(default)]
+    pub client_protocol_version: Option<u32>,
+}
+
+/// Daemon capabilities that aren't visible from `list_methods` alone,
+/// because `snapshot`/`subscribe`/`unsubscribe` are dispatched directly by
+/// `server.rs` ahead of the method table (see `ListMethodsResult`) and IPC
+/// availability is a transport-level, not a method-level, concern.
+#[derive(Debug, Clone, Serialize)]
+pub struct Features {
+    pub snapshot: bool,
+    pub subscribe: bool,
+    pub ipc: bool,
+}
+
+/// Result of `handshake`: enough for a client to decide whether it can talk
+/// to this daemon at all, and which optional features to use, before making
+/// any other call.
+#[derive(Debug, Clone, Serialize)]
+pub struct HandshakeResult {
+    pub protocol_version: u32,
+    pub daemon_version: String,
+    pub methods: Vec<String>,
+    pub features: Features,
+}
@@ -8,6 +8,7 @@
 
 use super::pipeline::*;
 use super::protocol::*;
+use crate::error::Severity;
 use crate::event::PipelineState;
 
 #[test]
@@ -98,6 +99,51 @@ fn test_response_error() {
     assert_eq!(error.message, "Invalid request");
 }
 
+#[test]
+fn test_response_success_severity() {
+    let response = Response::success("1".to_string(), serde_json::json!({}));
+    assert_eq!(response.severity, Severity::Success);
+
+    let json = serde_json::to_string(&response).unwrap();
+    assert!(json.contains("\"severity\":\"Success\""));
+}
+
+#[test]
+fn test_response_error_severity_by_code() {
+    let fatal = Response::error("1".to_string(), error_codes::PARSE_ERROR, "bad json".to_string());
+    assert_eq!(fatal.severity, Severity::Fatal);
+
+    let failure = Response::error(
+        "2".to_string(),
+        error_codes::PIPELINE_NOT_FOUND,
+        "not found".to_string(),
+    );
+    assert_eq!(failure.severity, Severity::Failure);
+}
+
+#[test]
+fn test_response_from_gpop_error_severity() {
+    use crate::error::GpopError;
+
+    let recoverable = Response::from_gpop_error(
+        "1".to_string(),
+        &GpopError::PipelineNotFound("pipeline-0".to_string()),
+    );
+    assert_eq!(recoverable.severity, Severity::Failure);
+
+    let fatal = Response::from_gpop_error("2".to_string(), &GpopError::WebSocket("closed".to_string()));
+    assert_eq!(fatal.severity, Severity::Fatal);
+}
+
+#[test]
+fn test_severity_serde_round_trip() {
+    for severity in [Severity::Success, Severity::Failure, Severity::Fatal] {
+        let json = serde_json::to_string(&severity).unwrap();
+        let parsed: Severity = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, severity);
+    }
+}
+
 #[test]
 fn test_response_serialization_skips_none() {
     let success = Response::success("1".to_string(), serde_json::json!({}));
@@ -228,3 +274,16 @@ fn test_pipeline_created_result() {
     let json = serde_json::to_string(&result).unwrap();
     assert_eq!(json, r#"{"pipeline_id":"pipeline-0"}"#);
 }
+
+#[test]
+fn test_list_methods_result() {
+    let result = ListMethodsResult {
+        methods: vec![MethodInfo {
+            name: "list_pipelines".to_string(),
+            description: "List all managed pipelines".to_string(),
+        }],
+    };
+    let json = serde_json::to_string(&result).unwrap();
+    assert!(json.contains("\"name\":\"list_pipelines\""));
+    assert!(json.contains("\"description\":\"List all managed pipelines\""));
+}
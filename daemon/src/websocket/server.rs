@@ -7,17 +7,21 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 use std::collections::HashMap;
+use std::fmt;
 use std::net::SocketAddr;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
 
+use dashmap::DashMap;
 use futures_util::{SinkExt, StreamExt};
 use subtle::ConstantTimeEq;
+use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::mpsc;
 use tokio_tungstenite::tungstenite::handshake::server::{
     ErrorResponse, Request as WsRequest, Response as WsResponse,
 };
-use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::http::{HeaderValue, StatusCode};
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, error, info, warn};
 
@@ -25,12 +29,224 @@ use crate::error::Result;
 use crate::gst::{EventReceiver, PipelineManager};
 
 use super::manager::ManagerInterface;
-use super::pipeline::SnapshotParams;
-use super::protocol::Request;
-use super::{CLIENT_MESSAGE_BUFFER, MAX_CONCURRENT_CLIENTS};
+use super::pipeline::{PipelineInfoResult, SnapshotParams};
+use super::protocol::{
+    error_codes, ConnectionStat, ConnectionStatsResult, ListPipelinesResult, Notification,
+    Request, Response, SubscribeResult, SubscriptionParams, UnsubscribeParams, UnsubscribeResult,
+};
+use super::{CLIENT_MESSAGE_BUFFER, MAX_CONCURRENT_CLIENTS, MAX_CONSECUTIVE_SEND_FAILURES};
+
+/// Identifies a connected client regardless of which transport it arrived on.
+/// The local IPC transport (Unix socket / Windows named pipe) has no peer
+/// address, so it is keyed by a monotonically increasing connection counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClientId {
+    Tcp(SocketAddr),
+    Ipc(u64),
+}
+
+impl fmt::Display for ClientId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientId::Tcp(addr) => write!(f, "tcp:{}", addr),
+            ClientId::Ipc(n) => write!(f, "ipc:{}", n),
+        }
+    }
+}
+
+static NEXT_IPC_CLIENT_ID: AtomicU64 = AtomicU64::new(0);
+
+pub(super) fn next_ipc_client_id() -> u64 {
+    NEXT_IPC_CLIENT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Subscription ids are handed out from a single global counter (rather than
+/// per-connection) so they're usable as opaque correlation ids in logs
+/// without also needing the `ClientId` to disambiguate them.
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(1);
+
+fn next_subscription_id() -> u64 {
+    NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// WebSocket subprotocol a client advertises (via `Sec-WebSocket-Protocol`) to
+/// request CBOR binary framing instead of the default JSON text framing.
+const BINARY_SUBPROTOCOL: &str = "gpop-binary";
+
+pub(super) type ClientTx = mpsc::Sender<Message>;
+
+/// A single event filter: `None` in either field means "match any". A filter
+/// with both fields `None` is the wildcard subscription that preserves the
+/// historical "send everything" behavior.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Subscription {
+    pipeline_id: Option<String>,
+    event_kind: Option<String>,
+}
+
+impl Subscription {
+    pub(crate) fn wildcard() -> Self {
+        Self {
+            pipeline_id: None,
+            event_kind: None,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn new(pipeline_id: Option<&str>, event_kind: Option<&str>) -> Self {
+        Self {
+            pipeline_id: pipeline_id.map(str::to_string),
+            event_kind: event_kind.map(str::to_string),
+        }
+    }
+
+    pub(crate) fn matches(&self, pipeline_id: Option<&str>, event_kind: &str) -> bool {
+        let kind_matches = self.event_kind.as_deref().map_or(true, |k| k == event_kind);
+        let pipeline_matches = match (&self.pipeline_id, pipeline_id) {
+            (None, _) => true,
+            (Some(want), Some(got)) => want == got,
+            (Some(_), None) => false,
+        };
+        kind_matches && pipeline_matches
+    }
+}
+
+/// Per-client broadcast state: the channel events are pushed through, the
+/// subscriptions the client currently holds (keyed by the id returned from
+/// `subscribe`, per the karyon jsonrpc pubsub model), and a consecutive-
+/// failure counter used to garbage-collect slow/dead clients.
+struct ClientEntry {
+    pub(super) tx: ClientTx,
+    /// Starts with a single reserved entry covering the wildcard filter, so
+    /// clients that never call `subscribe` keep getting every event,
+    /// matching the pre-filtering behavior. Replaced entirely the first time
+    /// the client calls `subscribe`/`unsubscribe`.
+    subscriptions: StdMutex<HashMap<u64, Subscription>>,
+    /// Set once the client issues its first `subscribe`/`unsubscribe` call,
+    /// so that call can replace the default wildcard instead of adding to it.
+    customized: AtomicBool,
+    consecutive_failures: AtomicU32,
+    /// Lifetime count of events dropped for this client (broadcast sends that
+    /// found the per-client channel full). Unlike `consecutive_failures`,
+    /// this never resets, so it can be surfaced later as a per-client health
+    /// metric without re-deriving it from the broadcast loop.
+    dropped_events: AtomicUsize,
+    /// Set whenever an event was dropped for this client (buffer full) or a
+    /// global broadcast lag was observed, and cleared once the broadcaster
+    /// has sent it a `PipelineEvent::Resync` plus a fresh pipeline snapshot.
+    /// Checked ahead of the next event this client is eligible for, so a
+    /// struggling client's view is repaired as soon as it drains.
+    missed_events: AtomicBool,
+    /// Whether this client negotiated CBOR binary framing (`gpop-binary`
+    /// subprotocol) during the handshake. Fixed for the lifetime of the
+    /// connection, so a plain `bool` is enough.
+    binary: bool,
+}
+
+/// Reserved id for the implicit wildcard subscription every connection
+/// starts with, before it has ever issued a `subscribe`/`unsubscribe` call.
+const DEFAULT_SUBSCRIPTION_ID: u64 = 0;
+
+impl ClientEntry {
+    pub(super) fn new(tx: ClientTx, binary: bool) -> Self {
+        Self {
+            tx,
+            subscriptions: StdMutex::new(HashMap::from([(
+                DEFAULT_SUBSCRIPTION_ID,
+                Subscription::wildcard(),
+            )])),
+            customized: AtomicBool::new(false),
+            consecutive_failures: AtomicU32::new(0),
+            dropped_events: AtomicUsize::new(0),
+            missed_events: AtomicBool::new(false),
+            binary,
+        }
+    }
+
+    /// Add a new subscription, clearing the default wildcard on the
+    /// client's first explicit `subscribe`/`unsubscribe` call. Returns the id
+    /// the client can later pass to `unsubscribe`.
+    fn add_subscription(&self, sub: Subscription) -> u64 {
+        let mut subs = self.subscriptions.lock().unwrap();
+        if !self.customized.swap(true, Ordering::Relaxed) {
+            subs.clear();
+        }
+        let id = next_subscription_id();
+        subs.insert(id, sub);
+        id
+    }
+
+    /// Drop a subscription by id, also clearing the default wildcard if this
+    /// is the client's first explicit call. Returns whether an entry with
+    /// that id actually existed.
+    fn remove_subscription(&self, subscription_id: u64) -> bool {
+        let mut subs = self.subscriptions.lock().unwrap();
+        if !self.customized.swap(true, Ordering::Relaxed) {
+            subs.clear();
+        }
+        subs.remove(&subscription_id).is_some()
+    }
+
+    fn wants(&self, pipeline_id: Option<&str>, event_kind: &str) -> bool {
+        self.subscriptions
+            .lock()
+            .unwrap()
+            .values()
+            .any(|sub| sub.matches(pipeline_id, event_kind))
+    }
+}
+
+/// Concurrent client registry backing the broadcast hot path. Connections are
+/// stored in a sharded map (`dashmap`) instead of behind a single
+/// `RwLock<HashMap>`, so the broadcaster's per-event fan-out never blocks on
+/// another connection registering or disconnecting. The live client count is
+/// tracked separately as an `AtomicUsize` so the `MAX_CONCURRENT_CLIENTS`
+/// check doesn't need to touch the map at all.
+pub(super) struct ClientRegistry {
+    clients: DashMap<ClientId, ClientEntry>,
+    count: AtomicUsize,
+}
+
+impl ClientRegistry {
+    fn new() -> Self {
+        Self {
+            clients: DashMap::new(),
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn for_test() -> Arc<Self> {
+        Arc::new(Self::new())
+    }
+
+    pub(super) fn insert(&self, id: ClientId, entry: ClientEntry) {
+        self.clients.insert(id, entry);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn remove(&self, id: &ClientId) {
+        if self.clients.remove(id).is_some() {
+            self.count.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Live client count, read from the atomic counter rather than locking
+    /// and scanning the map.
+    pub(super) fn len(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+impl std::ops::Deref for ClientRegistry {
+    type Target = DashMap<ClientId, ClientEntry>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.clients
+    }
+}
 
-type ClientTx = mpsc::Sender<Message>;
-type ClientMap = Arc<RwLock<HashMap<SocketAddr, ClientTx>>>;
+pub(super) type ClientMap = Arc<ClientRegistry>;
 
 /// Serialize a value to JSON, returning an error JSON response if serialization fails.
 /// This should never fail for well-typed structs, but we handle it gracefully.
@@ -42,12 +258,135 @@ fn serialize_or_error<T: serde::Serialize>(value: &T) -> String {
     })
 }
 
+/// Encode a response/event payload for a client according to its negotiated
+/// framing: JSON text by default, or CBOR binary when the client requested
+/// the `gpop-binary` subprotocol. CBOR is most valuable for the largest
+/// payloads (`snapshot` dot graphs, `stats` dumps), but is applied uniformly
+/// since every payload type is `Serialize`.
+fn encode_message<T: serde::Serialize>(value: &T, binary: bool) -> Message {
+    if binary {
+        match serde_cbor::to_vec(value) {
+            Ok(bytes) => return Message::Binary(bytes.into()),
+            Err(e) => error!("CBOR serialization failed, falling back to JSON: {}", e),
+        }
+    }
+    Message::Text(serialize_or_error(value).into())
+}
+
+/// Send a `PipelineEvent::Resync` followed by a full `list_pipelines`-style
+/// snapshot to a single client so it can rebuild its view after falling
+/// behind. Best-effort: uses `try_send` like the regular broadcast path, so
+/// a still-backed-up client just gets another chance on its next drain.
+async fn send_resync(entry: &ClientEntry, dropped: usize, manager: &PipelineManager) {
+    let resync_value = serde_json::to_value(crate::gst::PipelineEvent::Resync { dropped })
+        .unwrap_or(serde_json::Value::Null);
+    let (resync_notification, _, _) = event_to_notification(&resync_value);
+
+    let infos = manager.list_pipelines().await;
+    let snapshot = ListPipelinesResult {
+        pipelines: infos.into_iter().map(PipelineInfoResult::from).collect(),
+    };
+    let snapshot_value = serde_json::to_value(&snapshot).unwrap_or(serde_json::Value::Null);
+    let snapshot_notification = Notification::new("pipelines_snapshot", snapshot_value);
+
+    for notification in [resync_notification, snapshot_notification] {
+        let _ = entry.tx.try_send(encode_message(&notification, entry.binary));
+    }
+}
+
+/// Turn a `PipelineEvent` into the `Notification` pushed to subscribed
+/// clients, plus the `(event_kind, pipeline_id)` pair used to match it
+/// against per-client filters. `PipelineEvent` is tagged as
+/// `{"event": "...", "data": {...}}`, so the notification's `method` is the
+/// event tag and its `params` is the tag's `data`.
+pub(crate) fn event_to_notification(
+    event: &serde_json::Value,
+) -> (Notification, String, Option<String>) {
+    let kind = event
+        .get("event")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    let data = event.get("data").cloned().unwrap_or(serde_json::Value::Null);
+    let pipeline_id = data
+        .get("pipeline_id")
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    (Notification::new(kind.clone(), data), kind, pipeline_id)
+}
+
+/// Handle a `connection_stats` request: report every connected client's
+/// dropped-event and consecutive-failure counters, which live in the
+/// broadcast `ClientMap` rather than the `ManagerInterface`. See
+/// `ClientEntry::dropped_events`/`missed_events` for how these accumulate.
+fn handle_connection_stats(clients: &ClientMap) -> ConnectionStatsResult {
+    let connections = clients
+        .iter()
+        .map(|client| ConnectionStat {
+            id: client.key().to_string(),
+            dropped_events: client.value().dropped_events.load(Ordering::Relaxed),
+            consecutive_failures: client.value().consecutive_failures.load(Ordering::Relaxed),
+        })
+        .collect();
+    ConnectionStatsResult { connections }
+}
+
+/// Handle a `subscribe` request: register a new filter for the calling
+/// client and hand back the id it can later pass to `unsubscribe`.
+async fn handle_subscribe(clients: &ClientMap, id: ClientId, request: Request) -> Response {
+    let params: SubscriptionParams = serde_json::from_value(request.params).unwrap_or_default();
+    let sub = Subscription {
+        pipeline_id: params.pipeline_id,
+        event_kind: params.event_kind,
+    };
+
+    match clients.get(&id) {
+        Some(entry) => {
+            let subscription_id = entry.add_subscription(sub);
+            Response::success(
+                request.id,
+                serde_json::to_value(SubscribeResult { subscription_id }).unwrap(),
+            )
+        }
+        None => Response::error(
+            request.id,
+            error_codes::INTERNAL_ERROR,
+            "Client not registered".to_string(),
+        ),
+    }
+}
+
+/// Handle an `unsubscribe` request: drop the named subscription id from the
+/// calling client's filter set, stopping further notifications for it.
+async fn handle_unsubscribe(clients: &ClientMap, id: ClientId, request: Request) -> Response {
+    let params: UnsubscribeParams = match serde_json::from_value(request.params) {
+        Ok(p) => p,
+        Err(e) => return Response::invalid_params(request.id, format!("Invalid params: {}", e)),
+    };
+
+    match clients.get(&id) {
+        Some(entry) => {
+            let success = entry.remove_subscription(params.subscription_id);
+            Response::success(
+                request.id,
+                serde_json::to_value(UnsubscribeResult { success }).unwrap(),
+            )
+        }
+        None => Response::error(
+            request.id,
+            error_codes::INTERNAL_ERROR,
+            "Client not registered".to_string(),
+        ),
+    }
+}
+
 pub struct WebSocketServer {
     addr: SocketAddr,
     manager: Arc<PipelineManager>,
     clients: ClientMap,
     api_key: Option<String>,
     allowed_origins: Option<Vec<String>>,
+    ipc_path: Option<std::path::PathBuf>,
 }
 
 impl WebSocketServer {
@@ -60,12 +399,23 @@ impl WebSocketServer {
         Self {
             addr,
             manager,
-            clients: Arc::new(RwLock::new(HashMap::new())),
+            clients: Arc::new(ClientRegistry::new()),
             api_key,
             allowed_origins,
+            ipc_path: None,
         }
     }
 
+    /// Enable the local IPC transport (Unix domain socket on Unix, named pipe on
+    /// Windows) in addition to the TCP listener. Clients connecting over this
+    /// transport are authenticated by filesystem permissions on `path` rather
+    /// than the API key, since only local processes with access to the socket
+    /// file can reach it.
+    pub fn with_ipc_path(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.ipc_path = Some(path.into());
+        self
+    }
+
     pub async fn run(self, mut event_rx: EventReceiver) -> Result<()> {
         let listener = TcpListener::bind(&self.addr).await?;
         info!("WebSocket server listening on ws://{}", self.addr);
@@ -77,23 +427,102 @@ impl WebSocketServer {
 
         // Spawn event broadcaster
         let broadcast_clients = Arc::clone(&clients);
+        let broadcast_manager = Arc::clone(&manager);
         tokio::spawn(async move {
             loop {
                 match event_rx.recv().await {
                     Ok(event) => {
-                        // Serialize once, then clone for each client
-                        // Note: Message::Text requires owned String, so we must clone per-client
-                        let msg = serialize_or_error(&event);
-                        let clients = broadcast_clients.read().await;
-                        for (addr, tx) in clients.iter() {
+                        // Wrap the event as a notification (method + params,
+                        // no id) and serialize once per framing, then clone
+                        // per client.
+                        let event_value = serde_json::to_value(&event)
+                            .unwrap_or(serde_json::Value::Null);
+                        let (notification, event_kind, pipeline_id) =
+                            event_to_notification(&event_value);
+                        let msg = serialize_or_error(&notification);
+                        let cbor_msg = match serde_cbor::to_vec(&notification) {
+                            Ok(bytes) => Some(bytes),
+                            Err(e) => {
+                                error!("CBOR serialization failed: {}", e);
+                                None
+                            }
+                        };
+
+                        // Clients that fell behind since the last event they
+                        // were eligible for get resynced (Resync + a fresh
+                        // snapshot) right before this one, so they never
+                        // silently diverge while waiting on a topic that may
+                        // not fire again soon.
+                        let mut needs_resync = Vec::new();
+                        for client in broadcast_clients.iter() {
+                            let entry = client.value();
+                            if entry.wants(pipeline_id.as_deref(), &event_kind)
+                                && entry.missed_events.load(Ordering::Relaxed)
+                            {
+                                needs_resync.push(*client.key());
+                            }
+                        }
+                        for id in needs_resync {
+                            if let Some(entry) = broadcast_clients.get(&id) {
+                                if entry.missed_events.swap(false, Ordering::Relaxed) {
+                                    let dropped = entry.dropped_events.load(Ordering::Relaxed);
+                                    send_resync(entry.value(), dropped, &broadcast_manager).await;
+                                }
+                            }
+                        }
+
+                        let mut stale = Vec::new();
+                        for client in broadcast_clients.iter() {
+                            let id = *client.key();
+                            let entry = client.value();
+                            if !entry.wants(pipeline_id.as_deref(), &event_kind) {
+                                continue;
+                            }
+                            let payload = match (entry.binary, &cbor_msg) {
+                                (true, Some(bytes)) => Message::Binary(bytes.clone().into()),
+                                _ => Message::Text(msg.clone().into()),
+                            };
                             // Use try_send to avoid blocking; if buffer is full, client is slow
-                            if tx.try_send(Message::Text(msg.clone().into())).is_err() {
-                                debug!("Failed to send event to client {} (buffer full or disconnected)", addr);
+                            if entry.tx.try_send(payload).is_ok() {
+                                entry.consecutive_failures.store(0, Ordering::Relaxed);
+                            } else {
+                                entry.dropped_events.fetch_add(1, Ordering::Relaxed);
+                                entry.missed_events.store(true, Ordering::Relaxed);
+                                let failures =
+                                    entry.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+                                debug!(
+                                    "Failed to send event to client {} ({} consecutive failures)",
+                                    id, failures
+                                );
+                                if failures >= MAX_CONSECUTIVE_SEND_FAILURES {
+                                    warn!(
+                                        "Dropping client {} after {} consecutive failed sends",
+                                        id, failures
+                                    );
+                                    stale.push(id);
+                                }
                             }
                         }
+                        // Drop stale clients after the iterator above has released its
+                        // shard guards, so this never contends with the loop itself.
+                        for id in stale {
+                            broadcast_clients.remove(&id);
+                        }
                     }
                     Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
                         warn!("WebSocket broadcaster lagged by {} messages", n);
+                        // The single receiver backing this loop missed `n`
+                        // events from the source, so every connected client
+                        // (not just ones with a full per-client channel) may
+                        // have missed something. Flag them all for resync
+                        // ahead of the next event they're eligible for.
+                        for client in broadcast_clients.iter() {
+                            client
+                                .value()
+                                .dropped_events
+                                .fetch_add(n as usize, Ordering::Relaxed);
+                            client.value().missed_events.store(true, Ordering::Relaxed);
+                        }
                     }
                     Err(tokio::sync::broadcast::error::RecvError::Closed) => {
                         info!("Event channel closed, stopping WebSocket broadcaster");
@@ -103,7 +532,18 @@ impl WebSocketServer {
             }
         });
 
-        // Accept connections
+        // Spawn the local IPC listener (Unix socket / named pipe) alongside TCP
+        if let Some(path) = self.ipc_path.clone() {
+            let clients = Arc::clone(&clients);
+            let manager = Arc::clone(&manager);
+            tokio::spawn(async move {
+                if let Err(e) = super::ipc::run_ipc_listener(path, clients, manager).await {
+                    error!("IPC listener error: {}", e);
+                }
+            });
+        }
+
+        // Accept TCP connections
         loop {
             match listener.accept().await {
                 Ok((stream, addr)) => {
@@ -111,13 +551,15 @@ impl WebSocketServer {
                     let manager = Arc::clone(&manager);
                     let api_key = api_key.clone();
                     let allowed_origins = allowed_origins.clone();
+                    let ipc_enabled = self.ipc_path.is_some();
                     tokio::spawn(handle_connection(
                         stream,
-                        addr,
+                        ClientId::Tcp(addr),
                         clients,
                         manager,
                         api_key,
                         allowed_origins,
+                        ipc_enabled,
                     ));
                 }
                 Err(e) => {
@@ -132,35 +574,193 @@ impl WebSocketServer {
     }
 }
 
-async fn handle_connection(
-    stream: TcpStream,
-    addr: SocketAddr,
+/// Send an already-encoded reply to a client, dropping it silently if the
+/// client has since disconnected (mirrors the broadcaster's best-effort send).
+async fn send_to_client(clients: &ClientMap, id: ClientId, msg: Message) {
+    if let Some(entry) = clients.get(&id) {
+        let _ = entry.tx.try_send(msg);
+    }
+}
+
+/// Dispatch a decoded request to the right handler and encode the reply in
+/// the framing (`binary`) the client negotiated at handshake time. Shared by
+/// both the JSON text and CBOR binary message paths.
+pub(super) async fn dispatch_request(
+    handler: &ManagerInterface,
+    clients: &ClientMap,
+    id: ClientId,
+    binary: bool,
+    request: Request,
+) -> Message {
+    // Handle snapshot specially - returns direct response without JSON-RPC wrapper
+    if request.method == "snapshot" {
+        let params: SnapshotParams = serde_json::from_value(request.params).unwrap_or_default();
+        match handler.snapshot(params).await {
+            Ok(result) => encode_message(&result, binary),
+            Err(e) => {
+                let response = Response::from_gpop_error(request.id, &e);
+                encode_message(&response, binary)
+            }
+        }
+    } else {
+        let response = dispatch_one(handler, clients, id, request).await;
+        encode_message(&response, binary)
+    }
+}
+
+/// Dispatch a single decoded request to the right handler, always returning a
+/// proper JSON-RPC `Response`. Used directly by batch dispatch, where every
+/// element of the batch must come back wrapped the same way (including
+/// `snapshot`, which `dispatch_request` special-cases to skip the wrapper
+/// when it's the only request in the message).
+async fn dispatch_one(
+    handler: &ManagerInterface,
+    clients: &ClientMap,
+    id: ClientId,
+    request: Request,
+) -> Response {
+    if request.method == "snapshot" {
+        let params: SnapshotParams = serde_json::from_value(request.params).unwrap_or_default();
+        match handler.snapshot(params).await {
+            Ok(result) => Response::success(
+                request.id,
+                serde_json::to_value(result).unwrap_or(serde_json::Value::Null),
+            ),
+            Err(e) => Response::from_gpop_error(request.id, &e),
+        }
+    } else if request.method == "subscribe" {
+        // Subscribe/unsubscribe mutate this connection's own filter set, which
+        // lives in the broadcast ClientMap rather than the ManagerInterface.
+        handle_subscribe(clients, id, request).await
+    } else if request.method == "unsubscribe" {
+        handle_unsubscribe(clients, id, request).await
+    } else if request.method == "connection_stats" {
+        Response::success(
+            request.id,
+            serde_json::to_value(handle_connection_stats(clients))
+                .unwrap_or(serde_json::Value::Null),
+        )
+    } else {
+        handler.handle(request).await
+    }
+}
+
+/// Dispatch one element of a JSON-RPC 2.0 batch. An element with no `id`
+/// field is a notification: it still executes, but contributes no entry to
+/// the batch's reply array. An element that isn't a well-formed `Request` is
+/// reported as `invalid_request` unless it's a malformed notification, which
+/// is simply dropped (there's no id to reply against, and nothing to run).
+async fn dispatch_batch_element(
+    handler: &ManagerInterface,
+    clients: &ClientMap,
+    id: ClientId,
+    value: serde_json::Value,
+) -> Option<Response> {
+    let has_id = value.get("id").is_some();
+    let request: Request = match serde_json::from_value(value.clone()) {
+        Ok(request) => request,
+        Err(e) => {
+            return has_id.then(|| {
+                let req_id = value
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+                Response::invalid_request(req_id, format!("Invalid Request: {}", e))
+            });
+        }
+    };
+    let response = dispatch_one(handler, clients, id, request).await;
+    has_id.then_some(response)
+}
+
+/// Dispatch a JSON-RPC 2.0 batch request: every element runs independently
+/// and concurrently, since these are independent async pipeline operations,
+/// and the reply preserves one-to-one correspondence with the elements that
+/// carried an `id` (notifications are executed but produce no reply entry).
+/// Per spec, an empty batch is itself an `invalid_request`, not an empty
+/// array of replies.
+pub(super) async fn dispatch_batch(
+    handler: &ManagerInterface,
+    clients: &ClientMap,
+    id: ClientId,
+    elements: Vec<serde_json::Value>,
+) -> Vec<Response> {
+    if elements.is_empty() {
+        return vec![Response::invalid_request(
+            "unknown".to_string(),
+            "Invalid Request: empty batch".to_string(),
+        )];
+    }
+
+    futures_util::future::join_all(
+        elements
+            .into_iter()
+            .map(|value| dispatch_batch_element(handler, clients, id, value)),
+    )
+    .await
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Handle a single WebSocket connection over any duplex byte stream (TCP, Unix
+/// socket, named pipe). API key / origin validation only makes sense for the
+/// TCP transport; IPC connections are pre-authenticated by file permissions on
+/// the socket path, so callers pass `None` for both there.
+pub(super) async fn handle_connection<S>(
+    stream: S,
+    id: ClientId,
     clients: ClientMap,
     manager: Arc<PipelineManager>,
     api_key: Option<String>,
     allowed_origins: Option<Vec<String>>,
-) {
-    info!("New WebSocket connection from {}", addr);
-
-    // Check connection limit before accepting
-    {
-        let clients_map = clients.read().await;
-        if clients_map.len() >= MAX_CONCURRENT_CLIENTS {
-            warn!(
-                "Max clients ({}) reached, rejecting connection from {}",
-                MAX_CONCURRENT_CLIENTS, addr
-            );
-            return;
-        }
+    ipc_enabled: bool,
+) where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    info!("New WebSocket connection from {}", id);
+
+    // Check connection limit before accepting. Reads the atomic counter
+    // directly rather than locking the map, so this never contends with the
+    // broadcaster or other connections registering/unregistering.
+    if clients.len() >= MAX_CONCURRENT_CLIENTS {
+        warn!(
+            "Max clients ({}) reached, rejecting connection from {}",
+            MAX_CONCURRENT_CLIENTS, id
+        );
+        return;
     }
 
-    // Accept WebSocket connection with optional API key and origin validation
-    let ws_stream = if api_key.is_some() || allowed_origins.is_some() {
+    // Accept the WebSocket connection. The handshake callback always runs so
+    // it can negotiate the `gpop-binary` subprotocol (recording the result in
+    // `binary_requested`), and additionally validates Origin/API key when
+    // those are configured (TCP transport only).
+    let binary_requested = Arc::new(AtomicBool::new(false));
+    let ws_stream = {
         let expected_key = api_key;
         let origins = allowed_origins;
+        let binary_requested = Arc::clone(&binary_requested);
         let callback = move |req: &WsRequest,
-                             res: WsResponse|
+                             mut res: WsResponse|
               -> std::result::Result<WsResponse, ErrorResponse> {
+            // Negotiate optional CBOR binary framing via Sec-WebSocket-Protocol.
+            if let Some(proto_header) = req.headers().get("Sec-WebSocket-Protocol") {
+                if let Ok(protocols) = proto_header.to_str() {
+                    if protocols
+                        .split(',')
+                        .map(str::trim)
+                        .any(|p| p == BINARY_SUBPROTOCOL)
+                    {
+                        binary_requested.store(true, Ordering::Relaxed);
+                        res.headers_mut().insert(
+                            "Sec-WebSocket-Protocol",
+                            HeaderValue::from_static(BINARY_SUBPROTOCOL),
+                        );
+                    }
+                }
+            }
+
             // Validate Origin header if allowed_origins is configured
             // Note: Non-browser clients (CLI tools, scripts) typically don't send Origin headers.
             // If Origin is absent, we allow the request to support programmatic API access.
@@ -211,30 +811,23 @@ async fn handle_connection(
         match tokio_tungstenite::accept_hdr_async(stream, callback).await {
             Ok(ws) => ws,
             Err(e) => {
-                error!("WebSocket handshake failed for {}: {}", addr, e);
-                return;
-            }
-        }
-    } else {
-        match tokio_tungstenite::accept_async(stream).await {
-            Ok(ws) => ws,
-            Err(e) => {
-                error!("WebSocket handshake failed for {}: {}", addr, e);
+                error!("WebSocket handshake failed for {}: {}", id, e);
                 return;
             }
         }
     };
+    let binary = binary_requested.load(Ordering::Relaxed);
+    if binary {
+        debug!("Client {} negotiated CBOR binary framing", id);
+    }
 
     let (mut ws_sender, mut ws_receiver) = ws_stream.split();
     let (tx, mut rx) = mpsc::channel::<Message>(CLIENT_MESSAGE_BUFFER);
 
     // Register client
-    {
-        let mut clients_map = clients.write().await;
-        clients_map.insert(addr, tx);
-    }
+    clients.insert(id, ClientEntry::new(tx, binary));
 
-    let handler = ManagerInterface::new(manager);
+    let handler = ManagerInterface::new(manager, ipc_enabled);
 
     // Spawn task to forward messages from channel to WebSocket
     let sender_task = tokio::spawn(async move {
@@ -249,78 +842,127 @@ async fn handle_connection(
     while let Some(result) = ws_receiver.next().await {
         match result {
             Ok(Message::Text(text)) => {
-                debug!("Received from {}: {}", addr, text);
+                debug!("Received from {}: {}", id, text);
 
-                let request = match serde_json::from_str::<Request>(&text) {
-                    Ok(req) => req,
+                let value = match serde_json::from_str::<serde_json::Value>(&text) {
+                    Ok(value) => value,
                     Err(e) => {
-                        error!("Failed to parse request from {}: {}", addr, e);
-
-                        // Try to extract the ID from malformed JSON for better error correlation
-                        let id = serde_json::from_str::<serde_json::Value>(&text)
-                            .ok()
-                            .and_then(|v| v.get("id").and_then(|id| id.as_str()).map(String::from))
-                            .unwrap_or_else(|| "unknown".to_string());
-
+                        error!("Failed to parse request from {}: {}", id, e);
                         let response = super::protocol::Response::parse_error(
-                            id,
+                            "unknown".to_string(),
                             format!("Parse error: {}", e),
                         );
-                        let response_json = serialize_or_error(&response);
-                        let clients_map = clients.read().await;
-                        if let Some(tx) = clients_map.get(&addr) {
-                            let _ = tx.try_send(Message::Text(response_json.into()));
-                        }
+                        send_to_client(&clients, id, encode_message(&response, binary)).await;
                         continue;
                     }
                 };
 
-                // Handle snapshot specially - returns direct response without JSON-RPC wrapper
-                let response_json = if request.method == "snapshot" {
-                    let params: SnapshotParams =
-                        serde_json::from_value(request.params).unwrap_or_default();
-                    match handler.snapshot(params).await {
-                        Ok(result) => serialize_or_error(&result),
-                        Err(e) => {
-                            let response =
-                                super::protocol::Response::from_gpop_error(request.id, &e);
-                            serialize_or_error(&response)
-                        }
+                let req_id = value
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .map(String::from)
+                    .unwrap_or_else(|| "unknown".to_string());
+
+                match value {
+                    serde_json::Value::Array(elements) => {
+                        let replies = dispatch_batch(&handler, &clients, id, elements).await;
+                        send_to_client(&clients, id, encode_message(&replies, binary)).await;
+                    }
+                    serde_json::Value::Object(_) => {
+                        let request = match serde_json::from_value::<Request>(value) {
+                            Ok(req) => req,
+                            Err(e) => {
+                                error!("Failed to parse request from {}: {}", id, e);
+                                let response = super::protocol::Response::parse_error(
+                                    req_id,
+                                    format!("Parse error: {}", e),
+                                );
+                                send_to_client(&clients, id, encode_message(&response, binary))
+                                    .await;
+                                continue;
+                            }
+                        };
+                        let reply = dispatch_request(&handler, &clients, id, binary, request).await;
+                        send_to_client(&clients, id, reply).await;
+                    }
+                    _ => {
+                        let response = super::protocol::Response::parse_error(
+                            "unknown".to_string(),
+                            "Invalid Request: expected a JSON object or an array of requests"
+                                .to_string(),
+                        );
+                        send_to_client(&clients, id, encode_message(&response, binary)).await;
+                    }
+                }
+            }
+            Ok(Message::Binary(data)) => {
+                debug!("Received {} binary bytes from {}", data.len(), id);
+
+                let value = match serde_cbor::from_slice::<serde_json::Value>(&data) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        error!("Failed to parse CBOR request from {}: {}", id, e);
+                        let response = super::protocol::Response::parse_error(
+                            "unknown".to_string(),
+                            format!("Parse error: {}", e),
+                        );
+                        send_to_client(&clients, id, encode_message(&response, binary)).await;
+                        continue;
                     }
-                } else {
-                    let response = handler.handle(request).await;
-                    serialize_or_error(&response)
                 };
 
-                let clients_map = clients.read().await;
-                if let Some(tx) = clients_map.get(&addr) {
-                    let _ = tx.try_send(Message::Text(response_json.into()));
+                match value {
+                    serde_json::Value::Array(elements) => {
+                        let replies = dispatch_batch(&handler, &clients, id, elements).await;
+                        send_to_client(&clients, id, encode_message(&replies, binary)).await;
+                    }
+                    serde_json::Value::Object(_) => {
+                        let request = match serde_json::from_value::<Request>(value) {
+                            Ok(req) => req,
+                            Err(e) => {
+                                error!("Failed to parse CBOR request from {}: {}", id, e);
+                                let response = super::protocol::Response::parse_error(
+                                    "unknown".to_string(),
+                                    format!("Parse error: {}", e),
+                                );
+                                send_to_client(&clients, id, encode_message(&response, binary))
+                                    .await;
+                                continue;
+                            }
+                        };
+                        let reply = dispatch_request(&handler, &clients, id, binary, request).await;
+                        send_to_client(&clients, id, reply).await;
+                    }
+                    _ => {
+                        let response = super::protocol::Response::parse_error(
+                            "unknown".to_string(),
+                            "Invalid Request: expected a JSON object or an array of requests"
+                                .to_string(),
+                        );
+                        send_to_client(&clients, id, encode_message(&response, binary)).await;
+                    }
                 }
             }
             Ok(Message::Close(_)) => {
-                info!("Client {} disconnected", addr);
+                info!("Client {} disconnected", id);
                 break;
             }
             Ok(Message::Ping(data)) => {
-                let clients_map = clients.read().await;
-                if let Some(tx) = clients_map.get(&addr) {
-                    let _ = tx.try_send(Message::Pong(data));
+                if let Some(entry) = clients.get(&id) {
+                    let _ = entry.tx.try_send(Message::Pong(data));
                 }
             }
             Ok(_) => {}
             Err(e) => {
-                error!("Error receiving message from {}: {}", addr, e);
+                error!("Error receiving message from {}: {}", id, e);
                 break;
             }
         }
     }
 
-    // Unregister client
-    {
-        let mut clients_map = clients.write().await;
-        clients_map.remove(&addr);
-    }
+    // Unregister client, tearing down all of its subscriptions
+    clients.remove(&id);
 
     sender_task.abort();
-    info!("Connection closed for {}", addr);
+    info!("Connection closed for {}", id);
 }
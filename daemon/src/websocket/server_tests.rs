@@ -0,0 +1,73 @@
+// server_tests.rs
+//
+// Copyright 2026 Stéphane Cerveau <scerveau@igalia.com>
+//
+// This file is part of GstPrinceOfParser
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use super::server::*;
+
+#[test]
+fn test_wildcard_subscription_matches_anything() {
+    let sub = Subscription::wildcard();
+    assert!(sub.matches(Some("pipeline-0"), "eos"));
+    assert!(sub.matches(None, "state_changed"));
+}
+
+#[test]
+fn test_subscription_filters_by_pipeline_id() {
+    let sub = Subscription::new(Some("pipeline-0"), None);
+    assert!(sub.matches(Some("pipeline-0"), "eos"));
+    assert!(!sub.matches(Some("pipeline-1"), "eos"));
+    assert!(!sub.matches(None, "eos"));
+}
+
+#[test]
+fn test_subscription_filters_by_event_kind() {
+    let sub = Subscription::new(None, Some("error"));
+    assert!(sub.matches(Some("pipeline-0"), "error"));
+    assert!(sub.matches(None, "error"));
+    assert!(!sub.matches(Some("pipeline-0"), "eos"));
+}
+
+#[test]
+fn test_subscription_filters_by_both() {
+    let sub = Subscription::new(Some("pipeline-0"), Some("unsupported"));
+    assert!(sub.matches(Some("pipeline-0"), "unsupported"));
+    assert!(!sub.matches(Some("pipeline-0"), "eos"));
+    assert!(!sub.matches(Some("pipeline-1"), "unsupported"));
+}
+
+#[test]
+fn test_resync_event_carries_no_pipeline_id() {
+    let event = serde_json::json!({
+        "event": "resync",
+        "data": { "dropped": 3 },
+    });
+
+    let (notification, kind, pipeline_id) = event_to_notification(&event);
+
+    assert_eq!(kind, "resync");
+    assert_eq!(pipeline_id, None);
+    assert_eq!(notification.method, "resync");
+    assert_eq!(notification.params.get("dropped").and_then(|v| v.as_u64()), Some(3));
+}
+
+#[test]
+fn test_event_to_notification_extracts_kind_and_pipeline_id() {
+    let event = serde_json::json!({
+        "event": "eos",
+        "data": { "pipeline_id": "pipeline-0" },
+    });
+
+    let (notification, kind, pipeline_id) = event_to_notification(&event);
+
+    assert_eq!(kind, "eos");
+    assert_eq!(pipeline_id.as_deref(), Some("pipeline-0"));
+    assert_eq!(notification.method, "eos");
+    assert_eq!(
+        notification.params.get("pipeline_id").and_then(|v| v.as_str()),
+        Some("pipeline-0")
+    );
+}
@@ -2,13 +2,20 @@ use actix_multipart::Multipart;
 use actix_web::{web, HttpRequest, HttpResponse};
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
 use tracing::{debug, info};
 
 use crate::config::{is_allowed_extension, is_audio_extension, Config};
 use crate::error::{AppError, Result};
-use crate::job::{DemucsModel, DemucsOptions, JobManager, JobType, OutputFormat, TranscodeOptions};
+use crate::job::{
+    DemucsModel, DemucsOptions, ImageFormat, JobManager, JobOptions, JobType, OutputFormat,
+    ThumbnailOptions, TranscodeOptions, TranscodeOverrides, TranscodePreset,
+};
+use crate::media::{probe_file, MediaDetails, TrackTags};
+use crate::storage::{OutputLocation, StorageManager};
 
 /// Response for job creation
 #[derive(Serialize)]
@@ -29,16 +36,37 @@ pub struct JobListResponse {
 /// Query parameters for transcode job creation
 #[derive(Debug, Deserialize)]
 pub struct CreateTranscodeQuery {
-    #[serde(default = "default_format")]
-    output_format: String,
+    /// Named preset (see `GET /api/presets`) to expand into `TranscodeOptions`.
+    /// Any of the fields below that are also set override the preset's value.
+    preset: Option<String>,
+    output_format: Option<String>,
     video_bitrate_kbps: Option<u32>,
     audio_bitrate_kbps: Option<u32>,
     width: Option<u32>,
     height: Option<u32>,
-}
-
-fn default_format() -> String {
-    "mp4".to_string()
+    video_codec: Option<String>,
+    audio_codec: Option<String>,
+    /// Track metadata to write into the output. Unset fields fall back to
+    /// whatever the probe subsystem read off the input (see
+    /// `TranscodeOptions::resolved_tags`). Cover art isn't settable through
+    /// this query-param API; it only gets carried through from the input.
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    track: Option<u32>,
+    year: Option<i32>,
+    /// TTL in seconds; the job and its files are purged by the reaper once
+    /// it elapses. Omit to keep the job until it's deleted manually.
+    keep_for: Option<i64>,
+    /// Client-supplied key that makes resubmitting this request idempotent:
+    /// a second submission with the same key returns the job created by the
+    /// first one instead of starting a duplicate.
+    idempotency_key: Option<String>,
+    /// JSON-encoded array of follow-up job specs to enqueue automatically
+    /// against this job's finished output once it completes, e.g.
+    /// `[{"type":"thumbnail","count":3}]` to generate thumbnails from the
+    /// transcoded output without a manual download/re-upload round trip.
+    follow_ups: Option<String>,
 }
 
 /// Query parameters for demucs job creation
@@ -51,6 +79,16 @@ pub struct CreateDemucsQuery {
     stems: String,
     #[serde(default = "default_stem_format")]
     output_format: String,
+    /// TTL in seconds; the job and its files are purged by the reaper once
+    /// it elapses. Omit to keep the job until it's deleted manually.
+    keep_for: Option<i64>,
+    /// See `CreateTranscodeQuery::idempotency_key`.
+    idempotency_key: Option<String>,
+    /// JSON-encoded array of follow-up job specs to enqueue automatically
+    /// against every stem once this job completes, e.g.
+    /// `[{"type":"transcode","output_format":"mp3"}]` to transcode every
+    /// demucs stem to MP3 without a manual download/re-upload round trip.
+    follow_ups: Option<String>,
 }
 
 fn default_model() -> String {
@@ -61,13 +99,58 @@ fn default_stem_format() -> String {
     "wav".to_string()
 }
 
-/// Extract file from multipart form
-async fn extract_file_from_multipart(
+/// Query parameters for thumbnail job creation
+#[derive(Debug, Deserialize)]
+pub struct CreateThumbnailQuery {
+    #[serde(default = "default_thumbnail_count")]
+    count: u32,
+    #[serde(default = "default_thumbnail_width")]
+    width: u32,
+    #[serde(default = "default_thumbnail_format")]
+    format: String,
+    #[serde(default)]
+    sprite: bool,
+    /// Comma-separated list of sample points, as a percentage (0.0-100.0) of
+    /// the input's duration. Overrides the default evenly-spaced sampling;
+    /// must have exactly `count` entries if set.
+    at_percentages: Option<String>,
+    /// TTL in seconds; the job and its files are purged by the reaper once
+    /// it elapses. Omit to keep the job until it's deleted manually.
+    keep_for: Option<i64>,
+    /// See `CreateTranscodeQuery::idempotency_key`.
+    idempotency_key: Option<String>,
+    /// See `CreateDemucsQuery::follow_ups`.
+    follow_ups: Option<String>,
+}
+
+fn default_thumbnail_count() -> u32 {
+    5
+}
+
+fn default_thumbnail_width() -> u32 {
+    320
+}
+
+fn default_thumbnail_format() -> String {
+    "webp".to_string()
+}
+
+/// Stream the uploaded file from a multipart form straight to disk under the
+/// job's upload directory, rather than buffering it in memory first. Chunks
+/// are written to the destination file as they arrive and the running total
+/// is checked against `max_size` so an oversized upload is rejected without
+/// ever holding the whole body in RAM. A BLAKE3 digest of the content is
+/// computed from the same chunks, and the finished upload is moved into
+/// content-addressed storage keyed by that digest - the content of the
+/// returned path is identical either way, but a re-upload of bytes already
+/// on disk for another job is deduplicated instead of stored twice.
+async fn stream_file_from_multipart(
     mut payload: Multipart,
+    storage: &StorageManager,
+    job_id: &str,
     max_size: usize,
-) -> Result<(Vec<u8>, String)> {
-    let mut file_data: Option<Vec<u8>> = None;
-    let mut filename: Option<String> = None;
+) -> Result<(PathBuf, String, String)> {
+    let mut result: Option<(PathBuf, String, String)> = None;
 
     while let Some(field) = payload.next().await {
         let mut field = field.map_err(|e| AppError::Internal(e.to_string()))?;
@@ -78,27 +161,92 @@ async fn extract_file_from_multipart(
             .unwrap_or("");
 
         if field_name == "file" {
-            filename = content_disposition
+            let filename = content_disposition
                 .and_then(|cd| cd.get_filename())
-                .map(|s| sanitize_filename::sanitize(s));
-
-            let mut bytes = Vec::new();
-            while let Some(chunk) = field.next().await {
-                let chunk = chunk.map_err(|e| AppError::Internal(e.to_string()))?;
-                if bytes.len() + chunk.len() > max_size {
-                    return Err(AppError::FileTooLarge(bytes.len() + chunk.len(), max_size));
+                .map(|s| sanitize_filename::sanitize(s))
+                .ok_or_else(|| AppError::Internal("No filename provided".to_string()))?;
+
+            let (path, mut file) = storage.begin_upload(job_id, &filename).await?;
+
+            let mut total = 0usize;
+            let mut hasher = blake3::Hasher::new();
+            let write_result: Result<()> = async {
+                while let Some(chunk) = field.next().await {
+                    let chunk = chunk.map_err(|e| AppError::Internal(e.to_string()))?;
+                    total += chunk.len();
+                    if total > max_size {
+                        return Err(AppError::FileTooLarge(total, max_size));
+                    }
+                    hasher.update(&chunk);
+                    file.write_all(&chunk)
+                        .await
+                        .map_err(|e| AppError::Storage(format!("Failed to write upload: {}", e)))?;
                 }
-                bytes.extend_from_slice(&chunk);
+                file.flush()
+                    .await
+                    .map_err(|e| AppError::Storage(format!("Failed to flush upload: {}", e)))?;
+                file.sync_all()
+                    .await
+                    .map_err(|e| AppError::Storage(format!("Failed to sync upload: {}", e)))?;
+                Ok(())
+            }
+            .await;
+
+            if let Err(e) = write_result {
+                drop(file);
+                let _ = fs::remove_file(&path).await;
+                return Err(e);
             }
-            file_data = Some(bytes);
+            drop(file);
+
+            let hash = hasher.finalize().to_hex().to_string();
+            let final_path = storage
+                .finalize_content_addressed_upload(job_id, &path, &hash)
+                .await?;
+
+            result = Some((final_path, filename, hash));
         }
     }
 
-    let file_data = file_data.ok_or_else(|| AppError::Internal("No file uploaded".to_string()))?;
-    let filename =
-        filename.ok_or_else(|| AppError::Internal("No filename provided".to_string()))?;
+    result.ok_or_else(|| AppError::Internal("No file uploaded".to_string()))
+}
+
+/// Fail fast on an upload ffprobe can't find a single decodable stream in,
+/// rather than letting it reach `PipelineManager::add_pipeline` and fail
+/// there instead. `probe_file` itself already rejects a container ffprobe
+/// can't parse at all (`AppError::Probe`); this additionally catches the
+/// case where ffprobe parses the container fine but lists zero streams -
+/// `MediaDetails::streams` is just checked for emptiness, so a probe result
+/// with no streams is handled the same as one with several, not treated as
+/// a special/panicking case.
+async fn reject_undecodable_upload(path: &Path) -> Result<()> {
+    let media = probe_file(path).await?;
+    if media.streams.is_empty() {
+        return Err(AppError::UnsupportedMedia(media.format_name));
+    }
+    Ok(())
+}
+
+/// Parse the `follow_ups` query param's JSON-encoded `Vec<JobOptions>`, if
+/// present.
+fn parse_follow_ups(raw: &Option<String>) -> Result<Vec<JobOptions>> {
+    match raw {
+        Some(json) => serde_json::from_str(json)
+            .map_err(|e| AppError::Internal(format!("Invalid follow_ups: {}", e))),
+        None => Ok(vec![]),
+    }
+}
 
-    Ok((file_data, filename))
+/// Build the `JobCreatedResponse` for a job found via `idempotency_key`,
+/// so a resubmitted request gets back the same shape of response it would
+/// have gotten the first time, reflecting the existing job's current state.
+fn idempotent_replay_response(job: &crate::job::Job, job_type: &str) -> HttpResponse {
+    HttpResponse::Created().json(JobCreatedResponse {
+        job_id: job.id.clone(),
+        job_type: job_type.to_string(),
+        status: format!("{:?}", job.status).to_lowercase(),
+        message: format!("{} job already exists for this idempotency key", job_type),
+    })
 }
 
 /// POST /api/jobs/transcode - Create a new transcoding job
@@ -108,7 +256,17 @@ pub async fn create_transcode_job(
     manager: web::Data<Arc<JobManager>>,
     config: web::Data<Config>,
 ) -> Result<HttpResponse> {
-    let (file_data, filename) = extract_file_from_multipart(payload, config.max_upload_bytes()).await?;
+    if let Some(key) = &query.idempotency_key {
+        if let Some(job) = manager.find_by_idempotency_key(key).await {
+            return Ok(idempotent_replay_response(&job, "transcode"));
+        }
+    }
+
+    let job_id = uuid::Uuid::new_v4().to_string();
+    let (input_path, filename, _content_hash) =
+        stream_file_from_multipart(payload, &manager.storage(), &job_id, config.max_upload_bytes())
+            .await?;
+    reject_undecodable_upload(&input_path).await?;
 
     // Validate file extension
     let extension = Path::new(&filename)
@@ -121,29 +279,89 @@ pub async fn create_transcode_job(
         return Err(AppError::InvalidFileType(extension));
     }
 
-    // Parse output format
-    let output_format: OutputFormat = query
-        .output_format
-        .parse()
-        .map_err(|e: String| AppError::Internal(e))?;
+    // Resolve the named preset, if any; explicit query params below still
+    // override its fields field-by-field.
+    let preset: Option<TranscodePreset> = match &query.preset {
+        Some(name) => Some(
+            config
+                .preset(name)
+                .cloned()
+                .ok_or_else(|| AppError::InvalidPreset(name.clone()))?,
+        ),
+        None => None,
+    };
 
-    // Build transcode options
-    let options = TranscodeOptions {
-        output_format,
+    let overrides = TranscodeOverrides {
+        output_format: query
+            .output_format
+            .as_deref()
+            .map(|s| s.parse())
+            .transpose()
+            .map_err(|e: String| AppError::Internal(e))?,
         video_bitrate_kbps: query.video_bitrate_kbps,
         audio_bitrate_kbps: query.audio_bitrate_kbps,
         width: query.width,
         height: query.height,
+        video_codec: query
+            .video_codec
+            .as_deref()
+            .map(|s| s.parse())
+            .transpose()
+            .map_err(AppError::Internal)?,
+        audio_codec: query
+            .audio_codec
+            .as_deref()
+            .map(|s| s.parse())
+            .transpose()
+            .map_err(AppError::Internal)?,
+        tags: {
+            let tags = TrackTags {
+                title: query.title.clone(),
+                artist: query.artist.clone(),
+                album: query.album.clone(),
+                track: query.track,
+                year: query.year,
+                cover_art: None,
+            };
+            if tags.is_empty() { None } else { Some(tags) }
+        },
+    };
+
+    let options = match &preset {
+        Some(p) => p.resolve(&overrides),
+        None => TranscodeOptions {
+            output_format: overrides.output_format.unwrap_or(OutputFormat::Mp4),
+            video_bitrate_kbps: overrides.video_bitrate_kbps,
+            audio_bitrate_kbps: overrides.audio_bitrate_kbps,
+            width: overrides.width,
+            height: overrides.height,
+            video_codec: overrides.video_codec,
+            audio_codec: overrides.audio_codec,
+            tags: overrides.tags.clone(),
+        },
     };
 
+    options.validate_codecs().map_err(AppError::Internal)?;
+    let follow_ups = parse_follow_ups(&query.follow_ups)?;
+
+    let keep_for = query.keep_for.map(chrono::Duration::seconds);
+
     info!(
-        "Creating transcode job: {} ({} bytes) -> {}",
+        "Creating transcode job: {} -> {}",
         filename,
-        file_data.len(),
-        output_format.extension()
+        options.output_format.extension()
     );
 
-    let job_id = manager.create_transcode_job(&filename, &file_data, options).await?;
+    let job_id = manager
+        .create_transcode_job_dedup(job_id, filename, input_path, options, keep_for)
+        .await?;
+
+    if let Some(key) = &query.idempotency_key {
+        manager.set_idempotency_key(&job_id, key.clone()).await;
+    }
+    if !follow_ups.is_empty() {
+        manager.set_follow_ups(&job_id, follow_ups).await;
+    }
 
     Ok(HttpResponse::Created().json(JobCreatedResponse {
         job_id,
@@ -160,7 +378,17 @@ pub async fn create_demucs_job(
     manager: web::Data<Arc<JobManager>>,
     config: web::Data<Config>,
 ) -> Result<HttpResponse> {
-    let (file_data, filename) = extract_file_from_multipart(payload, config.max_upload_bytes()).await?;
+    if let Some(key) = &query.idempotency_key {
+        if let Some(job) = manager.find_by_idempotency_key(key).await {
+            return Ok(idempotent_replay_response(&job, "demucs"));
+        }
+    }
+
+    let job_id = uuid::Uuid::new_v4().to_string();
+    let (input_path, filename, _content_hash) =
+        stream_file_from_multipart(payload, &manager.storage(), &job_id, config.max_upload_bytes())
+            .await?;
+    reject_undecodable_upload(&input_path).await?;
 
     // Validate file extension (must be audio)
     let extension = Path::new(&filename)
@@ -215,15 +443,26 @@ pub async fn create_demucs_job(
         stems,
         output_format: query.output_format.clone(),
     };
+    let follow_ups = parse_follow_ups(&query.follow_ups)?;
+
+    let keep_for = query.keep_for.map(chrono::Duration::seconds);
 
     info!(
-        "Creating demucs job: {} ({} bytes) with model {}",
+        "Creating demucs job: {} with model {}",
         filename,
-        file_data.len(),
         model.as_str()
     );
 
-    let job_id = manager.create_demucs_job(&filename, &file_data, options).await?;
+    let job_id = manager
+        .create_demucs_job_dedup(job_id, filename, input_path, options, keep_for)
+        .await?;
+
+    if let Some(key) = &query.idempotency_key {
+        manager.set_idempotency_key(&job_id, key.clone()).await;
+    }
+    if !follow_ups.is_empty() {
+        manager.set_follow_ups(&job_id, follow_ups).await;
+    }
 
     Ok(HttpResponse::Created().json(JobCreatedResponse {
         job_id,
@@ -233,6 +472,87 @@ pub async fn create_demucs_job(
     }))
 }
 
+/// POST /api/jobs/thumbnail - Create a new thumbnail/sprite-sheet job
+pub async fn create_thumbnail_job(
+    payload: Multipart,
+    query: web::Query<CreateThumbnailQuery>,
+    manager: web::Data<Arc<JobManager>>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse> {
+    if let Some(key) = &query.idempotency_key {
+        if let Some(job) = manager.find_by_idempotency_key(key).await {
+            return Ok(idempotent_replay_response(&job, "thumbnail"));
+        }
+    }
+
+    let job_id = uuid::Uuid::new_v4().to_string();
+    let (input_path, filename, _content_hash) =
+        stream_file_from_multipart(payload, &manager.storage(), &job_id, config.max_upload_bytes())
+            .await?;
+    reject_undecodable_upload(&input_path).await?;
+
+    // Validate file extension
+    let extension = Path::new(&filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .ok_or_else(|| AppError::InvalidFileType("unknown".to_string()))?;
+
+    if !is_allowed_extension(&extension) {
+        return Err(AppError::InvalidFileType(extension));
+    }
+
+    let format: ImageFormat = query.format.parse().map_err(AppError::Internal)?;
+
+    let at_percentages = query
+        .at_percentages
+        .as_deref()
+        .map(|s| {
+            s.split(',')
+                .map(|p| {
+                    p.trim()
+                        .parse::<f32>()
+                        .map_err(|e| AppError::Internal(format!("Invalid at_percentages: {}", e)))
+                })
+                .collect::<Result<Vec<f32>>>()
+        })
+        .transpose()?;
+
+    let options = ThumbnailOptions {
+        count: query.count,
+        width: query.width,
+        format,
+        sprite: query.sprite,
+        at_percentages,
+    };
+    let follow_ups = parse_follow_ups(&query.follow_ups)?;
+
+    let keep_for = query.keep_for.map(chrono::Duration::seconds);
+
+    info!(
+        "Creating thumbnail job: {} ({} frames, sprite={})",
+        filename, options.count, options.sprite
+    );
+
+    let job_id = manager
+        .create_thumbnail_job(job_id, &filename, input_path, options, keep_for)
+        .await?;
+
+    if let Some(key) = &query.idempotency_key {
+        manager.set_idempotency_key(&job_id, key.clone()).await;
+    }
+    if !follow_ups.is_empty() {
+        manager.set_follow_ups(&job_id, follow_ups).await;
+    }
+
+    Ok(HttpResponse::Created().json(JobCreatedResponse {
+        job_id,
+        job_type: "thumbnail".to_string(),
+        status: "pending".to_string(),
+        message: "Thumbnail job created successfully".to_string(),
+    }))
+}
+
 /// GET /api/jobs - List all jobs
 pub async fn list_jobs(manager: web::Data<Arc<JobManager>>) -> Result<HttpResponse> {
     let jobs = manager.list_jobs().await;
@@ -252,6 +572,41 @@ pub async fn get_job(
     Ok(HttpResponse::Ok().json(details))
 }
 
+/// Response for GET /api/jobs/{id}/details
+#[derive(Serialize)]
+pub struct JobMediaDetailsResponse {
+    input: MediaDetails,
+    output: Option<MediaDetails>,
+}
+
+/// GET /api/jobs/{id}/details - ffprobe metadata for a job's input (and
+/// output, once available)
+pub async fn get_job_media_details(
+    path: web::Path<String>,
+    manager: web::Data<Arc<JobManager>>,
+) -> Result<HttpResponse> {
+    let job_id = path.into_inner();
+    let job = manager.get_job(&job_id).await?;
+
+    // Reuse the pre-enqueue probe instead of re-running ffprobe, if it's
+    // still around.
+    let input = match job.media_info.clone() {
+        Some(info) => info,
+        None => probe_file(&job.input_path).await?,
+    };
+
+    let output = if job.status == crate::job::JobStatus::Completed
+        && job.job_type == JobType::Transcode
+        && job.output_path.exists()
+    {
+        Some(probe_file(&job.output_path).await?)
+    } else {
+        None
+    };
+
+    Ok(HttpResponse::Ok().json(JobMediaDetailsResponse { input, output }))
+}
+
 /// DELETE /api/jobs/{id} - Delete a job
 pub async fn delete_job(
     path: web::Path<String>,
@@ -263,6 +618,28 @@ pub async fn delete_job(
     Ok(HttpResponse::NoContent().finish())
 }
 
+/// POST /api/jobs/{id}/pause - Pause a running job's pipeline
+pub async fn pause_job(
+    path: web::Path<String>,
+    manager: web::Data<Arc<JobManager>>,
+) -> Result<HttpResponse> {
+    let job_id = path.into_inner();
+    manager.pause_job(&job_id).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// POST /api/jobs/{id}/resume - Resume a previously-paused job's pipeline
+pub async fn resume_job(
+    path: web::Path<String>,
+    manager: web::Data<Arc<JobManager>>,
+) -> Result<HttpResponse> {
+    let job_id = path.into_inner();
+    manager.resume_job(&job_id).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
 /// GET /api/jobs/{id}/download - Download the output file (transcode jobs)
 pub async fn download_job(
     path: web::Path<String>,
@@ -284,6 +661,15 @@ pub async fn download_job(
         ));
     }
 
+    // If the output was published to a remote backend (e.g. S3), hand the
+    // client a redirect to the pre-signed URL instead of streaming the file
+    // through this process.
+    if let Some(OutputLocation::Remote(url)) = &job.output_location {
+        return Ok(HttpResponse::Found()
+            .insert_header(("Location", url.clone()))
+            .finish());
+    }
+
     // Check if output file exists
     let output_path = &job.output_path;
     if !output_path.exists() {
@@ -328,7 +714,8 @@ pub async fn download_job(
     Ok(file.into_response(&req))
 }
 
-/// GET /api/jobs/{id}/download/{stem} - Download a demucs stem
+/// GET /api/jobs/{id}/download/{stem} - Download a demucs stem or thumbnail
+/// output (frame, sprite, index)
 pub async fn download_stem(
     path: web::Path<(String, String)>,
     manager: web::Data<Arc<JobManager>>,
@@ -336,17 +723,32 @@ pub async fn download_stem(
 ) -> Result<HttpResponse> {
     let (job_id, stem) = path.into_inner();
 
-    // Get the stem file path
-    let stem_path = manager.get_demucs_stem_path(&job_id, &stem).await?;
+    // Get job for filename and to check whether this stem was published to
+    // a remote backend.
+    let job = manager.get_job(&job_id).await?;
+
+    // If this stem was published to a remote backend, redirect there
+    // instead of reading it back off local disk (it may no longer exist
+    // locally once published).
+    if let Some((_, OutputLocation::Remote(url))) =
+        job.stem_locations.iter().find(|(s, _)| s == &stem)
+    {
+        return Ok(HttpResponse::Found()
+            .insert_header(("Location", url.clone()))
+            .finish());
+    }
+
+    // Get the output file path
+    let stem_path = match job.job_type {
+        JobType::Thumbnail => manager.get_thumbnail_file_path(&job_id, &stem).await?,
+        _ => manager.get_demucs_stem_path(&job_id, &stem).await?,
+    };
 
     // Determine content type
     let content_type = mime_guess::from_path(&stem_path)
         .first_or_octet_stream()
         .to_string();
 
-    // Get job for filename
-    let job = manager.get_job(&job_id).await?;
-
     // Create filename for download
     let ext = stem_path
         .extension()
@@ -381,6 +783,31 @@ pub async fn download_stem(
     Ok(file.into_response(&req))
 }
 
+/// A preset as returned by `GET /api/presets`, with its settings already
+/// resolved to the `TranscodeOptions` they expand to.
+#[derive(Serialize)]
+pub struct PresetInfo {
+    name: String,
+    description: String,
+    options: TranscodeOptions,
+}
+
+/// GET /api/presets - List the named transcode presets `preset=` accepts
+pub async fn list_presets(config: web::Data<Config>) -> Result<HttpResponse> {
+    let mut presets: Vec<PresetInfo> = config
+        .presets
+        .iter()
+        .map(|(name, preset)| PresetInfo {
+            name: name.clone(),
+            description: preset.description.clone(),
+            options: preset.resolve(&TranscodeOverrides::default()),
+        })
+        .collect();
+    presets.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(HttpResponse::Ok().json(presets))
+}
+
 /// GET /health - Health check endpoint
 pub async fn health_check() -> HttpResponse {
     HttpResponse::Ok().json(serde_json::json!({
@@ -392,11 +819,16 @@ pub async fn health_check() -> HttpResponse {
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/api")
+            .route("/presets", web::get().to(list_presets))
             .route("/jobs/transcode", web::post().to(create_transcode_job))
             .route("/jobs/demucs", web::post().to(create_demucs_job))
+            .route("/jobs/thumbnail", web::post().to(create_thumbnail_job))
             .route("/jobs", web::get().to(list_jobs))
             .route("/jobs/{id}", web::get().to(get_job))
+            .route("/jobs/{id}/details", web::get().to(get_job_media_details))
             .route("/jobs/{id}", web::delete().to(delete_job))
+            .route("/jobs/{id}/pause", web::post().to(pause_job))
+            .route("/jobs/{id}/resume", web::post().to(resume_job))
             .route("/jobs/{id}/download", web::get().to(download_job))
             .route("/jobs/{id}/download/{stem}", web::get().to(download_stem)),
     )
@@ -1,6 +1,9 @@
 use clap::Parser;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::job::presets::{self, TranscodePreset};
+
 /// Web interface for gpop transcoding
 #[derive(Parser, Debug, Clone)]
 #[command(name = "gpop-web")]
@@ -26,13 +29,63 @@ pub struct Config {
     #[arg(long, default_value_t = 2048)]
     pub max_upload_mb: usize,
 
-    /// Maximum concurrent transcoding jobs
+    /// Maximum concurrent transcoding (CPU-bound encode) jobs
     #[arg(long, default_value_t = 4)]
     pub max_concurrent_jobs: usize,
 
+    /// Maximum concurrent demucs (model inference) jobs. Kept separate from
+    /// `max_concurrent_jobs` and lower by default since demucs is far more
+    /// CPU/memory-hungry per job than a transcode.
+    #[arg(long, default_value_t = 1)]
+    pub max_concurrent_demucs_jobs: usize,
+
     /// File retention period in hours (0 = keep forever)
     #[arg(long, default_value_t = 24)]
     pub retention_hours: u64,
+
+    /// How long a `Processing` job's position may go without advancing
+    /// before the progress-poller watchdog reports it as stalled via
+    /// `ProgressMessage::JobStalled`, in seconds.
+    #[arg(long, default_value_t = 60)]
+    pub stall_timeout_secs: u64,
+
+    /// How much longer than `stall_timeout_secs` a stalled job is left
+    /// alone before the watchdog gives up on it entirely: tears down its
+    /// pipeline and fails it (subject to the normal retry policy), in
+    /// seconds.
+    #[arg(long, default_value_t = 300)]
+    pub stall_fail_grace_secs: u64,
+
+    /// Output storage backend: "local" (outputs served from disk) or "s3"
+    /// (outputs uploaded to an S3-compatible object store)
+    #[arg(long, default_value = "local")]
+    pub storage_backend: String,
+
+    /// S3 bucket name (required when `storage_backend` is "s3")
+    #[arg(long)]
+    pub s3_bucket: Option<String>,
+
+    /// S3-compatible endpoint URL, for non-AWS object stores (e.g. MinIO)
+    #[arg(long)]
+    pub s3_endpoint: Option<String>,
+
+    /// S3 region
+    #[arg(long, default_value = "us-east-1")]
+    pub s3_region: String,
+
+    /// Key prefix applied to every object written to the S3 backend
+    #[arg(long, default_value = "")]
+    pub s3_prefix: String,
+
+    /// How long a pre-signed S3 download URL remains valid, in seconds
+    #[arg(long, default_value_t = 3600)]
+    pub s3_presign_seconds: u64,
+
+    /// Named transcode presets (e.g. "web-720p") that `preset=` query
+    /// parameters resolve against. Not exposed as a CLI flag; there's no
+    /// config file to source custom presets from yet.
+    #[arg(skip = presets::default_presets())]
+    pub presets: HashMap<String, TranscodePreset>,
 }
 
 impl Config {
@@ -47,6 +100,17 @@ impl Config {
     pub fn outputs_dir(&self) -> PathBuf {
         self.data_dir.join("outputs")
     }
+
+    /// Directory `JobManager` persists a JSON snapshot of each job under,
+    /// so job history and in-flight state survive a restart.
+    pub fn jobs_dir(&self) -> PathBuf {
+        self.data_dir.join("jobs")
+    }
+
+    /// Look up a named transcode preset, e.g. "web-720p"
+    pub fn preset(&self, name: &str) -> Option<&TranscodePreset> {
+        self.presets.get(name)
+    }
 }
 
 /// Allowed file extensions for upload
@@ -1,6 +1,8 @@
 use actix_web::{HttpResponse, ResponseError};
 use std::fmt;
 
+use crate::ws::GpopErrorKind;
+
 #[derive(Debug)]
 pub enum AppError {
     /// Job not found
@@ -9,16 +11,46 @@ pub enum AppError {
     FileNotFound(String),
     /// Invalid file type
     InvalidFileType(String),
+    /// Unknown transcode preset name
+    InvalidPreset(String),
     /// File too large
     FileTooLarge(usize, usize),
     /// gpop connection error
     GpopConnection(String),
-    /// gpop protocol error
-    GpopProtocol(String),
+    /// Protocol-level violation in the exchange with gpop-daemon itself
+    /// (malformed response, ...) rather than a server error it reported —
+    /// never worth reissuing the identical request.
+    GpopProtocol { message: String },
+    /// A well-known gpop-daemon server error (one of `error_codes`), with
+    /// `kind` identifying it so callers can match on it instead of parsing
+    /// `message`, and `retriable` carrying the daemon's own retry guidance.
+    GpopRpc {
+        code: i32,
+        kind: GpopErrorKind,
+        message: String,
+        retriable: bool,
+    },
     /// Pipeline creation failed
     PipelineCreation(String),
     /// Storage error
     Storage(String),
+    /// Failed to probe a media file with ffprobe
+    Probe(String),
+    /// Requested output format/options are incompatible with the probed
+    /// input media (e.g. an audio-only format for a file with no audio
+    /// stream)
+    IncompatibleMedia(String),
+    /// An uploaded file has no decodable streams at all, or ffprobe couldn't
+    /// identify its container - rejected before a job is even created for
+    /// it, rather than failing deep inside `PipelineManager::add_pipeline`.
+    /// Carries the container format ffprobe did detect, if any.
+    UnsupportedMedia(String),
+    /// Writing title/artist/album/cover-art tags into a finished output
+    /// failed
+    Tagging(String),
+    /// Requested an operation (e.g. pause/resume) that doesn't make sense
+    /// for the job's current `JobStatus`
+    InvalidJobState(String),
     /// Internal error
     Internal(String),
 }
@@ -29,13 +61,24 @@ impl fmt::Display for AppError {
             AppError::JobNotFound(id) => write!(f, "Job not found: {}", id),
             AppError::FileNotFound(path) => write!(f, "File not found: {}", path),
             AppError::InvalidFileType(ext) => write!(f, "Invalid file type: {}", ext),
+            AppError::InvalidPreset(name) => write!(f, "Unknown preset: {}", name),
             AppError::FileTooLarge(size, max) => {
                 write!(f, "File too large: {} bytes (max: {} bytes)", size, max)
             }
             AppError::GpopConnection(msg) => write!(f, "gpop connection error: {}", msg),
-            AppError::GpopProtocol(msg) => write!(f, "gpop protocol error: {}", msg),
+            AppError::GpopProtocol { message } => write!(f, "gpop protocol error: {}", message),
+            AppError::GpopRpc { code, message, .. } => {
+                write!(f, "gpop error {}: {}", code, message)
+            }
             AppError::PipelineCreation(msg) => write!(f, "Pipeline creation failed: {}", msg),
             AppError::Storage(msg) => write!(f, "Storage error: {}", msg),
+            AppError::Probe(msg) => write!(f, "Media probe failed: {}", msg),
+            AppError::IncompatibleMedia(msg) => write!(f, "Incompatible media: {}", msg),
+            AppError::UnsupportedMedia(detected) => {
+                write!(f, "Unsupported media: no decodable streams (detected format: {})", detected)
+            }
+            AppError::Tagging(msg) => write!(f, "Failed to write tags: {}", msg),
+            AppError::InvalidJobState(msg) => write!(f, "Invalid job state: {}", msg),
             AppError::Internal(msg) => write!(f, "Internal error: {}", msg),
         }
     }
@@ -51,12 +94,23 @@ impl ResponseError for AppError {
                     "error": self.to_string()
                 }))
             }
-            AppError::InvalidFileType(_) | AppError::FileTooLarge(_, _) => {
+            AppError::GpopRpc {
+                kind: GpopErrorKind::PipelineNotFound,
+                ..
+            } => HttpResponse::NotFound().json(serde_json::json!({
+                "error": self.to_string()
+            })),
+            AppError::InvalidFileType(_)
+            | AppError::InvalidPreset(_)
+            | AppError::FileTooLarge(_, _)
+            | AppError::IncompatibleMedia(_)
+            | AppError::UnsupportedMedia(_)
+            | AppError::InvalidJobState(_) => {
                 HttpResponse::BadRequest().json(serde_json::json!({
                     "error": self.to_string()
                 }))
             }
-            AppError::GpopConnection(_) | AppError::GpopProtocol(_) => {
+            AppError::GpopConnection(_) | AppError::GpopProtocol { .. } | AppError::GpopRpc { .. } => {
                 HttpResponse::ServiceUnavailable().json(serde_json::json!({
                     "error": self.to_string()
                 }))
@@ -68,4 +122,20 @@ impl ResponseError for AppError {
     }
 }
 
+impl AppError {
+    /// Whether retrying the operation that produced this error stands a
+    /// chance of succeeding. A dropped/not-yet-established gpop connection
+    /// is always worth retrying once reconnected; a known gpop-daemon error
+    /// carries its own verdict; a protocol violation never reached the
+    /// daemon in any meaningful sense, so retrying the same request verbatim
+    /// can't help.
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            AppError::GpopConnection(_) => true,
+            AppError::GpopRpc { retriable, .. } => *retriable,
+            _ => false,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, AppError>;
@@ -1,29 +1,95 @@
-use chrono::Utc;
-use std::collections::HashMap;
-use std::path::PathBuf;
+use chrono::{Duration as ChronoDuration, Utc};
+use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::RwLock;
+use tokio::io::AsyncReadExt;
+use tokio::sync::{broadcast, Mutex, OwnedSemaphorePermit, RwLock, Semaphore};
 use tracing::{debug, error, info, warn};
 
 use crate::config::Config;
 use crate::error::{AppError, Result};
+use crate::media::probe_file;
 use crate::storage::StorageManager;
 use crate::ws::{GpopConnection, GpopEvent, ProgressBroadcaster, ProgressMessage};
 
-use super::pipeline::{build_demucs_pipeline, build_transcode_pipeline, get_demucs_output_files};
+use super::persistence;
+use super::pipeline::{
+    assemble_thumbnail_sprite, build_demucs_pipeline, build_thumbnail_pipeline,
+    build_transcode_pipeline, get_demucs_output_files, get_thumbnail_frame_files, write_tags,
+};
 use super::types::*;
 
+/// Base delay for the first automatic retry of a transiently-failed job;
+/// doubled on each subsequent attempt (1s, 2s, 4s, ...) up to
+/// `RETRY_MAX_DELAY_MS`.
+const RETRY_BASE_DELAY_MS: u64 = 1_000;
+/// Ceiling on the backoff delay, so a job with a generous `max_retries`
+/// doesn't end up waiting an unreasonable amount of time between attempts.
+const RETRY_MAX_DELAY_MS: u64 = 60_000;
+
+/// Exponential backoff delay before retry attempt number `retry_count`
+/// (1-indexed: the first retry uses `retry_count == 1`).
+fn retry_backoff_ms(retry_count: u32) -> u64 {
+    let exponent = retry_count.saturating_sub(1).min(6);
+    RETRY_BASE_DELAY_MS
+        .saturating_mul(1u64 << exponent)
+        .min(RETRY_MAX_DELAY_MS)
+}
+
+/// Outcome broadcast to everyone coalesced onto the same in-flight dedup
+/// key: the job id that ended up driving the pipeline, or the error that
+/// stopped it from launching.
+type DedupOutcome = std::result::Result<String, String>;
+
 /// Job manager handles the lifecycle of transcoding and demucs jobs
 pub struct JobManager {
     jobs: RwLock<HashMap<String, Job>>,
     /// Map pipeline_id -> job_id for event routing
     pipeline_to_job: RwLock<HashMap<String, String>>,
+    /// Content-hash+options keys with a pipeline currently being launched for
+    /// them. A request that matches one of these subscribes to the sender
+    /// instead of starting a second pipeline.
+    dedup_inflight: DashMap<String, broadcast::Sender<DedupOutcome>>,
+    /// Content-hash+options keys of jobs that have *finished* successfully,
+    /// kept around so a later upload of the same content+options can reuse
+    /// the existing output instead of reprocessing it.
+    dedup_completed: DashMap<String, String>,
+    /// Gates how many transcode pipelines may run at once, separate from
+    /// `demucs_semaphore` since demucs model inference is far heavier per
+    /// job than an encode. Mirrors pict-rs's global `PROCESS_SEMAPHORE`.
+    transcode_semaphore: Arc<Semaphore>,
+    demucs_semaphore: Arc<Semaphore>,
+    /// Permits held by currently-running jobs, released (dropping the
+    /// permit) once a job completes, fails, or is deleted.
+    running_permits: DashMap<String, OwnedSemaphorePermit>,
+    /// Ordered queue of job ids waiting on `transcode_semaphore`, mirroring
+    /// the semaphore's own FIFO fairness so a queued job's position can be
+    /// reported to clients instead of leaving them staring at a job stuck
+    /// in `Pending`.
+    transcode_queue: Mutex<VecDeque<String>>,
+    /// Same as `transcode_queue`, for jobs waiting on `demucs_semaphore`.
+    demucs_queue: Mutex<VecDeque<String>>,
+    /// Job ids the stall watchdog has already warned about, so a job stuck
+    /// at the same position doesn't get a fresh `JobStalled` broadcast on
+    /// every 500ms poll tick. Cleared as soon as the job makes progress
+    /// again, fails, completes, or is deleted.
+    stalled_jobs: DashMap<String, ()>,
+    /// Client-supplied idempotency key -> job id, so resubmitting the same
+    /// request (e.g. after a client-side timeout) returns the original job
+    /// instead of starting a duplicate one.
+    idempotency_index: DashMap<String, String>,
     gpop: Arc<GpopConnection>,
     storage: Arc<StorageManager>,
     broadcaster: Arc<ProgressBroadcaster>,
-    #[allow(dead_code)]
     config: Config,
+    /// Flipped by `shutdown()` so long-running background tasks (currently
+    /// just the retention sweeper) started against this manager can stop
+    /// between ticks instead of being killed mid-scan.
+    shutdown_tx: tokio::sync::watch::Sender<bool>,
 }
 
 impl JobManager {
@@ -33,28 +99,224 @@ impl JobManager {
         broadcaster: Arc<ProgressBroadcaster>,
         config: Config,
     ) -> Self {
+        let transcode_semaphore = Arc::new(Semaphore::new(config.max_concurrent_jobs.max(1)));
+        let demucs_semaphore = Arc::new(Semaphore::new(config.max_concurrent_demucs_jobs.max(1)));
+        let (shutdown_tx, _) = tokio::sync::watch::channel(false);
+
         Self {
             jobs: RwLock::new(HashMap::new()),
             pipeline_to_job: RwLock::new(HashMap::new()),
+            dedup_inflight: DashMap::new(),
+            dedup_completed: DashMap::new(),
+            transcode_semaphore,
+            demucs_semaphore,
+            running_permits: DashMap::new(),
+            transcode_queue: Mutex::new(VecDeque::new()),
+            demucs_queue: Mutex::new(VecDeque::new()),
+            stalled_jobs: DashMap::new(),
+            idempotency_index: DashMap::new(),
             gpop,
             storage,
             broadcaster,
             config,
+            shutdown_tx,
+        }
+    }
+
+    /// Signal background tasks started against this manager (the retention
+    /// sweeper) to stop at their next opportunity.
+    pub fn shutdown(&self) {
+        let _ = self.shutdown_tx.send(true);
+    }
+
+    /// Subscribe to this manager's shutdown signal.
+    fn shutdown_signal(&self) -> tokio::sync::watch::Receiver<bool> {
+        self.shutdown_tx.subscribe()
+    }
+
+    /// Look up a job previously created with the given client-supplied
+    /// idempotency key, if it's still around.
+    pub async fn find_by_idempotency_key(&self, key: &str) -> Option<Job> {
+        let job_id = self.idempotency_index.get(key)?.clone();
+        self.get_job(&job_id).await.ok()
+    }
+
+    /// Record that `key` now maps to `job_id`, so a later submission with the
+    /// same key finds it via `find_by_idempotency_key` instead of starting a
+    /// new job.
+    pub fn register_idempotency_key(&self, key: String, job_id: String) {
+        self.idempotency_index.insert(key, job_id);
+    }
+
+    /// Stamp a freshly-created job with the idempotency key it was submitted
+    /// under and index it, so a retried submission of the same request
+    /// resolves to this job instead of starting a duplicate one.
+    pub async fn set_idempotency_key(self: &Arc<Self>, job_id: &str, key: String) {
+        {
+            let mut jobs = self.jobs.write().await;
+            if let Some(job) = jobs.get_mut(job_id) {
+                job.idempotency_key = Some(key.clone());
+            }
         }
+        self.register_idempotency_key(key, job_id.to_string());
+        self.persist_job(job_id).await;
     }
 
-    /// Create a new transcoding job
+    fn semaphore_for(&self, job_type: JobType) -> &Arc<Semaphore> {
+        match job_type {
+            // Thumbnailing is closer in weight to an encode than to demucs
+            // model inference, so it shares the transcode pool rather than
+            // warranting its own config knob.
+            JobType::Transcode | JobType::Thumbnail => &self.transcode_semaphore,
+            JobType::Demucs => &self.demucs_semaphore,
+        }
+    }
+
+    /// Drop the permit (if any) held for `job_id`, freeing a slot for the
+    /// next queued job of that type.
+    fn release_permit(&self, job_id: &str) {
+        self.running_permits.remove(job_id);
+        self.stalled_jobs.remove(job_id);
+    }
+
+    /// The ordered queue backing `job_type`'s admission control, paired with
+    /// its semaphore the same way `semaphore_for` pairs the gate itself.
+    fn queue_for(&self, job_type: JobType) -> &Mutex<VecDeque<String>> {
+        match job_type {
+            JobType::Transcode | JobType::Thumbnail => &self.transcode_queue,
+            JobType::Demucs => &self.demucs_queue,
+        }
+    }
+
+    /// Push a job onto the back of `job_type`'s pending queue and return its
+    /// 1-indexed position.
+    async fn enqueue_pending(&self, job_type: JobType, job_id: String) -> usize {
+        let mut queue = self.queue_for(job_type).lock().await;
+        queue.push_back(job_id);
+        queue.len()
+    }
+
+    /// Remove `job_id` from `job_type`'s pending queue - normally the front
+    /// entry, since the semaphore it's waiting on grants permits in the same
+    /// FIFO order, but a job deleted while still queued can leave a gap
+    /// further back - then broadcast the updated position of everything
+    /// still waiting behind it.
+    async fn dequeue_pending(&self, job_type: JobType, job_id: &str) {
+        let remaining: Vec<String> = {
+            let mut queue = self.queue_for(job_type).lock().await;
+            if let Some(idx) = queue.iter().position(|id| id == job_id) {
+                queue.remove(idx);
+            }
+            queue.iter().cloned().collect()
+        };
+        for (idx, id) in remaining.into_iter().enumerate() {
+            self.broadcaster.send(ProgressMessage::JobQueued {
+                job_id: id,
+                position: idx + 1,
+            });
+        }
+    }
+
+    /// Snapshot `job_id`'s current state to disk. Called after every status
+    /// transition so a restart can resume from close to where the server
+    /// left off, via [`JobManager::recover`]. Best-effort: a write failure is
+    /// logged, not propagated, since losing a snapshot shouldn't fail the job
+    /// itself.
+    async fn persist_job(&self, job_id: &str) {
+        let job = {
+            let jobs = self.jobs.read().await;
+            jobs.get(job_id).cloned()
+        };
+        if let Some(job) = job {
+            if let Err(e) = persistence::persist_job(self.storage.jobs_dir(), &job).await {
+                warn!("Failed to persist job {}: {}", job_id, e);
+            }
+        }
+    }
+
+    /// Hash the input file together with its serialized options into a
+    /// content-addressed cache key, so re-uploading the same file with the
+    /// same options can be deduplicated. Returns `None` (rather than an
+    /// error) if the input can't be read, since dedup is an optimization and
+    /// should never be the reason a job fails to start.
+    async fn content_key(input_path: &Path, options: &impl Serialize) -> Option<String> {
+        let mut file = tokio::fs::File::open(input_path).await.ok()?;
+        let mut hasher = blake3::Hasher::new();
+        let mut buf = [0u8; 64 * 1024];
+        loop {
+            let n = file.read(&mut buf).await.ok()?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buf[..n]);
+        }
+        let options_json = serde_json::to_vec(options).ok()?;
+        hasher.update(&options_json);
+        Some(hasher.finalize().to_hex().to_string())
+    }
+
+    /// If `key` points at a job that completed successfully and whose
+    /// output still exists, return its id so the caller can reuse it instead
+    /// of launching a new pipeline.
+    async fn reuse_completed_job(&self, key: &str) -> Option<String> {
+        let job_id = self.dedup_completed.get(key)?.clone();
+        match self.get_job(&job_id).await {
+            Ok(job) if job.status == JobStatus::Completed && Self::job_output_present(&job) => {
+                Some(job_id)
+            }
+            _ => {
+                self.dedup_completed.remove(key);
+                None
+            }
+        }
+    }
+
+    /// Whether a completed job's output is still around to be reused or
+    /// downloaded (it may have been cleaned up by the reaper or a manual
+    /// delete, or published and removed from the remote backend).
+    fn job_output_present(job: &Job) -> bool {
+        match job.job_type {
+            JobType::Transcode => job.output_location.is_some() || job.output_path.exists(),
+            JobType::Demucs | JobType::Thumbnail => {
+                !job.stem_locations.is_empty() || job.output_stems.iter().any(|p| p.exists())
+            }
+        }
+    }
+
+    /// Create a new transcoding job. The caller has already streamed the
+    /// upload to `input_path` under `job_id`'s upload directory.
     pub async fn create_transcode_job(
-        &self,
+        self: &Arc<Self>,
+        job_id: String,
         filename: &str,
-        data: &[u8],
+        input_path: PathBuf,
         options: TranscodeOptions,
+        keep_for: Option<ChronoDuration>,
     ) -> Result<String> {
-        // Generate job ID
-        let job_id = uuid::Uuid::new_v4().to_string();
+        self.create_transcode_job_with_key(job_id, filename, input_path, options, keep_for, None)
+            .await
+    }
 
-        // Store the uploaded file
-        let input_path = self.storage.store_upload(&job_id, filename, data).await?;
+    async fn create_transcode_job_with_key(
+        self: &Arc<Self>,
+        job_id: String,
+        filename: &str,
+        input_path: PathBuf,
+        options: TranscodeOptions,
+        keep_for: Option<ChronoDuration>,
+        dedup_key: Option<String>,
+    ) -> Result<String> {
+        // Probe the upload before committing to a pipeline: this is what
+        // catches a format/options combination gpop could never satisfy
+        // (e.g. Mp3 from a silent video) up front instead of deep inside the
+        // pipeline.
+        let media_info = probe_file(&input_path).await?;
+        options
+            .validate_against(&media_info)
+            .map_err(AppError::IncompatibleMedia)?;
+        options
+            .validate_output_target()
+            .map_err(AppError::IncompatibleMedia)?;
 
         // Determine output path
         let output_path = self
@@ -68,6 +330,9 @@ impl JobManager {
             input_path.clone(),
             output_path.clone(),
             options.clone(),
+            keep_for,
+            dedup_key,
+            Some(media_info),
         );
 
         // Store job
@@ -75,6 +340,7 @@ impl JobManager {
             let mut jobs = self.jobs.write().await;
             jobs.insert(job_id.clone(), job);
         }
+        self.persist_job(&job_id).await;
 
         info!(
             "Created transcode job {}: {} -> {}",
@@ -87,23 +353,41 @@ impl JobManager {
         let pipeline_desc = build_transcode_pipeline(&input_path, &output_path, &options);
         debug!("Pipeline for job {}: {}", job_id, pipeline_desc);
 
-        self.start_pipeline(&job_id, &pipeline_desc).await?;
+        self.start_pipeline_when_permitted(job_id.clone(), pipeline_desc, JobType::Transcode)
+            .await?;
 
         Ok(job_id)
     }
 
-    /// Create a new demucs job
+    /// Create a new demucs job. The caller has already streamed the upload
+    /// to `input_path` under `job_id`'s upload directory.
     pub async fn create_demucs_job(
-        &self,
+        self: &Arc<Self>,
+        job_id: String,
         filename: &str,
-        data: &[u8],
+        input_path: PathBuf,
         options: DemucsOptions,
+        keep_for: Option<ChronoDuration>,
     ) -> Result<String> {
-        // Generate job ID
-        let job_id = uuid::Uuid::new_v4().to_string();
+        self.create_demucs_job_with_key(job_id, filename, input_path, options, keep_for, None)
+            .await
+    }
 
-        // Store the uploaded file
-        let input_path = self.storage.store_upload(&job_id, filename, data).await?;
+    async fn create_demucs_job_with_key(
+        self: &Arc<Self>,
+        job_id: String,
+        filename: &str,
+        input_path: PathBuf,
+        options: DemucsOptions,
+        keep_for: Option<ChronoDuration>,
+        dedup_key: Option<String>,
+    ) -> Result<String> {
+        // Probe the upload before committing to a pipeline, same rationale
+        // as the transcode path.
+        let media_info = probe_file(&input_path).await?;
+        options
+            .validate_against(&media_info)
+            .map_err(AppError::IncompatibleMedia)?;
 
         // Create output directory for stems
         let output_dir = self.storage.job_demucs_output_dir(&job_id).await?;
@@ -115,6 +399,9 @@ impl JobManager {
             input_path.clone(),
             output_dir.clone(),
             options.clone(),
+            keep_for,
+            dedup_key,
+            Some(media_info),
         );
 
         // Store job
@@ -122,6 +409,7 @@ impl JobManager {
             let mut jobs = self.jobs.write().await;
             jobs.insert(job_id.clone(), job);
         }
+        self.persist_job(&job_id).await;
 
         info!(
             "Created demucs job {}: {} (model: {})",
@@ -134,13 +422,291 @@ impl JobManager {
         let pipeline_desc = build_demucs_pipeline(&input_path, &output_dir, &options);
         debug!("Pipeline for job {}: {}", job_id, pipeline_desc);
 
-        self.start_pipeline(&job_id, &pipeline_desc).await?;
+        self.start_pipeline_when_permitted(job_id.clone(), pipeline_desc, JobType::Demucs)
+            .await?;
+
+        Ok(job_id)
+    }
+
+    /// Create a new thumbnail/sprite-sheet job. The caller has already
+    /// streamed the upload to `input_path` under `job_id`'s upload
+    /// directory.
+    pub async fn create_thumbnail_job(
+        self: &Arc<Self>,
+        job_id: String,
+        filename: &str,
+        input_path: PathBuf,
+        options: ThumbnailOptions,
+        keep_for: Option<ChronoDuration>,
+    ) -> Result<String> {
+        // Probe the upload before committing to a pipeline, same rationale
+        // as the transcode path.
+        let media_info = probe_file(&input_path).await?;
+        options
+            .validate_against(&media_info)
+            .map_err(AppError::IncompatibleMedia)?;
+        let duration_ns = media_info.duration_ns().ok_or_else(|| {
+            AppError::IncompatibleMedia(
+                "thumbnail generation requires a known duration, but ffprobe reported none"
+                    .to_string(),
+            )
+        })?;
+
+        // Create output directory for frames/sprite
+        let output_dir = self.storage.job_thumbnail_output_dir(&job_id).await?;
+
+        // Create job
+        let job = Job::new_thumbnail(
+            job_id.clone(),
+            filename.to_string(),
+            input_path.clone(),
+            output_dir.clone(),
+            options.clone(),
+            keep_for,
+            None,
+            Some(media_info),
+        );
+
+        // Store job
+        {
+            let mut jobs = self.jobs.write().await;
+            jobs.insert(job_id.clone(), job);
+        }
+        self.persist_job(&job_id).await;
+
+        info!(
+            "Created thumbnail job {}: {} ({} frames)",
+            job_id, filename, options.count
+        );
+
+        // Build and create pipeline
+        let pipeline_desc = build_thumbnail_pipeline(&input_path, &output_dir, &options, duration_ns);
+        debug!("Pipeline for job {}: {}", job_id, pipeline_desc);
+
+        self.start_pipeline_when_permitted(job_id.clone(), pipeline_desc, JobType::Thumbnail)
+            .await?;
 
         Ok(job_id)
     }
 
+    /// Create a transcode job, deduplicating against any other job already
+    /// running (or finished) for the same input content and options. See
+    /// [`JobManager::coalesce_dedup`] for how coalescing works.
+    pub async fn create_transcode_job_dedup(
+        self: &Arc<Self>,
+        job_id: String,
+        filename: String,
+        input_path: PathBuf,
+        options: TranscodeOptions,
+        keep_for: Option<ChronoDuration>,
+    ) -> Result<String> {
+        let key = match Self::content_key(&input_path, &options).await {
+            Some(k) => k,
+            None => {
+                return self
+                    .create_transcode_job(job_id, &filename, input_path, options, keep_for)
+                    .await
+            }
+        };
+
+        let cleanup_job_id = job_id.clone();
+        let manager = Arc::clone(self);
+        let work_key = key.clone();
+        let work = async move {
+            manager
+                .create_transcode_job_with_key(
+                    job_id,
+                    &filename,
+                    input_path,
+                    options,
+                    keep_for,
+                    Some(work_key),
+                )
+                .await
+        };
+
+        self.coalesce_dedup(key, cleanup_job_id, work).await
+    }
+
+    /// Create a demucs job, deduplicating against any other job already
+    /// running (or finished) for the same input content and options. See
+    /// [`JobManager::coalesce_dedup`] for how coalescing works.
+    pub async fn create_demucs_job_dedup(
+        self: &Arc<Self>,
+        job_id: String,
+        filename: String,
+        input_path: PathBuf,
+        options: DemucsOptions,
+        keep_for: Option<ChronoDuration>,
+    ) -> Result<String> {
+        let key = match Self::content_key(&input_path, &options).await {
+            Some(k) => k,
+            None => {
+                return self
+                    .create_demucs_job(job_id, &filename, input_path, options, keep_for)
+                    .await
+            }
+        };
+
+        let cleanup_job_id = job_id.clone();
+        let manager = Arc::clone(self);
+        let work_key = key.clone();
+        let work = async move {
+            manager
+                .create_demucs_job_with_key(
+                    job_id,
+                    &filename,
+                    input_path,
+                    options,
+                    keep_for,
+                    Some(work_key),
+                )
+                .await
+        };
+
+        self.coalesce_dedup(key, cleanup_job_id, work).await
+    }
+
+    /// Coalesce a request for content identified by `key` onto whatever is
+    /// already happening for it:
+    ///
+    /// - If a job for this exact content+options already completed and its
+    ///   output is still around, return its id immediately without starting
+    ///   anything.
+    /// - If one is currently being launched, subscribe to its outcome
+    ///   instead of launching a second one.
+    /// - Otherwise, become the one driving `work`.
+    ///
+    /// `work` always runs to completion on a detached task, even if the
+    /// caller that triggered it (e.g. an HTTP request whose client
+    /// disconnects) is dropped while awaiting the result here — any other
+    /// request coalesced onto the same key is still waiting on it and must
+    /// not be orphaned. This mirrors pict-rs's `CancelSafeProcessor`.
+    async fn coalesce_dedup<Fut>(
+        self: &Arc<Self>,
+        key: String,
+        job_id: String,
+        work: Fut,
+    ) -> Result<String>
+    where
+        Fut: std::future::Future<Output = Result<String>> + Send + 'static,
+    {
+        if let Some(existing) = self.reuse_completed_job(&key).await {
+            info!("Deduped job {} -> reusing completed job {}", job_id, existing);
+            let _ = self.storage.cleanup_job(&job_id).await;
+            return Ok(existing);
+        }
+
+        // Resolve the entry and drop the dashmap shard guard before ever
+        // awaiting anything else, so we never hold it across an await point.
+        let (mut rx, joined_inflight) = match self.dedup_inflight.entry(key.clone()) {
+            Entry::Occupied(e) => (e.get().subscribe(), true),
+            Entry::Vacant(e) => {
+                let (tx, rx) = broadcast::channel(1);
+                e.insert(tx.clone());
+
+                let manager = Arc::clone(self);
+                let key_for_task = key.clone();
+                tokio::spawn(async move {
+                    let outcome = work.await;
+                    // Send the result and vacate the slot under the same
+                    // `DashMap` shard lock (an `Entry` guard blocks other
+                    // `entry()` calls on this key until dropped), so a
+                    // request arriving in between can't see the slot as
+                    // both empty and missing the result: it either joins
+                    // via `subscribe()` before we remove it, or waits for
+                    // the lock and then correctly starts a new leader.
+                    if let Entry::Occupied(e) = manager.dedup_inflight.entry(key_for_task) {
+                        let _ = e.get().send(outcome.map_err(|e| e.to_string()));
+                        e.remove();
+                    }
+                });
+
+                (rx, false)
+            }
+        };
+
+        if joined_inflight {
+            debug!("Joining in-flight job for dedup key {}", key);
+            let _ = self.storage.cleanup_job(&job_id).await;
+        }
+
+        match rx.recv().await {
+            Ok(Ok(job_id)) => Ok(job_id),
+            Ok(Err(msg)) => Err(AppError::PipelineCreation(msg)),
+            Err(_) => Err(AppError::Internal(
+                "Dedup leader finished without producing a result".to_string(),
+            )),
+        }
+    }
+
+    /// Acquire a permit from the job-type's semaphore before starting its
+    /// pipeline, gating how many transcode/demucs pipelines run at once.
+    ///
+    /// If a permit is immediately available, this behaves exactly like
+    /// calling `start_pipeline` directly, surfacing any failure to the
+    /// caller. If the pool is saturated, the job is left in `Pending`, pushed
+    /// onto `queue_for(job_type)` and a `ProgressMessage::JobQueued` is
+    /// broadcast with its position, and a background task takes over: it
+    /// waits for a permit - granted in the same order jobs were queued,
+    /// since `tokio::sync::Semaphore` is FIFO - then dequeues and starts the
+    /// pipeline once one frees up. The permit is held until the job
+    /// finishes, see [`JobManager::release_permit`].
+    async fn start_pipeline_when_permitted(
+        self: &Arc<Self>,
+        job_id: String,
+        pipeline_desc: String,
+        job_type: JobType,
+    ) -> Result<()> {
+        let semaphore = Arc::clone(self.semaphore_for(job_type));
+
+        match Arc::clone(&semaphore).try_acquire_owned() {
+            Ok(permit) => {
+                self.running_permits.insert(job_id.clone(), permit);
+                if let Err(e) = self.start_pipeline(&job_id, &pipeline_desc).await {
+                    self.release_permit(&job_id);
+                    return Err(e);
+                }
+                Ok(())
+            }
+            Err(_) => {
+                let position = self.enqueue_pending(job_type, job_id.clone()).await;
+                info!(
+                    "{:?} concurrency limit reached, queuing job {} at position {}",
+                    job_type, job_id, position
+                );
+                self.broadcaster.send(ProgressMessage::JobQueued {
+                    job_id: job_id.clone(),
+                    position,
+                });
+
+                let manager = Arc::clone(self);
+                tokio::spawn(async move {
+                    let permit = match semaphore.acquire_owned().await {
+                        Ok(permit) => permit,
+                        Err(_) => return, // semaphore closed, manager is shutting down
+                    };
+
+                    manager.dequeue_pending(job_type, &job_id).await;
+
+                    // The job may have been deleted while it was queued.
+                    if !manager.jobs.read().await.contains_key(&job_id) {
+                        return;
+                    }
+
+                    manager.running_permits.insert(job_id.clone(), permit);
+                    if let Err(e) = manager.start_pipeline(&job_id, &pipeline_desc).await {
+                        error!("Failed to start queued job {}: {}", job_id, e);
+                        manager.release_permit(&job_id);
+                    }
+                });
+                Ok(())
+            }
+        }
+    }
+
     /// Start a pipeline for a job
-    async fn start_pipeline(&self, job_id: &str, pipeline_desc: &str) -> Result<()> {
+    async fn start_pipeline(self: &Arc<Self>, job_id: &str, pipeline_desc: &str) -> Result<()> {
         match self.gpop.create_pipeline(pipeline_desc).await {
             Ok(pipeline_id) => {
                 // Update job with pipeline ID
@@ -150,8 +716,10 @@ impl JobManager {
                         job.pipeline_id = Some(pipeline_id.clone());
                         job.status = JobStatus::Processing;
                         job.started_at = Some(Utc::now());
+                        job.last_progress_at = Some(Utc::now());
                     }
                 }
+                self.persist_job(job_id).await;
 
                 // Map pipeline to job
                 {
@@ -162,7 +730,7 @@ impl JobManager {
                 // Start the pipeline
                 if let Err(e) = self.gpop.play(&pipeline_id).await {
                     error!("Failed to start pipeline for job {}: {}", job_id, e);
-                    self.mark_job_failed(job_id, &e.to_string()).await;
+                    self.mark_job_failed(job_id, JobError::PipelineError(e.to_string())).await;
                     return Err(AppError::PipelineCreation(e.to_string()));
                 }
 
@@ -171,11 +739,22 @@ impl JobManager {
                     job_id: job_id.to_string(),
                 });
 
+                // Best-effort: start the daemon's element/property stats
+                // poller for this pipeline so the UI gets live encoder
+                // stats. A failure here shouldn't fail the job, it just
+                // means no stats stream for it.
+                if let Err(e) = self.gpop.subscribe_stats(&pipeline_id, None).await {
+                    warn!(
+                        "Failed to subscribe to stats for pipeline {} (job {}): {}",
+                        pipeline_id, job_id, e
+                    );
+                }
+
                 Ok(())
             }
             Err(e) => {
                 error!("Failed to create pipeline for job {}: {}", job_id, e);
-                self.mark_job_failed(job_id, &e.to_string()).await;
+                self.mark_job_failed(job_id, JobError::PipelineError(e.to_string())).await;
                 Err(AppError::PipelineCreation(e.to_string()))
             }
         }
@@ -212,12 +791,34 @@ impl JobManager {
                         (None, None)
                     }
                 }
+                JobType::Thumbnail => {
+                    // Generate download URLs for each frame (or sprite+index)
+                    let urls: Vec<StemDownload> = job
+                        .output_stems
+                        .iter()
+                        .filter_map(|p| p.file_stem().and_then(|s| s.to_str()))
+                        .map(|name| StemDownload {
+                            stem: name.to_string(),
+                            url: format!("/api/jobs/{}/download/{}", job_id, name),
+                        })
+                        .collect();
+                    (None, Some(urls))
+                }
             }
         } else {
             (None, None)
         };
 
-        Ok(JobDetails::from_job(&job, download_url, download_urls))
+        // Probing is only meaningful (and the output only exists) once the
+        // job is done; skip it for pending/processing jobs rather than
+        // shelling out to ffprobe on every poll.
+        let media = if job.status == JobStatus::Completed && job.job_type == JobType::Transcode {
+            probe_file(&job.output_path).await.ok()
+        } else {
+            None
+        };
+
+        Ok(JobDetails::from_job(&job, download_url, download_urls, media))
     }
 
     /// List all jobs
@@ -226,6 +827,81 @@ impl JobManager {
         jobs.values().map(JobSummary::from).collect()
     }
 
+    /// Pause a running job's pipeline (GStreamer PLAYING -> PAUSED),
+    /// retaining its position so `resume_job` can pick up where it left off
+    /// instead of restarting from scratch.
+    pub async fn pause_job(self: &Arc<Self>, job_id: &str) -> Result<()> {
+        let pipeline_id = {
+            let jobs = self.jobs.read().await;
+            let job = jobs
+                .get(job_id)
+                .ok_or_else(|| AppError::JobNotFound(job_id.to_string()))?;
+            if job.status != JobStatus::Processing {
+                return Err(AppError::InvalidJobState(format!(
+                    "job {} is {:?}, not Processing, and cannot be paused",
+                    job_id, job.status
+                )));
+            }
+            job.pipeline_id
+                .clone()
+                .ok_or_else(|| AppError::InvalidJobState(format!("job {} has no pipeline", job_id)))?
+        };
+
+        self.gpop.pause(&pipeline_id).await?;
+
+        {
+            let mut jobs = self.jobs.write().await;
+            if let Some(job) = jobs.get_mut(job_id) {
+                job.status = JobStatus::Paused;
+            }
+        }
+        self.persist_job(job_id).await;
+        self.stalled_jobs.remove(job_id);
+
+        self.broadcaster.send(ProgressMessage::JobPaused {
+            job_id: job_id.to_string(),
+        });
+
+        Ok(())
+    }
+
+    /// Resume a paused job's pipeline (GStreamer PAUSED -> PLAYING), picking
+    /// up from the position it was paused at.
+    pub async fn resume_job(self: &Arc<Self>, job_id: &str) -> Result<()> {
+        let pipeline_id = {
+            let jobs = self.jobs.read().await;
+            let job = jobs
+                .get(job_id)
+                .ok_or_else(|| AppError::JobNotFound(job_id.to_string()))?;
+            if job.status != JobStatus::Paused {
+                return Err(AppError::InvalidJobState(format!(
+                    "job {} is {:?}, not Paused, and cannot be resumed",
+                    job_id, job.status
+                )));
+            }
+            job.pipeline_id
+                .clone()
+                .ok_or_else(|| AppError::InvalidJobState(format!("job {} has no pipeline", job_id)))?
+        };
+
+        self.gpop.play(&pipeline_id).await?;
+
+        {
+            let mut jobs = self.jobs.write().await;
+            if let Some(job) = jobs.get_mut(job_id) {
+                job.status = JobStatus::Processing;
+                job.last_progress_at = Some(Utc::now());
+            }
+        }
+        self.persist_job(job_id).await;
+
+        self.broadcaster.send(ProgressMessage::JobResumed {
+            job_id: job_id.to_string(),
+        });
+
+        Ok(())
+    }
+
     /// Cancel and delete a job
     pub async fn delete_job(&self, job_id: &str) -> Result<()> {
         let job = {
@@ -234,6 +910,9 @@ impl JobManager {
         };
 
         if let Some(job) = job {
+            self.release_permit(job_id);
+            self.dequeue_pending(job.job_type, job_id).await;
+
             // Stop the pipeline if running
             if let Some(pipeline_id) = &job.pipeline_id {
                 let _ = self.gpop.stop(pipeline_id).await;
@@ -255,10 +934,49 @@ impl JobManager {
                             .cleanup_output(job_id, opts.output_format.extension())
                             .await;
                     }
+                    // Also remove the published copy (no-op for the local
+                    // backend, since cleanup_output already removed the file).
+                    if let Some(opts) = job.transcode_options() {
+                        let key = format!("{}.{}", job_id, opts.output_format.extension());
+                        let _ = self.storage.remove_published_output(&key).await;
+                    }
                 }
                 JobType::Demucs => {
                     // Clean up entire output directory
                     let _ = self.storage.cleanup_demucs_output(job_id).await;
+                    for path in &job.output_stems {
+                        if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
+                            let key = format!("{}_stems/{}", job_id, filename);
+                            let _ = self.storage.remove_published_output(&key).await;
+                        }
+                    }
+                }
+                JobType::Thumbnail => {
+                    // Clean up entire output directory
+                    let _ = self.storage.cleanup_thumbnail_output(job_id).await;
+                    for path in &job.output_stems {
+                        if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
+                            let key = format!("{}_thumbnails/{}", job_id, filename);
+                            let _ = self.storage.remove_published_output(&key).await;
+                        }
+                    }
+                }
+            }
+
+            self.evict_dedup_entry(&job);
+
+            if let Err(e) = persistence::remove_job_record(self.storage.jobs_dir(), job_id).await {
+                warn!("Failed to remove persisted record for job {}: {}", job_id, e);
+            }
+
+            // Cascade to any jobs spawned from this one's follow-ups, so
+            // deleting the head of a chain doesn't orphan the rest of it.
+            for child_id in &job.child_ids {
+                if let Err(e) = Box::pin(self.delete_job(child_id)).await {
+                    warn!(
+                        "Failed to cascade-delete child job {} of {}: {}",
+                        child_id, job_id, e
+                    );
                 }
             }
 
@@ -269,6 +987,22 @@ impl JobManager {
         }
     }
 
+    /// Remove `job`'s content-addressed cache entry, if it has one and it
+    /// still points at this job. Called whenever a job's output stops being
+    /// something a future dedup lookup should be allowed to reuse.
+    fn evict_dedup_entry(&self, job: &Job) {
+        if let Some(key) = &job.dedup_key {
+            if self
+                .dedup_completed
+                .get(key)
+                .map(|entry| *entry == job.id)
+                .unwrap_or(false)
+            {
+                self.dedup_completed.remove(key);
+            }
+        }
+    }
+
     /// Get a stem file path for demucs job download
     pub async fn get_demucs_stem_path(&self, job_id: &str, stem: &str) -> Result<PathBuf> {
         let job = self.get_job(job_id).await?;
@@ -300,67 +1034,477 @@ impl JobManager {
         Err(AppError::FileNotFound(format!("Stem '{}' not found", stem)))
     }
 
-    /// Mark a job as failed
-    async fn mark_job_failed(&self, job_id: &str, error: &str) {
-        {
+    /// Get a thumbnail job's output file (a frame, or the sprite/index) by
+    /// name, looked up among the files `mark_job_completed` recorded on
+    /// `output_stems`.
+    pub async fn get_thumbnail_file_path(&self, job_id: &str, name: &str) -> Result<PathBuf> {
+        let job = self.get_job(job_id).await?;
+
+        if job.job_type != JobType::Thumbnail {
+            return Err(AppError::Internal("Not a thumbnail job".to_string()));
+        }
+
+        if job.status != JobStatus::Completed {
+            return Err(AppError::Internal("Job not completed".to_string()));
+        }
+
+        job.output_stems
+            .iter()
+            .find(|p| p.file_stem().and_then(|s| s.to_str()) == Some(name))
+            .cloned()
+            .filter(|p| p.exists())
+            .ok_or_else(|| AppError::FileNotFound(format!("Thumbnail output '{}' not found", name)))
+    }
+
+    /// Mark a job as failed. A retriable error on a job that hasn't
+    /// exhausted its `max_retries` is rescheduled with an exponential
+    /// backoff instead of being given up on; everything else (or a job out
+    /// of retries) transitions straight to the terminal `Failed` status.
+    async fn mark_job_failed(self: &Arc<Self>, job_id: &str, error: JobError) {
+        self.release_permit(job_id);
+
+        enum Outcome {
+            Retry {
+                attempt: u32,
+                delay_ms: u64,
+                job_type: JobType,
+                options: JobOptions,
+                input_path: PathBuf,
+                output_path: PathBuf,
+            },
+            Terminal {
+                dedup_key: Option<String>,
+            },
+            JobGone,
+        }
+
+        let outcome = {
             let mut jobs = self.jobs.write().await;
-            if let Some(job) = jobs.get_mut(job_id) {
-                job.status = JobStatus::Failed;
-                job.error = Some(error.to_string());
-                job.completed_at = Some(Utc::now());
+            match jobs.get_mut(job_id) {
+                Some(job) if error.is_retriable() && job.retry_count < job.max_retries => {
+                    job.retry_count += 1;
+                    let delay_ms = retry_backoff_ms(job.retry_count);
+                    job.status = JobStatus::Retrying;
+                    job.error = Some(error.clone());
+                    job.pipeline_id = None;
+                    job.next_retry_at =
+                        Some(Utc::now() + ChronoDuration::milliseconds(delay_ms as i64));
+                    Outcome::Retry {
+                        attempt: job.retry_count,
+                        delay_ms,
+                        job_type: job.job_type,
+                        options: job.options.clone(),
+                        input_path: job.input_path.clone(),
+                        output_path: job.output_path.clone(),
+                    }
+                }
+                Some(job) => {
+                    job.status = JobStatus::Failed;
+                    job.error = Some(error.clone());
+                    job.completed_at = Some(Utc::now());
+                    Outcome::Terminal {
+                        dedup_key: job.dedup_key.clone(),
+                    }
+                }
+                None => Outcome::JobGone,
+            }
+        };
+        self.persist_job(job_id).await;
+
+        match outcome {
+            Outcome::Retry {
+                attempt,
+                delay_ms,
+                job_type,
+                options,
+                input_path,
+                output_path,
+            } => {
+                info!(
+                    "Job {} failed ({}), retrying attempt {} in {}ms",
+                    job_id, error, attempt, delay_ms
+                );
+                self.broadcaster.send(ProgressMessage::JobRetrying {
+                    job_id: job_id.to_string(),
+                    attempt,
+                    delay_ms,
+                });
+
+                let manager = Arc::clone(self);
+                let job_id = job_id.to_string();
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+
+                    // The job may have been deleted while it was waiting to retry.
+                    let duration_ns = match manager.jobs.read().await.get(&job_id) {
+                        Some(job) => job.duration_ns,
+                        None => return,
+                    };
+
+                    let pipeline_desc = match (&job_type, &options) {
+                        (JobType::Transcode, JobOptions::Transcode(opts)) => {
+                            build_transcode_pipeline(&input_path, &output_path, opts)
+                        }
+                        (JobType::Demucs, JobOptions::Demucs(opts)) => {
+                            build_demucs_pipeline(&input_path, &output_path, opts)
+                        }
+                        (JobType::Thumbnail, JobOptions::Thumbnail(opts)) => {
+                            build_thumbnail_pipeline(
+                                &input_path,
+                                &output_path,
+                                opts,
+                                duration_ns.unwrap_or(0),
+                            )
+                        }
+                        _ => return,
+                    };
+
+                    if let Err(e) = manager
+                        .start_pipeline_when_permitted(job_id.clone(), pipeline_desc, job_type)
+                        .await
+                    {
+                        error!("Retry attempt for job {} failed to (re)start: {}", job_id, e);
+                    }
+                });
             }
+            Outcome::Terminal { dedup_key } => {
+                // A failed job is never worth reusing; evict it so the next
+                // identical upload gets a fresh attempt instead of reusing a
+                // job that's stuck in Failed.
+                if let Some(key) = dedup_key {
+                    if self
+                        .dedup_completed
+                        .get(&key)
+                        .map(|entry| *entry == job_id)
+                        .unwrap_or(false)
+                    {
+                        self.dedup_completed.remove(&key);
+                    }
+                }
+
+                self.broadcaster.send(ProgressMessage::JobFailed {
+                    job_id: job_id.to_string(),
+                    error: error.to_string(),
+                });
+            }
+            Outcome::JobGone => {}
         }
+    }
 
-        self.broadcaster.send(ProgressMessage::JobFailed {
-            job_id: job_id.to_string(),
-            error: error.to_string(),
-        });
+    /// Collect a completed thumbnail job's output files: the individual
+    /// frames the pipeline wrote, or, for `sprite: true`, the single tiled
+    /// grid image plus its JSON index (compositing frames is CPU-bound, so
+    /// it runs on a blocking thread rather than the async executor).
+    async fn finish_thumbnail_output(
+        &self,
+        job_id: &str,
+        output_dir: &Path,
+        options: &Option<ThumbnailOptions>,
+        duration_ns: Option<u64>,
+    ) -> Vec<PathBuf> {
+        let Some(options) = options else {
+            return vec![];
+        };
+
+        let frame_files = get_thumbnail_frame_files(output_dir, options);
+
+        if !options.sprite {
+            return frame_files.into_iter().map(|(_, path)| path).collect();
+        }
+
+        let timestamps = options.timestamps_ns(duration_ns.unwrap_or(0));
+        let frames: Vec<(PathBuf, u64)> = frame_files
+            .into_iter()
+            .zip(timestamps)
+            .map(|((_, path), ts)| (path, ts))
+            .collect();
+
+        let options = options.clone();
+        let output_dir = output_dir.to_path_buf();
+        let result = tokio::task::spawn_blocking(move || {
+            assemble_thumbnail_sprite(&frames, &options, &output_dir)
+        })
+        .await;
+
+        match result {
+            Ok(Ok((sprite_path, index_path))) => vec![sprite_path, index_path],
+            Ok(Err(e)) => {
+                warn!("Failed to assemble thumbnail sprite for job {}: {}", job_id, e);
+                vec![]
+            }
+            Err(e) => {
+                warn!("Thumbnail sprite task panicked for job {}: {}", job_id, e);
+                vec![]
+            }
+        }
     }
 
-    /// Mark a job as completed
-    async fn mark_job_completed(&self, job_id: &str) {
-        let job_type = {
+    /// Mark a job as completed. Publishes the finished output(s) to the
+    /// configured storage backend before flipping the job to `Completed`, so
+    /// a client that sees the completion event can immediately resolve a
+    /// download URL.
+    async fn mark_job_completed(self: &Arc<Self>, job_id: &str) {
+        self.release_permit(job_id);
+
+        let (job_type, output_path, transcode_opts, demucs_opts, thumbnail_opts, media_info, duration_ns, dedup_key, follow_ups) = {
+            let jobs = self.jobs.read().await;
+            match jobs.get(job_id) {
+                Some(job) => (
+                    job.job_type,
+                    job.output_path.clone(),
+                    job.transcode_options().cloned(),
+                    job.demucs_options().cloned(),
+                    job.thumbnail_options().cloned(),
+                    job.media_info.clone(),
+                    job.duration_ns,
+                    job.dedup_key.clone(),
+                    job.follow_ups.clone(),
+                ),
+                None => return,
+            }
+        };
+
+        let (output_location, output_stems, stem_locations) = match job_type {
+            JobType::Transcode => {
+                if let Some(opts) = &transcode_opts {
+                    if let Some(tags) = opts.resolved_tags(media_info.as_ref()) {
+                        if let Err(e) = write_tags(&output_path, opts.output_format, &tags) {
+                            warn!("Failed to write tags for job {}: {}", job_id, e);
+                        }
+                    }
+                }
+                let key = output_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or(job_id)
+                    .to_string();
+                let location = match self.storage.publish_output(&output_path, &key).await {
+                    Ok(loc) => Some(loc),
+                    Err(e) => {
+                        warn!("Failed to publish output for job {}: {}", job_id, e);
+                        None
+                    }
+                };
+                (location, vec![], vec![])
+            }
+            JobType::Demucs => {
+                let mut stems = vec![];
+                let mut locations = vec![];
+                if let Some(opts) = &demucs_opts {
+                    for (stem, path) in get_demucs_output_files(&output_path, opts) {
+                        let key = format!(
+                            "{}_stems/{}",
+                            job_id,
+                            path.file_name().and_then(|n| n.to_str()).unwrap_or(&stem)
+                        );
+                        match self.storage.publish_output(&path, &key).await {
+                            Ok(loc) => locations.push((stem.clone(), loc)),
+                            Err(e) => warn!(
+                                "Failed to publish stem '{}' for job {}: {}",
+                                stem, job_id, e
+                            ),
+                        }
+                        stems.push(path);
+                    }
+                }
+                (None, stems, locations)
+            }
+            JobType::Thumbnail => {
+                let stems = self
+                    .finish_thumbnail_output(job_id, &output_path, &thumbnail_opts, duration_ns)
+                    .await;
+                let mut locations = vec![];
+                for path in &stems {
+                    let key = format!(
+                        "{}_thumbnails/{}",
+                        job_id,
+                        path.file_name().and_then(|n| n.to_str()).unwrap_or("frame")
+                    );
+                    match self.storage.publish_output(path, &key).await {
+                        Ok(loc) => locations.push((
+                            path.file_stem().and_then(|n| n.to_str()).unwrap_or("frame").to_string(),
+                            loc,
+                        )),
+                        Err(e) => warn!(
+                            "Failed to publish thumbnail output '{}' for job {}: {}",
+                            path.display(),
+                            job_id,
+                            e
+                        ),
+                    }
+                }
+                (None, stems, locations)
+            }
+        };
+
+        {
             let mut jobs = self.jobs.write().await;
             if let Some(job) = jobs.get_mut(job_id) {
                 job.status = JobStatus::Completed;
                 job.progress = 1.0;
                 job.completed_at = Some(Utc::now());
+                job.output_location = output_location;
+                job.output_stems = output_stems.clone();
+                job.stem_locations = stem_locations;
+            }
+        }
+        self.persist_job(job_id).await;
 
-                // For demucs jobs, populate output_stems
-                if job.job_type == JobType::Demucs {
-                    if let Some(opts) = job.demucs_options() {
-                        let stem_files = get_demucs_output_files(&job.output_path, opts);
-                        job.output_stems = stem_files.into_iter().map(|(_, p)| p).collect();
-                    }
-                }
+        // Chain any follow-up jobs onto this one's finished output(s) before
+        // anything below gets a chance to clean them up.
+        self.spawn_follow_ups(job_id, job_type, &output_path, &output_stems, &follow_ups)
+            .await;
 
-                Some(job.job_type)
-            } else {
-                None
-            }
-        };
+        // Now that the job's output actually exists, let future uploads of
+        // the same content+options reuse it instead of reprocessing.
+        if let Some(key) = dedup_key {
+            self.dedup_completed.insert(key, job_id.to_string());
+        }
 
         // Send appropriate completion message
         match job_type {
-            Some(JobType::Transcode) => {
+            JobType::Transcode => {
                 let download_url = format!("/api/jobs/{}/download", job_id);
                 self.broadcaster.send(ProgressMessage::JobCompleted {
                     job_id: job_id.to_string(),
                     download_url,
                 });
             }
-            Some(JobType::Demucs) => {
-                // For demucs, we use a special message with multiple URLs
+            JobType::Demucs | JobType::Thumbnail => {
+                // Both produce multiple output files, so they share the
+                // same "check download_urls" completion message as demucs.
                 self.broadcaster.send(ProgressMessage::DemucsCompleted {
                     job_id: job_id.to_string(),
                 });
             }
-            None => {}
         }
 
         info!("Job {} completed", job_id);
     }
 
+    /// Enqueue `follow_ups` against the job's finished output(s) - every
+    /// stem for a multi-output job (demucs/thumbnail), or the single output
+    /// file for a transcode job - bypassing the multipart upload step
+    /// entirely since the bytes are already on disk. Best-effort per
+    /// spec/input pair: a follow-up that can't be created (e.g.
+    /// thumbnailing a stem with no video stream) is logged and skipped
+    /// rather than failing the job it's chained off of.
+    async fn spawn_follow_ups(
+        self: &Arc<Self>,
+        job_id: &str,
+        job_type: JobType,
+        output_path: &Path,
+        output_stems: &[PathBuf],
+        follow_ups: &[JobOptions],
+    ) {
+        if follow_ups.is_empty() {
+            return;
+        }
+
+        let inputs: Vec<&Path> = match job_type {
+            JobType::Demucs | JobType::Thumbnail => {
+                output_stems.iter().map(PathBuf::as_path).collect()
+            }
+            JobType::Transcode => vec![output_path],
+        };
+
+        let mut child_ids = Vec::new();
+        for spec in follow_ups {
+            for input_path in &inputs {
+                if !input_path.exists() {
+                    continue;
+                }
+                let filename = input_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("output")
+                    .to_string();
+                let child_id = uuid::Uuid::new_v4().to_string();
+
+                let result = match spec.clone() {
+                    JobOptions::Transcode(opts) => {
+                        self.create_transcode_job(
+                            child_id.clone(),
+                            &filename,
+                            input_path.to_path_buf(),
+                            opts,
+                            None,
+                        )
+                        .await
+                    }
+                    JobOptions::Demucs(opts) => {
+                        self.create_demucs_job(
+                            child_id.clone(),
+                            &filename,
+                            input_path.to_path_buf(),
+                            opts,
+                            None,
+                        )
+                        .await
+                    }
+                    JobOptions::Thumbnail(opts) => {
+                        self.create_thumbnail_job(
+                            child_id.clone(),
+                            &filename,
+                            input_path.to_path_buf(),
+                            opts,
+                            None,
+                        )
+                        .await
+                    }
+                };
+
+                match result {
+                    Ok(id) => {
+                        self.set_parent(&id, job_id).await;
+                        child_ids.push(id);
+                    }
+                    Err(e) => warn!(
+                        "Failed to enqueue follow-up job for parent {} (input {}): {}",
+                        job_id,
+                        input_path.display(),
+                        e
+                    ),
+                }
+            }
+        }
+
+        if !child_ids.is_empty() {
+            {
+                let mut jobs = self.jobs.write().await;
+                if let Some(job) = jobs.get_mut(job_id) {
+                    job.child_ids.extend(child_ids);
+                }
+            }
+            self.persist_job(job_id).await;
+        }
+    }
+
+    /// Record that `job_id` was spawned as a follow-up of `parent_id`.
+    async fn set_parent(&self, job_id: &str, parent_id: &str) {
+        {
+            let mut jobs = self.jobs.write().await;
+            if let Some(job) = jobs.get_mut(job_id) {
+                job.parent_id = Some(parent_id.to_string());
+            }
+        }
+        self.persist_job(job_id).await;
+    }
+
+    /// Attach follow-up job specs to run automatically against `job_id`'s
+    /// finished output(s) once it completes successfully. Set after
+    /// creation rather than threaded through `create_*_job`, same shape as
+    /// `set_idempotency_key`.
+    pub async fn set_follow_ups(self: &Arc<Self>, job_id: &str, follow_ups: Vec<JobOptions>) {
+        {
+            let mut jobs = self.jobs.write().await;
+            if let Some(job) = jobs.get_mut(job_id) {
+                job.follow_ups = follow_ups;
+            }
+        }
+        self.persist_job(job_id).await;
+    }
+
     /// Update job progress
     async fn update_job_progress(
         &self,
@@ -376,6 +1520,10 @@ impl JobManager {
         {
             let mut jobs = self.jobs.write().await;
             if let Some(job) = jobs.get_mut(job_id) {
+                if position_ns.is_some() && position_ns != job.position_ns {
+                    job.last_progress_at = Some(Utc::now());
+                    self.stalled_jobs.remove(job_id);
+                }
                 job.progress = progress;
                 job.position_ns = position_ns;
                 job.duration_ns = duration_ns;
@@ -391,7 +1539,7 @@ impl JobManager {
     }
 
     /// Handle events from gpop-daemon
-    pub async fn handle_gpop_event(&self, event: GpopEvent) {
+    pub async fn handle_gpop_event(self: &Arc<Self>, event: GpopEvent) {
         match event {
             GpopEvent::Eos { pipeline_id } => {
                 if let Some(job_id) = self.get_job_id_for_pipeline(&pipeline_id).await {
@@ -410,7 +1558,7 @@ impl JobManager {
                 message,
             } => {
                 if let Some(job_id) = self.get_job_id_for_pipeline(&pipeline_id).await {
-                    self.mark_job_failed(&job_id, &message).await;
+                    self.mark_job_failed(&job_id, JobError::PipelineError(message)).await;
 
                     // Clean up the pipeline
                     let _ = self.gpop.remove_pipeline(&pipeline_id).await;
@@ -426,6 +1574,26 @@ impl JobManager {
                 new_state,
             } => {
                 if let Some(job_id) = self.get_job_id_for_pipeline(&pipeline_id).await {
+                    // Keep status coherent with the pipeline's actual state
+                    // even if it changed outside of `pause_job`/`resume_job`
+                    // (e.g. the daemon itself paused it), so a pause/resume
+                    // cycle always resumes from the retained position
+                    // instead of `poll_progress` or a stray `play` racing a
+                    // status that no longer matches reality.
+                    let mut jobs = self.jobs.write().await;
+                    if let Some(job) = jobs.get_mut(&job_id) {
+                        match (job.status, new_state.as_str()) {
+                            (JobStatus::Processing, "PAUSED") => job.status = JobStatus::Paused,
+                            (JobStatus::Paused, "PLAYING") => {
+                                job.status = JobStatus::Processing;
+                                job.last_progress_at = Some(Utc::now());
+                            }
+                            _ => {}
+                        }
+                    }
+                    drop(jobs);
+                    self.persist_job(&job_id).await;
+
                     self.broadcaster.send(ProgressMessage::StateChanged {
                         job_id,
                         old_state,
@@ -433,6 +1601,11 @@ impl JobManager {
                     });
                 }
             }
+            GpopEvent::Stats { pipeline_id, stats } => {
+                if let Some(job_id) = self.get_job_id_for_pipeline(&pipeline_id).await {
+                    self.broadcaster.send(ProgressMessage::Stats { job_id, stats });
+                }
+            }
             _ => {}
         }
     }
@@ -444,7 +1617,7 @@ impl JobManager {
     }
 
     /// Poll progress for all active jobs
-    pub async fn poll_progress(&self) {
+    pub async fn poll_progress(self: &Arc<Self>) {
         let active_jobs: Vec<(String, String)> = {
             let jobs = self.jobs.read().await;
             jobs.values()
@@ -459,9 +1632,227 @@ impl JobManager {
                     self.update_job_progress(&job_id, pos.position_ns, pos.duration_ns)
                         .await;
                 }
+                Err(e) if e.is_retriable() => {
+                    debug!("Failed to get position for job {} (will retry): {}", job_id, e);
+                }
                 Err(e) => {
-                    debug!("Failed to get position for job {}: {}", job_id, e);
+                    error!("Failed to get position for job {}: {}", job_id, e);
+                    self.mark_job_failed(&job_id, JobError::PipelineError(e.to_string())).await;
+                }
+            }
+        }
+
+        self.check_stalled_jobs().await;
+    }
+
+    /// Scan `Processing` jobs for ones whose position hasn't advanced within
+    /// `config.stall_timeout_secs` and warn about them, escalating to a
+    /// forced failure (tearing down the pipeline) once a job has gone
+    /// `stall_timeout_secs + stall_fail_grace_secs` without progress.
+    async fn check_stalled_jobs(self: &Arc<Self>) {
+        let now = Utc::now();
+        let candidates: Vec<(String, Option<String>, i64)> = {
+            let jobs = self.jobs.read().await;
+            jobs.values()
+                .filter(|j| j.status == JobStatus::Processing)
+                .filter_map(|j| {
+                    let last_progress_at = j.last_progress_at?;
+                    let stalled_for_ms = (now - last_progress_at).num_milliseconds().max(0);
+                    Some((j.id.clone(), j.pipeline_id.clone(), stalled_for_ms))
+                })
+                .collect()
+        };
+
+        let timeout_ms = self.config.stall_timeout_secs.saturating_mul(1_000);
+        let fail_after_ms = timeout_ms.saturating_add(self.config.stall_fail_grace_secs.saturating_mul(1_000));
+
+        for (job_id, pipeline_id, stalled_for_ms) in candidates {
+            let stalled_for_ms = stalled_for_ms as u64;
+            if stalled_for_ms < timeout_ms {
+                continue;
+            }
+
+            if stalled_for_ms >= fail_after_ms {
+                warn!(
+                    "Job {} stalled for {}ms, exceeding grace period; failing it",
+                    job_id, stalled_for_ms
+                );
+                self.stalled_jobs.remove(&job_id);
+                if let Some(pipeline_id) = pipeline_id {
+                    let _ = self.gpop.stop(&pipeline_id).await;
+                    let _ = self.gpop.remove_pipeline(&pipeline_id).await;
+                    let mut mapping = self.pipeline_to_job.write().await;
+                    mapping.remove(&pipeline_id);
+                }
+                self.mark_job_failed(
+                    &job_id,
+                    JobError::Timeout(format!(
+                        "no progress for {}ms, exceeding stall grace period",
+                        stalled_for_ms
+                    )),
+                )
+                .await;
+            } else if self.stalled_jobs.insert(job_id.clone(), ()).is_none() {
+                warn!("Job {} stalled for {}ms", job_id, stalled_for_ms);
+                self.broadcaster.send(ProgressMessage::JobStalled {
+                    job_id,
+                    stalled_for_ms,
+                });
+            }
+        }
+    }
+
+    /// Purge every job whose `keep_for` TTL has elapsed, deleting its files
+    /// along with the job record.
+    pub async fn reap_expired_jobs(&self) {
+        let expired: Vec<String> = {
+            let jobs = self.jobs.read().await;
+            jobs.values()
+                .filter(|j| matches!(j.expires_at, Some(at) if at <= Utc::now()))
+                .map(|j| j.id.clone())
+                .collect()
+        };
+
+        for job_id in expired {
+            match self.delete_job(&job_id).await {
+                Ok(()) => info!("Reaped expired job {}", job_id),
+                Err(e) => warn!("Failed to reap expired job {}: {}", job_id, e),
+            }
+        }
+    }
+
+    /// Sweep `storage.uploads_dir()`/`storage.outputs_dir()` for entries
+    /// older than `config.retention_hours`, exempting every job id still
+    /// tracked here regardless of status. This is the global disk-level
+    /// backstop for `Config::retention_hours`, distinct from
+    /// `reap_expired_jobs`'s per-job `keep_for` TTL: it catches orphaned
+    /// uploads/outputs whose job record is already gone instead of letting
+    /// the data directory grow without bound.
+    pub async fn sweep_retention(&self) {
+        let tracked: std::collections::HashSet<String> = {
+            let jobs = self.jobs.read().await;
+            jobs.keys().cloned().collect()
+        };
+
+        let stats =
+            crate::storage::sweep_retention(&self.storage, self.config.retention_hours, &tracked)
+                .await;
+        if stats.files_removed > 0 {
+            info!(
+                "Retention sweep removed {} entries ({} bytes) in {:?}",
+                stats.files_removed, stats.bytes_reclaimed, stats.scan_duration
+            );
+        }
+    }
+
+    /// Load every job persisted under `storage.jobs_dir()` back into memory
+    /// at startup.
+    ///
+    /// A job left in `Processing`/`Retrying` is reconciled against `gpop`
+    /// rather than assumed dead: if its old pipeline id still answers to
+    /// `get_position` (the daemon survived or outlived a web-process-only
+    /// restart), it's left running and just gets its progress-polling
+    /// mapping restored; otherwise its pipeline is rebuilt from scratch and
+    /// resubmitted. A `Completed` job has its output re-validated against
+    /// disk so a later dedup lookup can't hand out a job whose output was
+    /// cleaned up while the server was down.
+    pub async fn recover(self: &Arc<Self>) {
+        let jobs = persistence::load_all(self.storage.jobs_dir()).await;
+        if jobs.is_empty() {
+            return;
+        }
+
+        let mut reconcile = Vec::new();
+
+        {
+            let mut table = self.jobs.write().await;
+            for job in jobs {
+                if let Some(key) = &job.idempotency_key {
+                    self.register_idempotency_key(key.clone(), job.id.clone());
+                }
+                if job.status == JobStatus::Completed {
+                    if Self::job_output_present(&job) {
+                        if let Some(key) = &job.dedup_key {
+                            self.dedup_completed.insert(key.clone(), job.id.clone());
+                        }
+                    } else {
+                        warn!(
+                            "Recovered job {} is Completed but its output is missing, \
+                             not offering it for dedup reuse",
+                            job.id
+                        );
+                    }
+                }
+                if matches!(job.status, JobStatus::Processing | JobStatus::Retrying) {
+                    reconcile.push((job.id.clone(), job.pipeline_id.clone()));
+                }
+                table.insert(job.id.clone(), job);
+            }
+        }
+
+        info!("Recovered persisted jobs, reconciling {}", reconcile.len());
+
+        for (job_id, old_pipeline_id) in reconcile {
+            let still_running = match &old_pipeline_id {
+                Some(pipeline_id) => self.gpop.get_position(pipeline_id).await.is_ok(),
+                None => false,
+            };
+
+            if still_running {
+                let pipeline_id = old_pipeline_id.unwrap();
+                info!(
+                    "Recovered job {} is still running on gpop pipeline {}, resuming polling",
+                    job_id, pipeline_id
+                );
+                let mut mapping = self.pipeline_to_job.write().await;
+                mapping.insert(pipeline_id, job_id.clone());
+
+                let mut table = self.jobs.write().await;
+                if let Some(job) = table.get_mut(&job_id) {
+                    job.last_progress_at = Some(Utc::now());
                 }
+                continue;
+            }
+
+            let job = {
+                let mut table = self.jobs.write().await;
+                match table.get_mut(&job_id) {
+                    Some(job) => {
+                        job.status = JobStatus::Pending;
+                        job.pipeline_id = None;
+                        job.next_retry_at = None;
+                        job.clone()
+                    }
+                    None => continue,
+                }
+            };
+            self.persist_job(&job_id).await;
+
+            info!(
+                "Recovered job {}'s pipeline no longer exists on gpop, rebuilding and resubmitting",
+                job_id
+            );
+
+            let pipeline_desc = match &job.options {
+                JobOptions::Transcode(opts) => {
+                    build_transcode_pipeline(&job.input_path, &job.output_path, opts)
+                }
+                JobOptions::Demucs(opts) => {
+                    build_demucs_pipeline(&job.input_path, &job.output_path, opts)
+                }
+                JobOptions::Thumbnail(opts) => build_thumbnail_pipeline(
+                    &job.input_path,
+                    &job.output_path,
+                    opts,
+                    job.duration_ns.unwrap_or(0),
+                ),
+            };
+
+            if let Err(e) = self
+                .start_pipeline_when_permitted(job_id.clone(), pipeline_desc, job.job_type)
+                .await
+            {
+                error!("Failed to resume recovered job {}: {}", job_id, e);
             }
         }
     }
@@ -506,3 +1897,33 @@ pub async fn start_progress_poller(manager: Arc<JobManager>, interval: Duration)
         manager.poll_progress().await;
     }
 }
+
+/// Start the background reaper that purges jobs whose `keep_for` TTL has
+/// elapsed
+pub async fn start_reaper(manager: Arc<JobManager>, interval: Duration) {
+    let mut interval_timer = tokio::time::interval(interval);
+
+    loop {
+        interval_timer.tick().await;
+        manager.reap_expired_jobs().await;
+    }
+}
+
+/// Start the background sweeper that enforces `Config::retention_hours`
+/// against `uploads_dir`/`outputs_dir`, catching orphaned files that the
+/// per-job `keep_for` reaper above never owned. Stops cleanly between ticks
+/// once `manager.shutdown()` is called, rather than being killed mid-scan.
+pub async fn start_retention_sweeper(manager: Arc<JobManager>, interval: Duration) {
+    let mut interval_timer = tokio::time::interval(interval);
+    let mut shutdown = manager.shutdown_signal();
+
+    loop {
+        tokio::select! {
+            _ = interval_timer.tick() => manager.sweep_retention().await,
+            _ = shutdown.changed() => {
+                info!("Retention sweeper shutting down");
+                break;
+            }
+        }
+    }
+}
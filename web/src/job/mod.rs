@@ -1,6 +1,11 @@
 pub mod manager;
+pub mod persistence;
 pub mod pipeline;
+pub mod presets;
 pub mod types;
 
-pub use manager::{start_event_handler, start_progress_poller, JobManager};
+pub use manager::{
+    start_event_handler, start_progress_poller, start_reaper, start_retention_sweeper, JobManager,
+};
+pub use presets::{default_presets, TranscodeOverrides, TranscodePreset};
 pub use types::*;
@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use tokio::fs;
+use tracing::warn;
+
+use crate::error::{AppError, Result};
+
+use super::types::Job;
+
+/// Write `job`'s current state to `jobs_dir` as `<job_id>.json`, overwriting
+/// any previous snapshot. Called on every status transition so a crash never
+/// loses more than the most recent state change.
+pub async fn persist_job(jobs_dir: &Path, job: &Job) -> Result<()> {
+    fs::create_dir_all(jobs_dir)
+        .await
+        .map_err(|e| AppError::Storage(format!("Failed to create jobs dir: {}", e)))?;
+
+    let path = jobs_dir.join(format!("{}.json", job.id));
+    let json = serde_json::to_vec_pretty(job)
+        .map_err(|e| AppError::Storage(format!("Failed to serialize job {}: {}", job.id, e)))?;
+
+    fs::write(&path, json)
+        .await
+        .map_err(|e| AppError::Storage(format!("Failed to persist job {}: {}", job.id, e)))
+}
+
+/// Delete a job's persisted record, e.g. once it's been deleted from the
+/// in-memory table via `JobManager::delete_job`.
+pub async fn remove_job_record(jobs_dir: &Path, job_id: &str) -> Result<()> {
+    let path = jobs_dir.join(format!("{}.json", job_id));
+    if fs::metadata(&path).await.is_ok() {
+        fs::remove_file(&path)
+            .await
+            .map_err(|e| AppError::Storage(format!("Failed to remove job record {}: {}", job_id, e)))?;
+    }
+    Ok(())
+}
+
+/// Load every persisted job record under `jobs_dir`. A record that fails to
+/// parse (e.g. written by an older, incompatible version) is skipped rather
+/// than aborting startup - losing one job's history is better than the
+/// server refusing to boot.
+pub async fn load_all(jobs_dir: &Path) -> Vec<Job> {
+    let mut jobs = Vec::new();
+
+    let mut entries = match fs::read_dir(jobs_dir).await {
+        Ok(entries) => entries,
+        Err(_) => return jobs, // nothing persisted yet
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if entry.path().extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+
+        match fs::read(entry.path()).await {
+            Ok(bytes) => match serde_json::from_slice::<Job>(&bytes) {
+                Ok(job) => jobs.push(job),
+                Err(e) => warn!("Skipping corrupt job record {}: {}", entry.path().display(), e),
+            },
+            Err(e) => warn!("Failed to read job record {}: {}", entry.path().display(), e),
+        }
+    }
+
+    jobs
+}
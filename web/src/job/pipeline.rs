@@ -1,24 +1,111 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use super::types::{DemucsOptions, OutputFormat, TranscodeOptions};
+use crate::error::{AppError, Result};
 
-/// Build a GStreamer pipeline description for transcoding
+use super::types::{
+    AudioCodec, CaptionMode, DemucsOptions, EncoderBackend, HlsRung, ImageFormat, OutputFormat,
+    OutputTarget, ThumbnailOptions, ThumbnailSpriteCell, TranscodeOptions, VideoCodec,
+};
+use crate::media::TrackTags;
+
+/// Target duration of one HLS/DASH media segment. `splitmuxsink` cuts at the
+/// next keyframe at-or-after this, so actual segment durations (see
+/// `MediaSegment`) land close to but not exactly at this value.
+const HLS_SEGMENT_DURATION_SECS: f64 = 2.5;
+
+/// Build a GStreamer pipeline description for transcoding.
+///
+/// For `Hls`/`Dash`, `output_path` is a directory (one subdirectory of
+/// segments per rung) rather than a single file. The pipeline writes only
+/// the segments; the master playlist/manifest is written by
+/// `write_hls_playlists`/`write_dash_manifest`, which `JobManager` does not
+/// call.
 pub fn build_transcode_pipeline(
     input_path: &Path,
     output_path: &Path,
     options: &TranscodeOptions,
 ) -> String {
     let input = escape_path(input_path);
+
+    match &options.output_target {
+        OutputTarget::Srt { uri } => return build_srt_pipeline(&input, options, uri),
+        OutputTarget::Rtmp { location } => return build_rtmp_pipeline(&input, options, location),
+        OutputTarget::File => {}
+    }
+
     let output = escape_path(output_path);
+    let caption_sidecar = escape_path(&output_path.with_extension("vtt"));
 
     match options.output_format {
-        OutputFormat::Mp4 => build_mp4_pipeline(&input, &output, options),
-        OutputFormat::Webm => build_webm_pipeline(&input, &output, options),
-        OutputFormat::Mkv => build_mkv_pipeline(&input, &output, options),
+        OutputFormat::Mp4 => build_mp4_pipeline(&input, &output, options, &caption_sidecar),
+        OutputFormat::Webm => build_webm_pipeline(&input, &output, options, &caption_sidecar),
+        OutputFormat::Mkv => build_mkv_pipeline(&input, &output, options, &caption_sidecar),
         OutputFormat::Mp3 => build_mp3_pipeline(&input, &output, options),
         OutputFormat::Ogg => build_ogg_pipeline(&input, &output, options),
         OutputFormat::Flac => build_flac_pipeline(&input, &output, options),
+        OutputFormat::Hls => build_hls_pipeline(input_path, output_path, options),
+        OutputFormat::Dash => build_dash_pipeline(input_path, output_path, options),
+    }
+}
+
+/// Write `tags` into a finished transcode output, using whichever tagging
+/// scheme the container expects (ID3v2 for MP3, Vorbis comments for
+/// Ogg/FLAC, iTunes-style atoms for MP4). Runs after the gst pipeline has
+/// already closed the file, same as `assemble_thumbnail_sprite` - tagging
+/// isn't expressible as a pipeline element the way encoding is, so it's a
+/// plain post-processing pass over the output file with the `lofty` crate,
+/// which abstracts the per-container tag format behind one API.
+///
+/// A no-op for Webm/Mkv (not requested) and for an empty `tags`.
+pub fn write_tags(output_path: &Path, format: OutputFormat, tags: &TrackTags) -> Result<()> {
+    if tags.is_empty() || !matches!(format, OutputFormat::Mp3 | OutputFormat::Ogg | OutputFormat::Flac | OutputFormat::Mp4) {
+        return Ok(());
+    }
+
+    let mut tagged_file = lofty::probe::Probe::open(output_path)
+        .and_then(|probe| probe.read())
+        .map_err(|e| {
+            AppError::Tagging(format!("failed to open {} for tagging: {}", output_path.display(), e))
+        })?;
+
+    let tag_type = tagged_file.primary_tag_type();
+    if tagged_file.primary_tag().is_none() {
+        tagged_file.insert_tag(lofty::tag::Tag::new(tag_type));
+    }
+    let tag = tagged_file.primary_tag_mut().expect("tag inserted above");
+
+    use lofty::tag::Accessor;
+    if let Some(title) = &tags.title {
+        tag.set_title(title.clone());
+    }
+    if let Some(artist) = &tags.artist {
+        tag.set_artist(artist.clone());
     }
+    if let Some(album) = &tags.album {
+        tag.set_album(album.clone());
+    }
+    if let Some(track) = tags.track {
+        tag.set_track(track);
+    }
+    if let Some(year) = tags.year {
+        tag.set_year(year as u32);
+    }
+    if let Some(cover_art) = &tags.cover_art {
+        tag.push_picture(lofty::picture::Picture::new_unchecked(
+            lofty::picture::PictureType::CoverFront,
+            Some(lofty::picture::MimeType::Jpeg),
+            None,
+            cover_art.clone(),
+        ));
+    }
+
+    tagged_file
+        .save_to_path(output_path, lofty::config::WriteOptions::default())
+        .map_err(|e| {
+            AppError::Tagging(format!("failed to write tags to {}: {}", output_path.display(), e))
+        })?;
+
+    Ok(())
 }
 
 /// Build a GStreamer pipeline description for demucs source separation
@@ -122,6 +209,353 @@ pub fn get_demucs_output_files(
         .collect()
 }
 
+/// One variant (bitrate/resolution rung) of an HLS/DASH adaptive output, as
+/// referenced from the master playlist/manifest.
+#[derive(Debug, Clone)]
+pub struct VariantStream {
+    pub name: String,
+    pub bitrate_bps: u32,
+    pub width: u32,
+    pub height: u32,
+    pub playlist_path: PathBuf,
+}
+
+/// One fMP4 segment of a single rung's media playlist, with the duration
+/// GStreamer actually wrote it at - `splitmuxsink` cuts at the next keyframe
+/// at-or-after `HLS_SEGMENT_DURATION_SECS`, so segments can run a little
+/// short or long of the target.
+#[derive(Debug, Clone)]
+pub struct MediaSegment {
+    pub path: PathBuf,
+    pub duration_secs: f64,
+}
+
+/// Build the shared tee-fan-out portion of an HLS/DASH pipeline: decode once,
+/// then push the video through `videotee` and the encoded audio through
+/// `audiotee` so each rung's branch can tap off both.
+fn build_adaptive_decode_stage(input: &str, options: &TranscodeOptions) -> String {
+    let audio_codec = options.resolved_audio_codec().unwrap_or(AudioCodec::Aac);
+    let audio_enc = build_audio_encoder(audio_codec, options);
+
+    format!(
+        "filesrc location=\"{input}\" ! decodebin name=dec \
+         dec. ! queue ! videoconvert ! tee name=videotee \
+         dec. ! queue ! audioconvert ! audioresample ! {audio_enc} ! tee name=audiotee"
+    )
+}
+
+/// Append one rung's encode-and-segment branch to an adaptive pipeline:
+/// `videotee`/`audiotee` (from `build_adaptive_decode_stage`) feed a
+/// `videoscale ! x264enc` branch into a `splitmuxsink` using the `cmafmux`
+/// muxer, segmenting fMP4 fragments to `output_dir/<rung>/segment_%05d.m4s`.
+fn push_adaptive_rung_branch(pipeline: &mut String, output_dir: &Path, rung: &HlsRung) {
+    let rung_dir = escape_path(&output_dir.join(rung.name()));
+    let sink_name = format!("mux_{}", rung.name());
+    let segment_time_ns = (HLS_SEGMENT_DURATION_SECS * 1_000_000_000.0) as u64;
+
+    pipeline.push_str(&format!(
+        " splitmuxsink name={sink_name} muxer-factory=cmafmux \
+         location=\"{rung_dir}/segment_%05d.m4s\" max-size-time={segment_time_ns} \
+         videotee. ! queue ! videoscale ! video/x-raw,width={width},height={height} ! \
+         x264enc bitrate={bitrate} ! h264parse ! {sink_name}. \
+         audiotee. ! queue ! {sink_name}.",
+        width = rung.width,
+        height = rung.height,
+        bitrate = rung.bitrate_kbps,
+    ));
+}
+
+/// Build a GStreamer pipeline description for adaptive-bitrate HLS output:
+/// decode once, fan the video out through `tee` into one `videoscale !
+/// x264enc` branch per `TranscodeOptions::hls_rungs` rung, and segment each
+/// branch into ~2.5s fMP4 fragments. The `.m3u8` playlists referencing those
+/// segments aren't written by the pipeline itself - call
+/// `write_hls_playlists` once it reaches EOS.
+pub fn build_hls_pipeline(input_path: &Path, output_dir: &Path, options: &TranscodeOptions) -> String {
+    let input = escape_path(input_path);
+    let mut pipeline = build_adaptive_decode_stage(&input, options);
+
+    for rung in &options.resolved_hls_rungs() {
+        push_adaptive_rung_branch(&mut pipeline, output_dir, rung);
+    }
+
+    pipeline
+}
+
+/// Build a GStreamer pipeline description for adaptive-bitrate DASH output.
+/// Same tee/rung/segmenting layout as `build_hls_pipeline` - DASH's CMAF
+/// segments are the same fMP4 fragments HLS uses, only the manifest format
+/// written by `write_dash_manifest` differs.
+pub fn build_dash_pipeline(input_path: &Path, output_dir: &Path, options: &TranscodeOptions) -> String {
+    build_hls_pipeline(input_path, output_dir, options)
+}
+
+/// Write the master and per-rung media `.m3u8` playlists for a finished HLS
+/// output, given each rung's measured segment durations. Mirrors
+/// `assemble_thumbnail_sprite` - `splitmuxsink` only writes the segment
+/// files, not the playlists describing them, so this runs as a plain
+/// post-processing pass once the pipeline reaches EOS.
+pub fn write_hls_playlists(
+    output_dir: &Path,
+    rungs: &[HlsRung],
+    segments_by_rung: &[Vec<MediaSegment>],
+) -> Result<(PathBuf, Vec<VariantStream>)> {
+    let mut variants = Vec::with_capacity(rungs.len());
+
+    for (rung, segments) in rungs.iter().zip(segments_by_rung) {
+        let rung_dir = output_dir.join(rung.name());
+        let playlist_path = rung_dir.join("playlist.m3u8");
+
+        let target_duration = segments
+            .iter()
+            .map(|s| s.duration_secs.ceil() as u64)
+            .max()
+            .unwrap_or(HLS_SEGMENT_DURATION_SECS.ceil() as u64);
+
+        let mut playlist = format!(
+            "#EXTM3U\n#EXT-X-VERSION:7\n#EXT-X-TARGETDURATION:{target_duration}\n#EXT-X-PLAYLIST-TYPE:VOD\n"
+        );
+        for segment in segments {
+            let file_name = segment.path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            playlist.push_str(&format!("#EXTINF:{:.3},\n{}\n", segment.duration_secs, file_name));
+        }
+        playlist.push_str("#EXT-X-ENDLIST\n");
+
+        std::fs::write(&playlist_path, playlist).map_err(|e| {
+            AppError::Internal(format!("failed to write HLS media playlist {}: {}", playlist_path.display(), e))
+        })?;
+
+        variants.push(VariantStream {
+            name: rung.name(),
+            bitrate_bps: rung.bitrate_kbps * 1000,
+            width: rung.width,
+            height: rung.height,
+            playlist_path,
+        });
+    }
+
+    let master_path = output_dir.join("master.m3u8");
+    let mut master_playlist = "#EXTM3U\n#EXT-X-VERSION:7\n".to_string();
+    for variant in &variants {
+        master_playlist.push_str(&format!(
+            "#EXT-X-STREAM-INF:BANDWIDTH={},RESOLUTION={}x{}\n{}/playlist.m3u8\n",
+            variant.bitrate_bps, variant.width, variant.height, variant.name
+        ));
+    }
+    std::fs::write(&master_path, master_playlist)
+        .map_err(|e| AppError::Internal(format!("failed to write HLS master playlist {}: {}", master_path.display(), e)))?;
+
+    Ok((master_path, variants))
+}
+
+/// Write the `.mpd` manifest for a finished DASH output, given each rung's
+/// measured segment durations. Same rationale as `write_hls_playlists` - the
+/// manifest is plain post-processing over the pipeline's segment output.
+pub fn write_dash_manifest(
+    output_dir: &Path,
+    rungs: &[HlsRung],
+    segments_by_rung: &[Vec<MediaSegment>],
+) -> Result<(PathBuf, Vec<VariantStream>)> {
+    let total_duration_secs: f64 = segments_by_rung
+        .first()
+        .map(|segments| segments.iter().map(|s| s.duration_secs).sum())
+        .unwrap_or(0.0);
+
+    let mut variants = Vec::with_capacity(rungs.len());
+    let mut representations = String::new();
+    for (rung, _segments) in rungs.iter().zip(segments_by_rung) {
+        let rung_dir = rung.name();
+        representations.push_str(&format!(
+            "      <Representation id=\"{name}\" bandwidth=\"{bitrate_bps}\" width=\"{width}\" height=\"{height}\">\n\
+             \x20\x20\x20\x20\x20\x20\x20<SegmentTemplate media=\"{name}/segment_$Number%05d$.m4s\" \
+             startNumber=\"0\" duration=\"{duration}\" timescale=\"1\"/>\n\
+             \x20\x20\x20\x20\x20\x20</Representation>\n",
+            name = rung_dir,
+            bitrate_bps = rung.bitrate_kbps * 1000,
+            width = rung.width,
+            height = rung.height,
+            duration = HLS_SEGMENT_DURATION_SECS,
+        ));
+
+        variants.push(VariantStream {
+            name: rung.name(),
+            bitrate_bps: rung.bitrate_kbps * 1000,
+            width: rung.width,
+            height: rung.height,
+            // DASH references segments directly from the manifest's
+            // `SegmentTemplate`, so there's no per-rung playlist file - point
+            // at the rung's segment directory instead.
+            playlist_path: output_dir.join(rung.name()),
+        });
+    }
+
+    let manifest = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <MPD xmlns=\"urn:mpeg:dash:schema:mpd:2011\" profiles=\"urn:mpeg:dash:profile:isoff-on-demand:2011\" \
+         type=\"static\" mediaPresentationDuration=\"PT{duration}S\">\n\
+         \x20\x20<Period>\n\
+         \x20\x20\x20\x20<AdaptationSet mimeType=\"video/mp4\" segmentAlignment=\"true\">\n\
+         {representations}\
+         \x20\x20\x20\x20</AdaptationSet>\n\
+         \x20\x20</Period>\n\
+         </MPD>\n",
+        duration = total_duration_secs,
+    );
+
+    let manifest_path = output_dir.join("manifest.mpd");
+    std::fs::write(&manifest_path, manifest)
+        .map_err(|e| AppError::Internal(format!("failed to write DASH manifest {}: {}", manifest_path.display(), e)))?;
+
+    Ok((manifest_path, variants))
+}
+
+/// Expected output paths for an `Hls`/`Dash` job: the master playlist (or
+/// manifest), plus each rung's media playlist and the segment files it
+/// should contain, numbered to match `splitmuxsink`'s `%05d` pattern.
+/// Segment count is estimated from `duration_ns` - mirrors
+/// `build_thumbnail_pipeline`'s framerate estimate, since GStreamer doesn't
+/// expose the segmenter's actual keyframe-boundary decisions up front.
+pub fn get_hls_output_files(
+    output_dir: &Path,
+    options: &TranscodeOptions,
+    duration_ns: u64,
+) -> (PathBuf, Vec<(String, PathBuf, Vec<PathBuf>)>) {
+    let rungs = options.resolved_hls_rungs();
+    let duration_secs = duration_ns as f64 / 1_000_000_000.0;
+    let segment_count = (duration_secs / HLS_SEGMENT_DURATION_SECS).ceil().max(1.0) as u32;
+
+    let master_name = if options.output_format == OutputFormat::Dash {
+        "manifest.mpd"
+    } else {
+        "master.m3u8"
+    };
+
+    let variants = rungs
+        .iter()
+        .map(|rung| {
+            let rung_dir = output_dir.join(rung.name());
+            let playlist_path = rung_dir.join("playlist.m3u8");
+            let segments = (0..segment_count)
+                .map(|i| rung_dir.join(format!("segment_{:05}.m4s", i)))
+                .collect();
+            (rung.name(), playlist_path, segments)
+        })
+        .collect();
+
+    (output_dir.join(master_name), variants)
+}
+
+/// Build a GStreamer pipeline description that extracts `options.count`
+/// frames from `input_path`, evenly spaced across `duration_ns`, and writes
+/// each to `output_dir` as `frame_<i>.<ext>`.
+///
+/// A single gst-launch string can't seek to a precise per-frame timestamp
+/// the way `ThumbnailOptions::timestamps_ns` computes them, so instead we
+/// decimate the decoded stream to a constant low framerate chosen to land
+/// close to those evenly-spaced points: `count` frames over `duration_ns`.
+pub fn build_thumbnail_pipeline(
+    input_path: &Path,
+    output_dir: &Path,
+    options: &ThumbnailOptions,
+    duration_ns: u64,
+) -> String {
+    let input = escape_path(input_path);
+    let output_dir_str = escape_path(output_dir);
+    let ext = options.format.extension();
+    let encoder = thumbnail_encoder(options.format);
+
+    // videorate takes a fractional framerate as num/denom; scale by 1e6 so
+    // sub-1fps sampling (long inputs, few frames) keeps enough precision.
+    let duration_secs = (duration_ns as f64 / 1_000_000_000.0).max(1.0);
+    let fps_micro = ((options.count as f64 / duration_secs) * 1_000_000.0).round() as u64;
+
+    format!(
+        "filesrc location=\"{input}\" ! decodebin ! videoconvert ! videoscale ! \
+         video/x-raw,width={width} ! videorate ! video/x-raw,framerate={fps_micro}/1000000 ! \
+         {encoder} ! multifilesink location=\"{output_dir_str}/frame_%d.{ext}\" max-files={count}",
+        width = options.width,
+        count = options.count,
+    )
+}
+
+fn thumbnail_encoder(format: ImageFormat) -> &'static str {
+    match format {
+        ImageFormat::Webp => "webpenc",
+        ImageFormat::Jpeg => "jpegenc",
+        ImageFormat::Png => "pngenc",
+    }
+}
+
+/// Expected per-frame output files for a thumbnail job, numbered to match
+/// `multifilesink`'s `%d` pattern (`frame_0`, `frame_1`, ...).
+pub fn get_thumbnail_frame_files(
+    output_dir: &Path,
+    options: &ThumbnailOptions,
+) -> Vec<(String, PathBuf)> {
+    let ext = options.format.extension();
+    (0..options.count)
+        .map(|i| {
+            let name = format!("frame_{}", i);
+            let path = output_dir.join(format!("{}.{}", name, ext));
+            (name, path)
+        })
+        .collect()
+}
+
+/// Tile the frames a `sprite: true` thumbnail job produced into a single
+/// grid image (`ThumbnailOptions::grid_columns` columns) plus a JSON index
+/// mapping each cell's rect back to the timestamp it was sampled at, so
+/// players can resolve a hover-scrub preview with one image fetch instead of
+/// one per frame. Runs after the pipeline's individual frame files already
+/// exist on disk, so it's plain image composition rather than a gst element.
+pub fn assemble_thumbnail_sprite(
+    frames: &[(PathBuf, u64)],
+    options: &ThumbnailOptions,
+    output_dir: &Path,
+) -> Result<(PathBuf, PathBuf)> {
+    let columns = options.grid_columns().max(1);
+    let rows = (frames.len() as u32 + columns - 1) / columns;
+
+    let mut decoded = Vec::with_capacity(frames.len());
+    let (mut cell_width, mut cell_height) = (0u32, 0u32);
+    for (path, _) in frames {
+        let img = image::open(path).map_err(|e| {
+            AppError::Internal(format!("failed to read thumbnail frame {}: {}", path.display(), e))
+        })?;
+        cell_width = cell_width.max(img.width());
+        cell_height = cell_height.max(img.height());
+        decoded.push(img);
+    }
+
+    let mut grid = image::RgbaImage::new(cell_width * columns, cell_height * rows);
+    let mut cells = Vec::with_capacity(frames.len());
+
+    for (i, (img, (_, timestamp_ns))) in decoded.iter().zip(frames.iter()).enumerate() {
+        let i = i as u32;
+        let (x, y) = ((i % columns) * cell_width, (i / columns) * cell_height);
+        image::imageops::overlay(&mut grid, &img.to_rgba8(), x.into(), y.into());
+        cells.push(ThumbnailSpriteCell {
+            index: i,
+            x,
+            y,
+            width: cell_width,
+            height: cell_height,
+            timestamp_ns: *timestamp_ns,
+        });
+    }
+
+    let sprite_path = output_dir.join(format!("sprite.{}", options.format.extension()));
+    grid.save(&sprite_path)
+        .map_err(|e| AppError::Internal(format!("failed to write sprite image: {}", e)))?;
+
+    let index_path = output_dir.join("index.json");
+    let index_json = serde_json::to_vec_pretty(&cells).map_err(|e| AppError::Internal(e.to_string()))?;
+    std::fs::write(&index_path, index_json)
+        .map_err(|e| AppError::Internal(format!("failed to write sprite index: {}", e)))?;
+
+    Ok((sprite_path, index_path))
+}
+
 fn escape_path(path: &Path) -> String {
     // Escape special characters for GStreamer pipeline strings
     path.to_string_lossy()
@@ -129,89 +563,246 @@ fn escape_path(path: &Path) -> String {
         .replace('"', "\\\"")
 }
 
-fn build_mp4_pipeline(input: &str, output: &str, options: &TranscodeOptions) -> String {
-    let video_enc = build_x264_encoder(options);
-    let audio_enc = build_aac_encoder(options);
+fn build_mp4_pipeline(input: &str, output: &str, options: &TranscodeOptions, caption_sidecar: &str) -> String {
+    let video_codec = options.resolved_video_codec().unwrap_or(VideoCodec::H264);
+    let audio_codec = options.resolved_audio_codec().unwrap_or(AudioCodec::Aac);
+    let video_enc = build_video_encoder(video_codec, options);
+    let video_parse = video_parser(video_codec);
+    let audio_enc = build_audio_encoder(audio_codec, options);
+    let video_branch = build_video_branch(options, &video_enc, Some(video_parse));
+    let caption_branch = build_caption_extra_branch(options, caption_sidecar);
 
     format!(
         "filesrc location=\"{input}\" ! decodebin name=dec \
-         dec. ! queue ! videoconvert ! {video_enc} ! h264parse ! queue ! mux. \
+         {video_branch} \
          dec. ! queue ! audioconvert ! audioresample ! {audio_enc} ! queue ! mux. \
-         mp4mux name=mux ! filesink location=\"{output}\""
+         mp4mux name=mux ! filesink location=\"{output}\"{caption_branch}"
     )
 }
 
-fn build_webm_pipeline(input: &str, output: &str, options: &TranscodeOptions) -> String {
-    let video_bitrate = options.video_bitrate_kbps.unwrap_or(2000);
-    let audio_bitrate = options.audio_bitrate_kbps.unwrap_or(128);
+fn build_webm_pipeline(input: &str, output: &str, options: &TranscodeOptions, caption_sidecar: &str) -> String {
+    let video_codec = options.resolved_video_codec().unwrap_or(VideoCodec::Vp9);
+    let audio_codec = options.resolved_audio_codec().unwrap_or(AudioCodec::Vorbis);
+    let video_enc = build_video_encoder(video_codec, options);
+    let audio_enc = build_audio_encoder(audio_codec, options);
+    let video_branch = build_video_branch(options, &video_enc, None);
+    let caption_branch = build_caption_extra_branch(options, caption_sidecar);
 
     format!(
         "filesrc location=\"{input}\" ! decodebin name=dec \
-         dec. ! queue ! videoconvert ! vp8enc target-bitrate={video_bps} deadline=1 ! queue ! mux. \
-         dec. ! queue ! audioconvert ! audioresample ! vorbisenc bitrate={audio_bps} ! queue ! mux. \
-         webmmux name=mux ! filesink location=\"{output}\"",
-        video_bps = video_bitrate * 1000,
-        audio_bps = audio_bitrate * 1000
+         {video_branch} \
+         dec. ! queue ! audioconvert ! audioresample ! {audio_enc} ! queue ! mux. \
+         webmmux name=mux ! filesink location=\"{output}\"{caption_branch}"
     )
 }
 
-fn build_mkv_pipeline(input: &str, output: &str, options: &TranscodeOptions) -> String {
-    let video_enc = build_x264_encoder(options);
-    let audio_enc = build_aac_encoder(options);
+fn build_mkv_pipeline(input: &str, output: &str, options: &TranscodeOptions, caption_sidecar: &str) -> String {
+    let video_codec = options.resolved_video_codec().unwrap_or(VideoCodec::H264);
+    let audio_codec = options.resolved_audio_codec().unwrap_or(AudioCodec::Aac);
+    let video_enc = build_video_encoder(video_codec, options);
+    let video_parse = video_parser(video_codec);
+    let audio_enc = build_audio_encoder(audio_codec, options);
+    let video_branch = build_video_branch(options, &video_enc, Some(video_parse));
+    let caption_branch = build_caption_extra_branch(options, caption_sidecar);
 
     format!(
         "filesrc location=\"{input}\" ! decodebin name=dec \
-         dec. ! queue ! videoconvert ! {video_enc} ! h264parse ! queue ! mux. \
+         {video_branch} \
          dec. ! queue ! audioconvert ! audioresample ! {audio_enc} ! queue ! mux. \
-         matroskamux name=mux ! filesink location=\"{output}\""
+         matroskamux name=mux ! filesink location=\"{output}\"{caption_branch}"
     )
 }
 
+/// Build a live MPEG-TS-over-SRT pipeline for `OutputTarget::Srt`. Always
+/// encodes H.264/AAC, the only codec pair SRT's conventional MPEG-TS
+/// payload supports here, regardless of `options.output_format` - see
+/// `TranscodeOptions::validate_output_target`.
+fn build_srt_pipeline(input: &str, options: &TranscodeOptions, uri: &str) -> String {
+    let video_enc = build_video_encoder(VideoCodec::H264, options);
+    let video_parse = video_parser(VideoCodec::H264);
+    let audio_enc = build_audio_encoder(AudioCodec::Aac, options);
+    let video_branch = build_video_branch(options, &video_enc, Some(video_parse));
+
+    format!(
+        "filesrc location=\"{input}\" ! decodebin name=dec \
+         {video_branch} \
+         dec. ! queue ! audioconvert ! audioresample ! {audio_enc} ! queue ! mux. \
+         mpegtsmux name=mux ! srtsink uri=\"{uri}\""
+    )
+}
+
+/// Build a live FLV-over-RTMP pipeline for `OutputTarget::Rtmp`. Always
+/// encodes H.264/AAC, the only codec pair FLV/RTMP support, regardless of
+/// `options.output_format` - see `TranscodeOptions::validate_output_target`.
+fn build_rtmp_pipeline(input: &str, options: &TranscodeOptions, location: &str) -> String {
+    let video_enc = build_video_encoder(VideoCodec::H264, options);
+    let video_parse = video_parser(VideoCodec::H264);
+    let audio_enc = build_audio_encoder(AudioCodec::Aac, options);
+    let video_branch = build_video_branch(options, &video_enc, Some(video_parse));
+
+    format!(
+        "filesrc location=\"{input}\" ! decodebin name=dec \
+         {video_branch} \
+         dec. ! queue ! audioconvert ! audioresample ! {audio_enc} ! queue ! mux. \
+         flvmux name=mux streamable=true ! rtmpsink location=\"{location}\""
+    )
+}
+
+/// Video decode/encode branch feeding the named `mux.`, inserting a
+/// caption-burn-in stage ahead of the encoder when `options.captions ==
+/// Some(CaptionMode::Burn)`. `video_parse` is `None` for containers (WebM)
+/// whose encoders don't need a bitstream parser ahead of the muxer.
+///
+/// Burn-in decodes the source's CEA-608/708 captions to text
+/// (`ccextractor ! cea608tott`) and feeds it into `textoverlay`'s
+/// `text_sink` pad alongside the decoded video, so the rendered captions are
+/// baked into the encoded pixels.
+fn build_video_branch(options: &TranscodeOptions, video_enc: &str, video_parse: Option<&str>) -> String {
+    let parse = video_parse.map(|p| format!("{} ! ", p)).unwrap_or_default();
+
+    if options.captions == Some(CaptionMode::Burn) {
+        format!(
+            "dec. ! queue ! videoconvert ! textoverlay name=cc_overlay ! {video_enc} ! {parse}queue ! mux. \
+             dec. ! queue ! ccextractor ! cea608tott ! queue ! cc_overlay.text_sink"
+        )
+    } else {
+        format!("dec. ! queue ! videoconvert ! {video_enc} ! {parse}queue ! mux.")
+    }
+}
+
+/// Extra caption-handling clause for an mp4/webm/mkv pipeline: forwards
+/// CEA-608/708 caption data into the muxer for `Passthrough`, or extracts it
+/// to a standalone WebVTT sidecar file for `Sidecar`. A no-op for `Burn`
+/// (handled inline by `build_video_branch`) and when no caption mode is set.
+fn build_caption_extra_branch(options: &TranscodeOptions, caption_sidecar: &str) -> String {
+    match options.captions {
+        Some(CaptionMode::Passthrough) => " dec. ! queue ! ccextractor ! queue ! mux.".to_string(),
+        Some(CaptionMode::Sidecar) => format!(
+            " dec. ! queue ! ccextractor ! cea608tott ! queue ! webvttenc ! filesink location=\"{caption_sidecar}\""
+        ),
+        Some(CaptionMode::Burn) | None => String::new(),
+    }
+}
+
 fn build_mp3_pipeline(input: &str, output: &str, options: &TranscodeOptions) -> String {
-    let bitrate = options.audio_bitrate_kbps.unwrap_or(192);
+    let audio_enc = build_audio_encoder(AudioCodec::Mp3, options);
 
     format!(
         "filesrc location=\"{input}\" ! decodebin ! audioconvert ! audioresample ! \
-         lamemp3enc target=bitrate bitrate={bitrate} ! \
+         {audio_enc} ! \
          id3v2mux ! filesink location=\"{output}\""
     )
 }
 
 fn build_ogg_pipeline(input: &str, output: &str, options: &TranscodeOptions) -> String {
-    let bitrate = options.audio_bitrate_kbps.unwrap_or(128);
+    let audio_codec = options.resolved_audio_codec().unwrap_or(AudioCodec::Vorbis);
+    let audio_enc = build_audio_encoder(audio_codec, options);
 
     format!(
         "filesrc location=\"{input}\" ! decodebin ! audioconvert ! audioresample ! \
-         vorbisenc bitrate={bitrate_bps} ! \
-         oggmux ! filesink location=\"{output}\"",
-        bitrate_bps = bitrate * 1000
+         {audio_enc} ! \
+         oggmux ! filesink location=\"{output}\""
     )
 }
 
-fn build_flac_pipeline(input: &str, output: &str, _options: &TranscodeOptions) -> String {
+fn build_flac_pipeline(input: &str, output: &str, options: &TranscodeOptions) -> String {
+    let audio_enc = build_audio_encoder(AudioCodec::Flac, options);
+
     format!(
         "filesrc location=\"{input}\" ! decodebin ! audioconvert ! audioresample ! \
-         flacenc ! filesink location=\"{output}\""
+         {audio_enc} ! filesink location=\"{output}\""
     )
 }
 
-fn build_x264_encoder(options: &TranscodeOptions) -> String {
-    let mut parts = vec!["x264enc tune=zerolatency".to_string()];
+/// Named hardware-accelerated element for `codec` under `backend`, or `None`
+/// if that combination isn't one of the families gpop knows how to drive -
+/// callers fall back to the software encoder in that case. Always `None` for
+/// `EncoderBackend::Software`.
+fn hardware_encoder_element(codec: VideoCodec, backend: EncoderBackend) -> Option<&'static str> {
+    match (codec, backend) {
+        (VideoCodec::H264, EncoderBackend::Vaapi) => Some("vaapih264enc"),
+        (VideoCodec::H264, EncoderBackend::Nvenc) => Some("nvh264enc"),
+        (VideoCodec::H264, EncoderBackend::Qsv) => Some("qsvh264enc"),
+        (VideoCodec::H265, EncoderBackend::Vaapi) => Some("vaapih265enc"),
+        (VideoCodec::H265, EncoderBackend::Nvenc) => Some("nvh265enc"),
+        (VideoCodec::H265, EncoderBackend::Qsv) => Some("qsvh265enc"),
+        (VideoCodec::Av1, EncoderBackend::Nvenc) => Some("nvav1enc"),
+        (VideoCodec::Av1, EncoderBackend::Qsv) => Some("qsvav1enc"),
+        (VideoCodec::Vp9, EncoderBackend::Vaapi) => Some("vaapivp9enc"),
+        _ => None,
+    }
+}
+
+/// Build the video encoder element (plus any scaling stage) for `codec`,
+/// honoring `options.encoder`'s backend choice and falling back to the
+/// software encoder when that backend doesn't support `codec`.
+fn build_video_encoder(codec: VideoCodec, options: &TranscodeOptions) -> String {
+    let scale = build_video_scale(options);
+    let hardware = hardware_encoder_element(codec, options.encoder.backend);
+
+    let mut parts = match hardware {
+        Some(element) => vec![element.to_string()],
+        None => match codec {
+            VideoCodec::H264 => vec!["x264enc tune=zerolatency".to_string()],
+            VideoCodec::H265 => vec!["x265enc".to_string()],
+            VideoCodec::Av1 => vec!["av1enc".to_string()],
+            VideoCodec::Vp9 => vec!["vp9enc".to_string(), "deadline=1".to_string()],
+        },
+    };
 
     if let Some(bitrate) = options.video_bitrate_kbps {
-        parts.push(format!("bitrate={}", bitrate));
+        match (codec, hardware) {
+            (VideoCodec::Av1, None) => parts.push(format!("target-bitrate={}", bitrate)),
+            (VideoCodec::Vp9, None) => parts.push(format!("target-bitrate={}", bitrate * 1000)),
+            _ => parts.push(format!("bitrate={}", bitrate)),
+        }
     }
 
-    // Add video scaling if dimensions specified
-    let scale = build_video_scale(options);
+    for (key, value) in &options.encoder.properties {
+        parts.push(format!("{}={}", key, value));
+    }
 
     format!("{}{}", scale, parts.join(" "))
 }
 
-fn build_aac_encoder(options: &TranscodeOptions) -> String {
-    let bitrate = options.audio_bitrate_kbps.unwrap_or(128);
-    // Use fdkaacenc if available, fallback to avenc_aac
-    format!("fdkaacenc bitrate={}", bitrate * 1000)
+/// Parser element required between an encoder and certain muxers (MP4/MKV
+/// need a bitstream parser ahead of them; WebM's encoders don't).
+fn video_parser(codec: VideoCodec) -> &'static str {
+    match codec {
+        VideoCodec::H264 => "h264parse",
+        VideoCodec::H265 => "h265parse",
+        VideoCodec::Av1 => "av1parse",
+        VideoCodec::Vp9 => "identity",
+    }
+}
+
+fn build_audio_encoder(codec: AudioCodec, options: &TranscodeOptions) -> String {
+    let mut encoder = match codec {
+        AudioCodec::Aac => {
+            let bitrate = options.audio_bitrate_kbps.unwrap_or(128);
+            // Always fdkaacenc; there is no fallback to avenc_aac
+            format!("fdkaacenc bitrate={}", bitrate * 1000)
+        }
+        AudioCodec::Opus => {
+            let bitrate = options.audio_bitrate_kbps.unwrap_or(128);
+            format!("opusenc bitrate={}", bitrate * 1000)
+        }
+        AudioCodec::Vorbis => {
+            let bitrate = options.audio_bitrate_kbps.unwrap_or(128);
+            format!("vorbisenc bitrate={}", bitrate * 1000)
+        }
+        AudioCodec::Mp3 => {
+            let bitrate = options.audio_bitrate_kbps.unwrap_or(192);
+            format!("lamemp3enc target=bitrate bitrate={}", bitrate)
+        }
+        AudioCodec::Flac => "flacenc".to_string(),
+    };
+
+    for (key, value) in &options.encoder.properties {
+        encoder.push_str(&format!(" {}={}", key, value));
+    }
+
+    encoder
 }
 
 fn build_video_scale(options: &TranscodeOptions) -> String {
@@ -313,4 +904,230 @@ mod tests {
         assert_eq!(files[0].0, "vocals");
         assert_eq!(files[0].1, PathBuf::from("/tmp/output/vocals.wav"));
     }
+
+    #[test]
+    fn test_thumbnail_pipeline() {
+        let input = PathBuf::from("/tmp/input.mp4");
+        let output_dir = PathBuf::from("/tmp/output");
+        let options = ThumbnailOptions {
+            count: 4,
+            width: 160,
+            ..Default::default()
+        };
+
+        let pipeline = build_thumbnail_pipeline(&input, &output_dir, &options, 60_000_000_000);
+
+        assert!(pipeline.contains("decodebin"));
+        assert!(pipeline.contains("width=160"));
+        assert!(pipeline.contains("webpenc"));
+        assert!(pipeline.contains("frame_%d.webp"));
+        assert!(pipeline.contains("max-files=4"));
+    }
+
+    #[test]
+    fn test_video_encoder_hardware_backend() {
+        let options = TranscodeOptions {
+            video_codec: Some(VideoCodec::H264),
+            video_bitrate_kbps: Some(4000),
+            encoder: super::super::types::EncoderConfig {
+                backend: super::super::types::EncoderBackend::Vaapi,
+                properties: vec![],
+            },
+            ..Default::default()
+        };
+
+        let pipeline = build_transcode_pipeline(
+            &PathBuf::from("/tmp/input.mkv"),
+            &PathBuf::from("/tmp/output.mp4"),
+            &options,
+        );
+
+        assert!(pipeline.contains("vaapih264enc"));
+        assert!(pipeline.contains("bitrate=4000"));
+        assert!(!pipeline.contains("x264enc"));
+    }
+
+    #[test]
+    fn test_video_encoder_falls_back_to_software_for_unsupported_combo() {
+        let options = TranscodeOptions {
+            video_codec: Some(VideoCodec::Av1),
+            encoder: super::super::types::EncoderConfig {
+                backend: super::super::types::EncoderBackend::Vaapi,
+                properties: vec![],
+            },
+            ..Default::default()
+        };
+
+        let pipeline = build_transcode_pipeline(
+            &PathBuf::from("/tmp/input.mkv"),
+            &PathBuf::from("/tmp/output.mp4"),
+            &options,
+        );
+
+        // gpop doesn't know a vaapi AV1 encoder family, so this falls back.
+        assert!(pipeline.contains("av1enc"));
+    }
+
+    #[test]
+    fn test_encoder_extra_properties_applied_to_video_and_audio() {
+        let options = TranscodeOptions {
+            encoder: super::super::types::EncoderConfig {
+                backend: super::super::types::EncoderBackend::Software,
+                properties: vec![("speed-preset".to_string(), "veryfast".to_string())],
+            },
+            ..Default::default()
+        };
+
+        let pipeline = build_transcode_pipeline(
+            &PathBuf::from("/tmp/input.mkv"),
+            &PathBuf::from("/tmp/output.mp4"),
+            &options,
+        );
+
+        assert!(pipeline.matches("speed-preset=veryfast").count() >= 2);
+    }
+
+    #[test]
+    fn test_hls_pipeline_default_rungs() {
+        let input = PathBuf::from("/tmp/input.mkv");
+        let output_dir = PathBuf::from("/tmp/hls_output");
+        let options = TranscodeOptions {
+            output_format: OutputFormat::Hls,
+            ..Default::default()
+        };
+
+        let pipeline = build_transcode_pipeline(&input, &output_dir, &options);
+
+        assert!(pipeline.contains("tee name=videotee"));
+        assert!(pipeline.contains("tee name=audiotee"));
+        assert!(pipeline.contains("splitmuxsink name=mux_2000k_1280x720"));
+        assert!(pipeline.contains("splitmuxsink name=mux_800k_640x360"));
+        assert!(pipeline.contains("muxer-factory=cmafmux"));
+        assert!(pipeline.contains("hls_output/2000k_1280x720/segment_%05d.m4s"));
+    }
+
+    #[test]
+    fn test_dash_pipeline_uses_same_segmenting_as_hls() {
+        let input = PathBuf::from("/tmp/input.mkv");
+        let output_dir = PathBuf::from("/tmp/dash_output");
+        let options = TranscodeOptions {
+            output_format: OutputFormat::Dash,
+            ..Default::default()
+        };
+
+        let pipeline = build_transcode_pipeline(&input, &output_dir, &options);
+
+        assert!(pipeline.contains("muxer-factory=cmafmux"));
+        assert!(pipeline.contains("dash_output/2000k_1280x720/segment_%05d.m4s"));
+    }
+
+    #[test]
+    fn test_get_hls_output_files() {
+        let output_dir = PathBuf::from("/tmp/hls_output");
+        let options = TranscodeOptions {
+            output_format: OutputFormat::Hls,
+            hls_rungs: vec![HlsRung {
+                bitrate_kbps: 1000,
+                width: 960,
+                height: 540,
+            }],
+            ..Default::default()
+        };
+
+        // 6 seconds at a 2.5s target should need 3 segments.
+        let (master, variants) = get_hls_output_files(&output_dir, &options, 6_000_000_000);
+
+        assert_eq!(master, PathBuf::from("/tmp/hls_output/master.m3u8"));
+        assert_eq!(variants.len(), 1);
+        let (name, playlist_path, segments) = &variants[0];
+        assert_eq!(name, "1000k_960x540");
+        assert_eq!(playlist_path, &PathBuf::from("/tmp/hls_output/1000k_960x540/playlist.m3u8"));
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0], PathBuf::from("/tmp/hls_output/1000k_960x540/segment_00000.m4s"));
+    }
+
+    #[test]
+    fn test_get_hls_output_files_dash_manifest_name() {
+        let output_dir = PathBuf::from("/tmp/dash_output");
+        let options = TranscodeOptions {
+            output_format: OutputFormat::Dash,
+            ..Default::default()
+        };
+
+        let (master, _variants) = get_hls_output_files(&output_dir, &options, 3_000_000_000);
+
+        assert_eq!(master, PathBuf::from("/tmp/dash_output/manifest.mpd"));
+    }
+
+    #[test]
+    fn test_write_hls_playlists() {
+        let output_dir = std::env::temp_dir().join("gpop_test_write_hls_playlists");
+        let rungs = vec![HlsRung {
+            bitrate_kbps: 1000,
+            width: 960,
+            height: 540,
+        }];
+        std::fs::create_dir_all(output_dir.join(rungs[0].name())).unwrap();
+        let segments = vec![vec![
+            MediaSegment {
+                path: output_dir.join(rungs[0].name()).join("segment_00000.m4s"),
+                duration_secs: 2.5,
+            },
+            MediaSegment {
+                path: output_dir.join(rungs[0].name()).join("segment_00001.m4s"),
+                duration_secs: 2.4,
+            },
+        ]];
+
+        let (master_path, variants) = write_hls_playlists(&output_dir, &rungs, &segments).unwrap();
+
+        assert_eq!(master_path, output_dir.join("master.m3u8"));
+        let master_contents = std::fs::read_to_string(&master_path).unwrap();
+        assert!(master_contents.contains("#EXT-X-STREAM-INF"));
+        assert!(master_contents.contains("1000k_960x540/playlist.m3u8"));
+
+        assert_eq!(variants.len(), 1);
+        let media_contents = std::fs::read_to_string(&variants[0].playlist_path).unwrap();
+        assert!(media_contents.contains("segment_00000.m4s"));
+        assert!(media_contents.contains("#EXT-X-ENDLIST"));
+
+        std::fs::remove_dir_all(&output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_tags_noop_for_empty_tags() {
+        let output = PathBuf::from("/tmp/does-not-exist.mp3");
+        let tags = TrackTags::default();
+
+        // No file I/O happens for an empty tag set, so this doesn't touch
+        // the (nonexistent) path.
+        assert!(write_tags(&output, OutputFormat::Mp3, &tags).is_ok());
+    }
+
+    #[test]
+    fn test_write_tags_noop_for_unsupported_container() {
+        let output = PathBuf::from("/tmp/does-not-exist.webm");
+        let tags = TrackTags {
+            title: Some("Track".to_string()),
+            ..Default::default()
+        };
+
+        assert!(write_tags(&output, OutputFormat::Webm, &tags).is_ok());
+    }
+
+    #[test]
+    fn test_get_thumbnail_frame_files() {
+        let output_dir = PathBuf::from("/tmp/output");
+        let options = ThumbnailOptions {
+            count: 3,
+            format: ImageFormat::Png,
+            ..Default::default()
+        };
+
+        let files = get_thumbnail_frame_files(&output_dir, &options);
+
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[0], ("frame_0".to_string(), PathBuf::from("/tmp/output/frame_0.png")));
+        assert_eq!(files[2], ("frame_2".to_string(), PathBuf::from("/tmp/output/frame_2.png")));
+    }
 }
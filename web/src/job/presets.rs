@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::media::TrackTags;
+
+use super::types::{AudioCodec, OutputFormat, TranscodeOptions, VideoCodec};
+
+/// A named, server-defined set of transcode settings, echoing pict-rs's
+/// named processing presets. Clients pick a preset by name instead of
+/// hand-tuning bitrate/resolution, and may still override individual
+/// fields on top of it.
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscodePreset {
+    /// Human-readable summary shown by `GET /api/presets`
+    pub description: String,
+    pub output_format: OutputFormat,
+    pub video_bitrate_kbps: Option<u32>,
+    pub audio_bitrate_kbps: Option<u32>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub video_codec: Option<VideoCodec>,
+    pub audio_codec: Option<AudioCodec>,
+}
+
+impl TranscodePreset {
+    fn to_options(&self) -> TranscodeOptions {
+        TranscodeOptions {
+            output_format: self.output_format,
+            video_bitrate_kbps: self.video_bitrate_kbps,
+            audio_bitrate_kbps: self.audio_bitrate_kbps,
+            width: self.width,
+            height: self.height,
+            video_codec: self.video_codec,
+            audio_codec: self.audio_codec,
+            tags: None,
+            ..Default::default()
+        }
+    }
+
+    /// Expand this preset into `TranscodeOptions`, with `overrides` (query
+    /// params the caller set explicitly) winning field-by-field.
+    pub fn resolve(&self, overrides: &TranscodeOverrides) -> TranscodeOptions {
+        let mut options = self.to_options();
+        if let Some(format) = overrides.output_format {
+            options.output_format = format;
+        }
+        if overrides.video_bitrate_kbps.is_some() {
+            options.video_bitrate_kbps = overrides.video_bitrate_kbps;
+        }
+        if overrides.audio_bitrate_kbps.is_some() {
+            options.audio_bitrate_kbps = overrides.audio_bitrate_kbps;
+        }
+        if overrides.width.is_some() {
+            options.width = overrides.width;
+        }
+        if overrides.height.is_some() {
+            options.height = overrides.height;
+        }
+        if overrides.video_codec.is_some() {
+            options.video_codec = overrides.video_codec;
+        }
+        if overrides.audio_codec.is_some() {
+            options.audio_codec = overrides.audio_codec;
+        }
+        if overrides.tags.is_some() {
+            options.tags = overrides.tags.clone();
+        }
+        options
+    }
+}
+
+/// Explicit query parameters a caller set alongside `preset`, which take
+/// priority over the preset's own fields when resolving final
+/// `TranscodeOptions`.
+#[derive(Debug, Default)]
+pub struct TranscodeOverrides {
+    pub output_format: Option<OutputFormat>,
+    pub video_bitrate_kbps: Option<u32>,
+    pub audio_bitrate_kbps: Option<u32>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub video_codec: Option<VideoCodec>,
+    pub audio_codec: Option<AudioCodec>,
+    pub tags: Option<TrackTags>,
+}
+
+/// The presets shipped by default. Operators can't reconfigure these at
+/// runtime today (there's no config file, only CLI flags), so this is the
+/// single source of truth for what `preset=` names are accepted.
+pub fn default_presets() -> HashMap<String, TranscodePreset> {
+    let mut presets = HashMap::new();
+
+    presets.insert(
+        "web-720p".to_string(),
+        TranscodePreset {
+            description: "720p H.264/AAC MP4 for web playback".to_string(),
+            output_format: OutputFormat::Mp4,
+            video_bitrate_kbps: Some(2500),
+            audio_bitrate_kbps: Some(128),
+            width: None,
+            height: Some(720),
+            video_codec: None,
+            audio_codec: None,
+        },
+    );
+
+    presets.insert(
+        "mobile-low".to_string(),
+        TranscodePreset {
+            description: "Low-bitrate 480p MP4 for constrained mobile connections".to_string(),
+            output_format: OutputFormat::Mp4,
+            video_bitrate_kbps: Some(600),
+            audio_bitrate_kbps: Some(64),
+            width: None,
+            height: Some(480),
+            video_codec: None,
+            audio_codec: None,
+        },
+    );
+
+    presets.insert(
+        "podcast-mono".to_string(),
+        TranscodePreset {
+            description: "64kbps MP3 sized for spoken-word audio".to_string(),
+            output_format: OutputFormat::Mp3,
+            video_bitrate_kbps: None,
+            audio_bitrate_kbps: Some(64),
+            width: None,
+            height: None,
+            video_codec: None,
+            audio_codec: None,
+        },
+    );
+
+    presets
+}
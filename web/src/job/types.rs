@@ -1,6 +1,10 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use thiserror::Error;
+
+use crate::media::{MediaDetails, TrackTags};
+use crate::storage::OutputLocation;
 
 /// Job type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -8,6 +12,7 @@ use std::path::PathBuf;
 pub enum JobType {
     Transcode,
     Demucs,
+    Thumbnail,
 }
 
 /// Job status
@@ -18,6 +23,12 @@ pub enum JobStatus {
     Pending,
     /// Job is currently processing
     Processing,
+    /// Job failed with a retriable error and is waiting out a backoff delay
+    /// before `JobManager` rebuilds its pipeline and tries again
+    Retrying,
+    /// Job's pipeline has been paused (GStreamer PAUSED state) and is
+    /// holding its position, waiting to be resumed
+    Paused,
     /// Job completed successfully
     Completed,
     /// Job failed with an error
@@ -26,6 +37,50 @@ pub enum JobStatus {
     Cancelled,
 }
 
+/// Why a job failed. Serializes as a tagged object (`{"kind": ..., "message":
+/// ...}`) instead of a bare string, so clients can render a distinct message
+/// per failure class and decide whether retrying is worth it without
+/// pattern-matching on free text.
+#[derive(Debug, Clone, Serialize, Deserialize, Error)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum JobError {
+    /// Output format/codec combination the pipeline can't produce
+    #[error("unsupported format: {0}")]
+    UnsupportedFormat(String),
+    /// ffprobe failed to read the input file
+    #[error("probe failed: {0}")]
+    ProbeFailed(String),
+    /// Input file was missing when the job tried to read it
+    #[error("input not found: {0}")]
+    InputNotFound(String),
+    /// gpop pipeline creation, playback or the bus reported an error
+    #[error("pipeline error: {0}")]
+    PipelineError(String),
+    /// Requested Demucs model isn't available on this host
+    #[error("model unavailable: {0}")]
+    ModelUnavailable(String),
+    /// Job was cancelled before it finished
+    #[error("cancelled")]
+    Cancelled,
+    /// Job exceeded its processing deadline
+    #[error("timed out: {0}")]
+    Timeout(String),
+    /// Anything that doesn't fit the classes above
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+impl JobError {
+    /// Whether retrying the job stands a chance of succeeding. Mirrors
+    /// `AppError::is_retriable`/`GpopError::is_retriable`: transient
+    /// conditions (a flaky pipeline, a deadline that might not be hit next
+    /// time) are worth another attempt, while errors rooted in the job's
+    /// input or request will just fail the same way again.
+    pub fn is_retriable(&self) -> bool {
+        matches!(self, JobError::PipelineError(_) | JobError::Timeout(_))
+    }
+}
+
 /// Output format for transcoding
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -36,6 +91,17 @@ pub enum OutputFormat {
     Mp3,
     Ogg,
     Flac,
+    /// Adaptive-bitrate HLS: a master `.m3u8` playlist plus one fMP4-segmented
+    /// media playlist per `TranscodeOptions::hls_rungs` rung. Unlike the
+    /// single-file formats above, the job's output is a directory. A job
+    /// writes only the per-rung segments there; the playlists come from
+    /// `write_hls_playlists`, which `JobManager` does not call.
+    Hls,
+    /// Adaptive-bitrate DASH: a `.mpd` manifest plus one fMP4-segmented
+    /// representation per rung, same rung ladder and directory layout as
+    /// `Hls`. As with `Hls`, a job writes only the segments; the manifest
+    /// comes from `write_dash_manifest`, which `JobManager` does not call.
+    Dash,
 }
 
 impl OutputFormat {
@@ -47,12 +113,66 @@ impl OutputFormat {
             OutputFormat::Mp3 => "mp3",
             OutputFormat::Ogg => "ogg",
             OutputFormat::Flac => "flac",
+            OutputFormat::Hls => "m3u8",
+            OutputFormat::Dash => "mpd",
         }
     }
 
     pub fn is_audio_only(&self) -> bool {
         matches!(self, OutputFormat::Mp3 | OutputFormat::Ogg | OutputFormat::Flac)
     }
+
+    /// Whether this format's output is a directory of files (a master
+    /// playlist/manifest plus per-rung segments) rather than a single file.
+    pub fn is_segmented(&self) -> bool {
+        matches!(self, OutputFormat::Hls | OutputFormat::Dash)
+    }
+
+    /// Video/audio codecs this container can legally carry, most-preferred
+    /// first. The first entry of each is also the fallback used when a job
+    /// doesn't specify one.
+    pub fn allowed_codecs(&self) -> (&'static [VideoCodec], &'static [AudioCodec]) {
+        match self {
+            OutputFormat::Mp4 => (
+                &[VideoCodec::H264, VideoCodec::H265, VideoCodec::Av1],
+                &[AudioCodec::Aac],
+            ),
+            OutputFormat::Webm => (
+                &[VideoCodec::Vp9, VideoCodec::Av1],
+                &[AudioCodec::Opus, AudioCodec::Vorbis],
+            ),
+            OutputFormat::Mkv => (
+                &[
+                    VideoCodec::H264,
+                    VideoCodec::H265,
+                    VideoCodec::Av1,
+                    VideoCodec::Vp9,
+                ],
+                &[
+                    AudioCodec::Aac,
+                    AudioCodec::Opus,
+                    AudioCodec::Vorbis,
+                    AudioCodec::Mp3,
+                    AudioCodec::Flac,
+                ],
+            ),
+            OutputFormat::Mp3 => (&[], &[AudioCodec::Mp3]),
+            OutputFormat::Ogg => (&[], &[AudioCodec::Vorbis, AudioCodec::Opus]),
+            OutputFormat::Flac => (&[], &[AudioCodec::Flac]),
+            OutputFormat::Hls => (&[VideoCodec::H264, VideoCodec::H265], &[AudioCodec::Aac]),
+            OutputFormat::Dash => (
+                &[VideoCodec::H264, VideoCodec::H265, VideoCodec::Av1],
+                &[AudioCodec::Aac],
+            ),
+        }
+    }
+
+    /// The codec used when a job leaves `video_codec`/`audio_codec` unset -
+    /// the first (most-preferred) entry of `allowed_codecs()`.
+    pub fn default_codecs(&self) -> (Option<VideoCodec>, Option<AudioCodec>) {
+        let (video, audio) = self.allowed_codecs();
+        (video.first().copied(), audio.first().copied())
+    }
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -66,11 +186,86 @@ impl std::str::FromStr for OutputFormat {
             "mp3" => Ok(OutputFormat::Mp3),
             "ogg" => Ok(OutputFormat::Ogg),
             "flac" => Ok(OutputFormat::Flac),
+            "hls" => Ok(OutputFormat::Hls),
+            "dash" => Ok(OutputFormat::Dash),
             _ => Err(format!("Unknown format: {}", s)),
         }
     }
 }
 
+/// Video codec for transcoding, independent of the container format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoCodec {
+    H264,
+    H265,
+    Av1,
+    Vp9,
+}
+
+impl VideoCodec {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VideoCodec::H264 => "h264",
+            VideoCodec::H265 => "h265",
+            VideoCodec::Av1 => "av1",
+            VideoCodec::Vp9 => "vp9",
+        }
+    }
+}
+
+impl std::str::FromStr for VideoCodec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "h264" => Ok(VideoCodec::H264),
+            "h265" => Ok(VideoCodec::H265),
+            "av1" => Ok(VideoCodec::Av1),
+            "vp9" => Ok(VideoCodec::Vp9),
+            _ => Err(format!("Unknown video codec: {}", s)),
+        }
+    }
+}
+
+/// Audio codec for transcoding, independent of the container format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioCodec {
+    Aac,
+    Opus,
+    Vorbis,
+    Mp3,
+    Flac,
+}
+
+impl AudioCodec {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioCodec::Aac => "aac",
+            AudioCodec::Opus => "opus",
+            AudioCodec::Vorbis => "vorbis",
+            AudioCodec::Mp3 => "mp3",
+            AudioCodec::Flac => "flac",
+        }
+    }
+}
+
+impl std::str::FromStr for AudioCodec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "aac" => Ok(AudioCodec::Aac),
+            "opus" => Ok(AudioCodec::Opus),
+            "vorbis" => Ok(AudioCodec::Vorbis),
+            "mp3" => Ok(AudioCodec::Mp3),
+            "flac" => Ok(AudioCodec::Flac),
+            _ => Err(format!("Unknown audio codec: {}", s)),
+        }
+    }
+}
+
 /// Demucs stem types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -126,6 +321,123 @@ impl DemucsModel {
     }
 }
 
+/// Which element family to synthesize a video encoder from: plain software
+/// encoders, or a named hardware-accelerated family. `build_video_encoder`
+/// can't probe the GStreamer registry from a bare pipeline description, so
+/// unsupported codec/backend combinations (see `hardware_encoder_element`)
+/// fall back to the software encoder rather than emitting an element that
+/// might not be installed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EncoderBackend {
+    Software,
+    Vaapi,
+    Nvenc,
+    Qsv,
+}
+
+impl Default for EncoderBackend {
+    fn default() -> Self {
+        EncoderBackend::Software
+    }
+}
+
+/// Data-driven encoder selection carried on `TranscodeOptions`: which
+/// backend to prefer for the video encoder, plus arbitrary extra
+/// `key=value` properties (preset, profile, keyframe interval, rate-control
+/// mode, ...) appended verbatim to both the video and audio encoder
+/// elements, after whatever `video_bitrate_kbps`/`audio_bitrate_kbps`
+/// already set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EncoderConfig {
+    #[serde(default)]
+    pub backend: EncoderBackend,
+    #[serde(default)]
+    pub properties: Vec<(String, String)>,
+}
+
+/// How to handle CEA-608/708 closed captions carried by the input when
+/// transcoding. Unset (`None` on `TranscodeOptions::captions`) keeps the
+/// pipeline's original behavior of simply dropping them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptionMode {
+    /// Carry the caption data through to the output container unmodified.
+    Passthrough,
+    /// Render captions onto the video itself before encoding, so they
+    /// survive into players that don't support the container's caption
+    /// track.
+    Burn,
+    /// Extract captions to a separate WebVTT file next to the output,
+    /// instead of writing them into the container at all.
+    Sidecar,
+}
+
+/// Where a transcode's output goes: written to a file using
+/// `output_format`'s container (the default), or pushed live to a streaming
+/// ingest endpoint. SRT and RTMP each carry their own fixed container
+/// (MPEG-TS, FLV) and codec set (H.264/AAC), independent of
+/// `output_format`, which is ignored for either of them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum OutputTarget {
+    File,
+    /// Push an MPEG-TS-muxed stream to an SRT listener/caller, e.g.
+    /// `srt://host:port`.
+    Srt { uri: String },
+    /// Push an FLV-muxed stream to an RTMP server, e.g.
+    /// `rtmp://host/app/stream`.
+    Rtmp { location: String },
+}
+
+impl Default for OutputTarget {
+    fn default() -> Self {
+        OutputTarget::File
+    }
+}
+
+impl OutputTarget {
+    pub fn is_network(&self) -> bool {
+        !matches!(self, OutputTarget::File)
+    }
+}
+
+/// A single bitrate/resolution rung of an `OutputFormat::Hls`/`Dash` adaptive
+/// ladder, fanned out from one `tee` into its own encode-and-segment branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HlsRung {
+    pub bitrate_kbps: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl HlsRung {
+    /// Directory/variant name for this rung, e.g. `2000k_1280x720`, used both
+    /// as the `output_dir` subdirectory and the master playlist's variant
+    /// reference.
+    pub fn name(&self) -> String {
+        format!("{}k_{}x{}", self.bitrate_kbps, self.width, self.height)
+    }
+}
+
+/// Default adaptive ladder used when a `Hls`/`Dash` job doesn't specify
+/// `TranscodeOptions::hls_rungs`: a 720p and a 360p rung, matching the
+/// bitrate/resolution pairs commonly seen in VOD ladders.
+pub fn default_hls_rungs() -> Vec<HlsRung> {
+    vec![
+        HlsRung {
+            bitrate_kbps: 2000,
+            width: 1280,
+            height: 720,
+        },
+        HlsRung {
+            bitrate_kbps: 800,
+            width: 640,
+            height: 360,
+        },
+    ]
+}
+
 /// Transcoding options
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscodeOptions {
@@ -138,6 +450,36 @@ pub struct TranscodeOptions {
     pub width: Option<u32>,
     #[serde(default)]
     pub height: Option<u32>,
+    /// Explicit video codec, e.g. to pick H.265 inside an MP4. `None` falls
+    /// back to `output_format`'s default.
+    #[serde(default)]
+    pub video_codec: Option<VideoCodec>,
+    /// Explicit audio codec, e.g. to pick Opus inside a WebM. `None` falls
+    /// back to `output_format`'s default.
+    #[serde(default)]
+    pub audio_codec: Option<AudioCodec>,
+    /// Track metadata to write into the output (ID3v2 for MP3, Vorbis
+    /// comments for Ogg/FLAC, iTunes atoms for MP4/M4A). Fields left unset
+    /// here fall back to whatever the probe subsystem read off the input -
+    /// see `resolved_tags`.
+    #[serde(default)]
+    pub tags: Option<TrackTags>,
+    /// Bitrate/resolution ladder for `Hls`/`Dash` output. Empty falls back to
+    /// `default_hls_rungs()`; ignored for single-file formats.
+    #[serde(default)]
+    pub hls_rungs: Vec<HlsRung>,
+    /// Backend and extra properties for the video/audio encoder elements.
+    /// Defaults to plain software encoding with no extra properties.
+    #[serde(default)]
+    pub encoder: EncoderConfig,
+    /// How to handle closed captions carried by the input. `None` (the
+    /// default) drops them, same as before this field existed.
+    #[serde(default)]
+    pub captions: Option<CaptionMode>,
+    /// Where the output goes: a file (the default) or a live streaming
+    /// ingest endpoint. See `OutputTarget`.
+    #[serde(default)]
+    pub output_target: OutputTarget,
 }
 
 impl Default for TranscodeOptions {
@@ -148,6 +490,138 @@ impl Default for TranscodeOptions {
             audio_bitrate_kbps: None,
             width: None,
             height: None,
+            video_codec: None,
+            audio_codec: None,
+            tags: None,
+            hls_rungs: vec![],
+            encoder: EncoderConfig::default(),
+            captions: None,
+            output_target: OutputTarget::File,
+        }
+    }
+}
+
+impl TranscodeOptions {
+    /// The video codec to actually encode with: the explicit choice if one
+    /// was made, else `output_format`'s default (`None` for an audio-only
+    /// format).
+    pub fn resolved_video_codec(&self) -> Option<VideoCodec> {
+        self.video_codec.or_else(|| self.output_format.default_codecs().0)
+    }
+
+    /// The audio codec to actually encode with: the explicit choice if one
+    /// was made, else `output_format`'s default.
+    pub fn resolved_audio_codec(&self) -> Option<AudioCodec> {
+        self.audio_codec.or_else(|| self.output_format.default_codecs().1)
+    }
+
+    /// The bitrate/resolution ladder to actually encode with: `hls_rungs` if
+    /// set, else `default_hls_rungs()`. Only meaningful for `Hls`/`Dash`.
+    pub fn resolved_hls_rungs(&self) -> Vec<HlsRung> {
+        if self.hls_rungs.is_empty() {
+            default_hls_rungs()
+        } else {
+            self.hls_rungs.clone()
+        }
+    }
+
+    /// Reject an explicit `video_codec`/`audio_codec` that `output_format`
+    /// can't legally carry (e.g. VP9 in an MP4).
+    pub fn validate_codecs(&self) -> std::result::Result<(), String> {
+        let (allowed_video, allowed_audio) = self.output_format.allowed_codecs();
+
+        if let Some(codec) = self.video_codec {
+            if !allowed_video.contains(&codec) {
+                return Err(format!(
+                    "video codec {:?} isn't supported in {:?}",
+                    codec, self.output_format
+                ));
+            }
+        }
+
+        if let Some(codec) = self.audio_codec {
+            if !allowed_audio.contains(&codec) {
+                return Err(format!(
+                    "audio codec {:?} isn't supported in {:?}",
+                    codec, self.output_format
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reject an explicit `video_codec`/`audio_codec` that `output_target`
+    /// can't carry: SRT (MPEG-TS) and RTMP (FLV) are both constrained to
+    /// H.264/AAC, regardless of what `output_format` would otherwise allow.
+    pub fn validate_output_target(&self) -> std::result::Result<(), String> {
+        if !self.output_target.is_network() {
+            return Ok(());
+        }
+
+        if let Some(codec) = self.video_codec {
+            if codec != VideoCodec::H264 {
+                return Err(format!(
+                    "{:?} requires H.264 video, got {:?}",
+                    self.output_target, codec
+                ));
+            }
+        }
+
+        if let Some(codec) = self.audio_codec {
+            if codec != AudioCodec::Aac {
+                return Err(format!(
+                    "{:?} requires AAC audio, got {:?}",
+                    self.output_target, codec
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reject a combination of output options and probed input media that
+    /// the pipeline could never satisfy, so the caller fails fast instead of
+    /// discovering it deep inside gpop once the pipeline is already running.
+    pub fn validate_against(&self, media: &MediaDetails) -> std::result::Result<(), String> {
+        if self.output_format.is_audio_only() {
+            if self.width.is_some() || self.height.is_some() {
+                return Err(format!(
+                    "width/height don't apply to audio-only format {:?}",
+                    self.output_format
+                ));
+            }
+            if !media.has_audio() {
+                return Err(format!(
+                    "{:?} output requires an audio stream, but the input has none",
+                    self.output_format
+                ));
+            }
+        } else if !media.has_video() {
+            return Err(format!(
+                "{:?} output requires a video stream, but the input has none",
+                self.output_format
+            ));
+        }
+        Ok(())
+    }
+
+    /// Tags to actually write into the output: explicit fields (`self.tags`)
+    /// win, with anything left unset filled in from the input's probed tags
+    /// so a plain format conversion doesn't silently drop existing metadata.
+    /// Returns `None` if there's nothing to write either way.
+    pub fn resolved_tags(&self, media: Option<&MediaDetails>) -> Option<TrackTags> {
+        let probed = media.map(|m| &m.tags);
+        let merged = match (&self.tags, probed) {
+            (Some(explicit), Some(probed)) => explicit.merged_with_probe(probed),
+            (Some(explicit), None) => explicit.clone(),
+            (None, Some(probed)) => probed.clone(),
+            (None, None) => return None,
+        };
+        if merged.is_empty() {
+            None
+        } else {
+            Some(merged)
         }
     }
 }
@@ -180,12 +654,159 @@ impl Default for DemucsOptions {
     }
 }
 
+impl DemucsOptions {
+    /// Reject separating a file with no audio stream to probe for stems.
+    pub fn validate_against(&self, media: &MediaDetails) -> std::result::Result<(), String> {
+        if !media.has_audio() {
+            return Err("demucs requires an audio stream, but the input has none".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Image format for thumbnail/sprite output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    Webp,
+    Jpeg,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Webp => "webp",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+impl Default for ImageFormat {
+    fn default() -> Self {
+        ImageFormat::Webp
+    }
+}
+
+impl std::str::FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "webp" => Ok(ImageFormat::Webp),
+            "jpeg" | "jpg" => Ok(ImageFormat::Jpeg),
+            "png" => Ok(ImageFormat::Png),
+            _ => Err(format!("Unknown image format: {}", s)),
+        }
+    }
+}
+
+/// Thumbnail/preview-sprite generation options
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThumbnailOptions {
+    /// Number of frames to extract
+    #[serde(default = "default_thumbnail_count")]
+    pub count: u32,
+    /// Width in pixels to scale each frame to, preserving aspect ratio
+    #[serde(default = "default_thumbnail_width")]
+    pub width: u32,
+    /// Output image format
+    #[serde(default)]
+    pub format: ImageFormat,
+    /// Tile all frames into a single grid image (plus a JSON index mapping
+    /// each cell to its timestamp) instead of emitting one file per frame
+    #[serde(default)]
+    pub sprite: bool,
+    /// Explicit sample points, as a percentage (0.0-100.0) of the input's
+    /// duration. Overrides the default evenly-spaced sampling; must have
+    /// exactly `count` entries if set.
+    #[serde(default)]
+    pub at_percentages: Option<Vec<f32>>,
+}
+
+fn default_thumbnail_count() -> u32 {
+    5
+}
+
+fn default_thumbnail_width() -> u32 {
+    320
+}
+
+impl Default for ThumbnailOptions {
+    fn default() -> Self {
+        Self {
+            count: default_thumbnail_count(),
+            width: default_thumbnail_width(),
+            format: ImageFormat::default(),
+            sprite: false,
+            at_percentages: None,
+        }
+    }
+}
+
+impl ThumbnailOptions {
+    /// Timestamps to sample, in nanoseconds: either the explicit
+    /// `at_percentages`, or `count` frames evenly spaced across
+    /// `duration_ns` per `t_i = duration * (i + 0.5) / count`.
+    pub fn timestamps_ns(&self, duration_ns: u64) -> Vec<u64> {
+        match &self.at_percentages {
+            Some(percentages) => percentages
+                .iter()
+                .map(|p| ((*p as f64 / 100.0) * duration_ns as f64) as u64)
+                .collect(),
+            None => (0..self.count)
+                .map(|i| (duration_ns as f64 * (i as f64 + 0.5) / self.count as f64) as u64)
+                .collect(),
+        }
+    }
+
+    /// Column count for the sprite grid, `ceil(sqrt(count))`.
+    pub fn grid_columns(&self) -> u32 {
+        (self.count as f64).sqrt().ceil() as u32
+    }
+
+    /// Reject thumbnailing a file with no video stream, or options that
+    /// can't be satisfied (zero frames, a mismatched `at_percentages`).
+    pub fn validate_against(&self, media: &MediaDetails) -> std::result::Result<(), String> {
+        if !media.has_video() {
+            return Err("thumbnail generation requires a video stream, but the input has none".to_string());
+        }
+        if self.count == 0 {
+            return Err("count must be at least 1".to_string());
+        }
+        if let Some(percentages) = &self.at_percentages {
+            if percentages.len() != self.count as usize {
+                return Err(format!(
+                    "at_percentages has {} entries but count is {}",
+                    percentages.len(),
+                    self.count
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A single cell of a thumbnail sprite grid, mapping its rect back to the
+/// timestamp it was sampled at
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThumbnailSpriteCell {
+    pub index: u32,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub timestamp_ns: u64,
+}
+
 /// Job-specific options
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum JobOptions {
     Transcode(TranscodeOptions),
     Demucs(DemucsOptions),
+    Thumbnail(ThumbnailOptions),
 }
 
 impl JobOptions {
@@ -193,12 +814,22 @@ impl JobOptions {
         match self {
             JobOptions::Transcode(_) => JobType::Transcode,
             JobOptions::Demucs(_) => JobType::Demucs,
+            JobOptions::Thumbnail(_) => JobType::Thumbnail,
         }
     }
 }
 
+/// Default scheduling priority for a new job, on a 0 (lowest) - 255
+/// (highest) scale. Leaves headroom on both sides for callers that want to
+/// de-prioritize bulk work or bump something ahead of the queue.
+pub const DEFAULT_JOB_PRIORITY: u8 = 128;
+
+/// Default cap on automatic retries for a transient (`JobError::is_retriable`)
+/// failure before a job is given up on as terminally `Failed`.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
 /// A processing job (transcode or demucs)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Job {
     /// Unique job ID (UUID)
     pub id: String,
@@ -216,6 +847,12 @@ pub struct Job {
     pub output_path: PathBuf,
     /// Output paths for demucs stems (populated after completion)
     pub output_stems: Vec<PathBuf>,
+    /// Where the transcode output lives once published to the storage
+    /// backend (populated after completion).
+    pub output_location: Option<OutputLocation>,
+    /// Where each demucs stem lives once published, keyed by stem name
+    /// (populated after completion).
+    pub stem_locations: Vec<(String, OutputLocation)>,
     /// Job options
     pub options: JobOptions,
     /// Progress (0.0 to 1.0)
@@ -224,14 +861,76 @@ pub struct Job {
     pub position_ns: Option<u64>,
     /// Total duration in nanoseconds
     pub duration_ns: Option<u64>,
-    /// Error message if failed
-    pub error: Option<String>,
+    /// Input media metadata from the pre-enqueue ffprobe, if probing
+    /// succeeded. `duration_ns` above is seeded from this so progress
+    /// reporting has a denominator from the start.
+    pub media_info: Option<MediaDetails>,
+    /// Classified failure reason, set once the job transitions to `Failed`
+    pub error: Option<JobError>,
     /// When the job was created
     pub created_at: DateTime<Utc>,
     /// When processing started
     pub started_at: Option<DateTime<Utc>>,
     /// When processing completed
     pub completed_at: Option<DateTime<Utc>>,
+    /// When this job (and its files) should be automatically deleted by the
+    /// reaper, if the client requested a `keep_for` TTL on creation
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Content-addressed cache key (hash of the input plus its options),
+    /// present when the job was created through the dedup path. Used to
+    /// evict or refresh the completed-job cache when this job finishes.
+    pub dedup_key: Option<String>,
+    /// Scheduling priority; higher runs sooner. Currently advisory - the
+    /// concurrency gate in `JobManager` is a fair per-type semaphore, so
+    /// this doesn't yet reorder jobs ahead of others already waiting on a
+    /// permit. A real priority-ordered dispatcher is tracked separately.
+    #[serde(default = "default_job_priority")]
+    pub priority: u8,
+    /// Number of times this job has been automatically retried after a
+    /// transient failure.
+    #[serde(default)]
+    pub retry_count: u32,
+    /// How many retries a transient failure gets before the job is given up
+    /// on as terminally `Failed`.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// When the next automatic retry is scheduled to fire, if one is
+    /// currently pending.
+    #[serde(default)]
+    pub next_retry_at: Option<DateTime<Utc>>,
+    /// Client-supplied key (distinct from the content-hash `dedup_key`) used
+    /// to make re-submitting the same request idempotent: a second
+    /// submission with the same key returns the existing job instead of
+    /// starting a duplicate one.
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+    /// Job specs to enqueue automatically against this job's finished
+    /// output(s) once it completes successfully - every stem for a
+    /// multi-output job (demucs/thumbnail), or the single output file for a
+    /// transcode job. Set via `JobManager::set_follow_ups` after creation,
+    /// same shape as `idempotency_key`.
+    #[serde(default)]
+    pub follow_ups: Vec<JobOptions>,
+    /// Id of the job whose `follow_ups` spawned this one, if any.
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Ids of jobs spawned from this job's `follow_ups` once it completed.
+    #[serde(default)]
+    pub child_ids: Vec<String>,
+    /// When `position_ns` last actually advanced, reset each time
+    /// `start_pipeline` (re)starts the job. Compared against
+    /// `Config::stall_timeout_secs` by the progress-poller watchdog to spot
+    /// a wedged pipeline.
+    #[serde(default)]
+    pub last_progress_at: Option<DateTime<Utc>>,
+}
+
+fn default_job_priority() -> u8 {
+    DEFAULT_JOB_PRIORITY
+}
+
+fn default_max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
 }
 
 impl Job {
@@ -241,7 +940,11 @@ impl Job {
         input_path: PathBuf,
         output_path: PathBuf,
         options: TranscodeOptions,
+        keep_for: Option<Duration>,
+        dedup_key: Option<String>,
+        media_info: Option<MediaDetails>,
     ) -> Self {
+        let duration_ns = media_info.as_ref().and_then(MediaDetails::duration_ns);
         Self {
             id,
             job_type: JobType::Transcode,
@@ -251,14 +954,28 @@ impl Job {
             input_path,
             output_path,
             output_stems: vec![],
+            output_location: None,
+            stem_locations: vec![],
             options: JobOptions::Transcode(options),
             progress: 0.0,
             position_ns: None,
-            duration_ns: None,
+            duration_ns,
+            media_info,
             error: None,
             created_at: Utc::now(),
             started_at: None,
             completed_at: None,
+            expires_at: keep_for.map(|d| Utc::now() + d),
+            dedup_key,
+            priority: DEFAULT_JOB_PRIORITY,
+            retry_count: 0,
+            max_retries: DEFAULT_MAX_RETRIES,
+            next_retry_at: None,
+            idempotency_key: None,
+            follow_ups: vec![],
+            parent_id: None,
+            child_ids: vec![],
+            last_progress_at: None,
         }
     }
 
@@ -268,7 +985,11 @@ impl Job {
         input_path: PathBuf,
         output_dir: PathBuf,
         options: DemucsOptions,
+        keep_for: Option<Duration>,
+        dedup_key: Option<String>,
+        media_info: Option<MediaDetails>,
     ) -> Self {
+        let duration_ns = media_info.as_ref().and_then(MediaDetails::duration_ns);
         Self {
             id,
             job_type: JobType::Demucs,
@@ -278,14 +999,73 @@ impl Job {
             input_path,
             output_path: output_dir,
             output_stems: vec![],
+            output_location: None,
+            stem_locations: vec![],
             options: JobOptions::Demucs(options),
             progress: 0.0,
             position_ns: None,
-            duration_ns: None,
+            duration_ns,
+            media_info,
+            error: None,
+            created_at: Utc::now(),
+            started_at: None,
+            completed_at: None,
+            expires_at: keep_for.map(|d| Utc::now() + d),
+            dedup_key,
+            priority: DEFAULT_JOB_PRIORITY,
+            retry_count: 0,
+            max_retries: DEFAULT_MAX_RETRIES,
+            next_retry_at: None,
+            idempotency_key: None,
+            follow_ups: vec![],
+            parent_id: None,
+            child_ids: vec![],
+            last_progress_at: None,
+        }
+    }
+
+    pub fn new_thumbnail(
+        id: String,
+        input_filename: String,
+        input_path: PathBuf,
+        output_dir: PathBuf,
+        options: ThumbnailOptions,
+        keep_for: Option<Duration>,
+        dedup_key: Option<String>,
+        media_info: Option<MediaDetails>,
+    ) -> Self {
+        let duration_ns = media_info.as_ref().and_then(MediaDetails::duration_ns);
+        Self {
+            id,
+            job_type: JobType::Thumbnail,
+            pipeline_id: None,
+            status: JobStatus::Pending,
+            input_filename,
+            input_path,
+            output_path: output_dir,
+            output_stems: vec![],
+            output_location: None,
+            stem_locations: vec![],
+            options: JobOptions::Thumbnail(options),
+            progress: 0.0,
+            position_ns: None,
+            duration_ns,
+            media_info,
             error: None,
             created_at: Utc::now(),
             started_at: None,
             completed_at: None,
+            expires_at: keep_for.map(|d| Utc::now() + d),
+            dedup_key,
+            priority: DEFAULT_JOB_PRIORITY,
+            retry_count: 0,
+            max_retries: DEFAULT_MAX_RETRIES,
+            next_retry_at: None,
+            idempotency_key: None,
+            follow_ups: vec![],
+            parent_id: None,
+            child_ids: vec![],
+            last_progress_at: None,
         }
     }
 
@@ -304,6 +1084,36 @@ impl Job {
             _ => None,
         }
     }
+
+    /// Get thumbnail options if this is a thumbnail job
+    pub fn thumbnail_options(&self) -> Option<&ThumbnailOptions> {
+        match &self.options {
+            JobOptions::Thumbnail(opts) => Some(opts),
+            _ => None,
+        }
+    }
+
+    /// Seconds until the reaper purges this job, if it has a TTL. `0` if the
+    /// deadline has already passed but the reaper hasn't run yet.
+    pub fn expires_in_seconds(&self) -> Option<i64> {
+        self.expires_at
+            .map(|at| (at - Utc::now()).num_seconds().max(0))
+    }
+
+    /// Set the scheduling priority at creation time, chainable onto
+    /// `new_transcode`/`new_demucs`/`new_thumbnail` so those constructors
+    /// don't need yet another positional parameter.
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Attach the client-supplied idempotency key at creation time, same
+    /// rationale as `with_priority`.
+    pub fn with_idempotency_key(mut self, key: Option<String>) -> Self {
+        self.idempotency_key = key;
+        self
+    }
 }
 
 /// Job summary for API responses
@@ -317,6 +1127,9 @@ pub struct JobSummary {
     pub output_info: String,
     pub progress: f64,
     pub created_at: DateTime<Utc>,
+    /// Seconds until this job's files are purged by the reaper, if it was
+    /// created with a `keep_for` TTL
+    pub expires_in_seconds: Option<i64>,
 }
 
 impl From<&Job> for JobSummary {
@@ -324,6 +1137,7 @@ impl From<&Job> for JobSummary {
         let output_info = match &job.options {
             JobOptions::Transcode(opts) => opts.output_format.extension().to_string(),
             JobOptions::Demucs(opts) => opts.model.as_str().to_string(),
+            JobOptions::Thumbnail(opts) => opts.format.extension().to_string(),
         };
         Self {
             id: job.id.clone(),
@@ -333,6 +1147,7 @@ impl From<&Job> for JobSummary {
             output_info,
             progress: job.progress,
             created_at: job.created_at,
+            expires_in_seconds: job.expires_in_seconds(),
         }
     }
 }
@@ -348,7 +1163,7 @@ pub struct JobDetails {
     pub progress: f64,
     pub position_ns: Option<u64>,
     pub duration_ns: Option<u64>,
-    pub error: Option<String>,
+    pub error: Option<JobError>,
     /// Download URL for single-file output (transcode)
     pub download_url: Option<String>,
     /// Download URLs for multiple outputs (demucs stems)
@@ -356,6 +1171,23 @@ pub struct JobDetails {
     pub created_at: DateTime<Utc>,
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// Seconds until this job's files are purged by the reaper, if it was
+    /// created with a `keep_for` TTL
+    pub expires_in_seconds: Option<i64>,
+    /// ffprobe metadata for the output file, populated once the job
+    /// completes so clients can validate the transcode's result
+    pub media: Option<MediaDetails>,
+    /// Scheduling priority the job was submitted with
+    pub priority: u8,
+    /// Number of automatic retries attempted so far after transient failures
+    pub retry_count: u32,
+    /// When the next automatic retry is scheduled, if a retry is pending
+    pub next_retry_at: Option<DateTime<Utc>>,
+    /// Id of the job whose `follow_ups` spawned this one, if any - lets the
+    /// UI render the chain this job is part of.
+    pub parent_id: Option<String>,
+    /// Ids of jobs spawned from this job's `follow_ups` once it completed.
+    pub child_ids: Vec<String>,
 }
 
 /// Stem download info for demucs jobs
@@ -370,6 +1202,7 @@ impl JobDetails {
         job: &Job,
         download_url: Option<String>,
         download_urls: Option<Vec<StemDownload>>,
+        media: Option<MediaDetails>,
     ) -> Self {
         Self {
             id: job.id.clone(),
@@ -386,6 +1219,13 @@ impl JobDetails {
             created_at: job.created_at,
             started_at: job.started_at,
             completed_at: job.completed_at,
+            expires_in_seconds: job.expires_in_seconds(),
+            media,
+            priority: job.priority,
+            retry_count: job.retry_count,
+            next_retry_at: job.next_retry_at,
+            parent_id: job.parent_id.clone(),
+            child_ids: job.child_ids.clone(),
         }
     }
 }
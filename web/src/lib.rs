@@ -2,6 +2,7 @@ pub mod api;
 pub mod config;
 pub mod error;
 pub mod job;
+pub mod media;
 pub mod storage;
 pub mod ws;
 
@@ -8,22 +8,26 @@ use tracing_subscriber::EnvFilter;
 
 use gpop_web::api::configure_routes;
 use gpop_web::config::Config;
-use gpop_web::job::{start_event_handler, start_progress_poller, JobManager};
+use gpop_web::job::{
+    start_event_handler, start_progress_poller, start_reaper, start_retention_sweeper, JobManager,
+};
 use gpop_web::storage::StorageManager;
-use gpop_web::ws::{handle_client_websocket, GpopConnection, ProgressBroadcaster};
+use gpop_web::ws::{handle_client_websocket, GpopConnection, ProgressBroadcaster, ProgressQuery};
 
 /// WebSocket endpoint handler for browser clients
 async fn ws_progress(
     req: HttpRequest,
     stream: web::Payload,
+    query: web::Query<ProgressQuery>,
     broadcaster: web::Data<Arc<ProgressBroadcaster>>,
 ) -> actix_web::Result<HttpResponse> {
-    let (res, session, _msg_stream) = actix_ws::handle(&req, stream)?;
+    let (res, session, msg_stream) = actix_ws::handle(&req, stream)?;
+    let binary = query.wants_binary();
 
     // Spawn handler task
     let broadcaster = Arc::clone(broadcaster.get_ref());
     actix_web::rt::spawn(async move {
-        handle_client_websocket(session, broadcaster).await;
+        handle_client_websocket(session, msg_stream, broadcaster, binary).await;
     });
 
     Ok(res)
@@ -86,6 +90,10 @@ async fn main() -> std::io::Result<()> {
         config.clone(),
     ));
 
+    // Restore any jobs persisted by a previous run before accepting new
+    // work, so in-flight/queued jobs survive a restart.
+    job_manager.recover().await;
+
     // Start event handler
     let manager_clone = Arc::clone(&job_manager);
     let gpop_clone = Arc::clone(&gpop);
@@ -99,6 +107,30 @@ async fn main() -> std::io::Result<()> {
         start_progress_poller(manager_clone, Duration::from_millis(500)).await;
     });
 
+    // Start the expired-job reaper (every minute)
+    let manager_clone = Arc::clone(&job_manager);
+    tokio::spawn(async move {
+        start_reaper(manager_clone, Duration::from_secs(60)).await;
+    });
+
+    // Start the retention sweeper for orphaned uploads/outputs (every 15
+    // minutes - retention_hours is coarse-grained enough that this doesn't
+    // need the reaper's tighter cadence)
+    let manager_clone = Arc::clone(&job_manager);
+    tokio::spawn(async move {
+        start_retention_sweeper(manager_clone, Duration::from_secs(15 * 60)).await;
+    });
+
+    // Ask the retention sweeper to stop between ticks on ctrl-c instead of
+    // being killed mid-scan; the other background loops don't yet have an
+    // equivalent shutdown path.
+    let manager_clone = Arc::clone(&job_manager);
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            manager_clone.shutdown();
+        }
+    });
+
     // Get bind address
     let bind_addr = format!("{}:{}", config.host, config.port);
 
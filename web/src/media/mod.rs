@@ -0,0 +1,3 @@
+pub mod probe;
+
+pub use probe::{probe_file, MediaDetails, StreamDetails, TrackTags};
@@ -0,0 +1,232 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+use tokio::process::Command;
+
+use crate::error::{AppError, Result};
+
+/// Structured metadata about a media file, extracted by shelling out to
+/// `ffprobe` and parsing its JSON output. Mirrors how pict-rs derives a
+/// `Details` struct from ffmpeg/exiftool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaDetails {
+    /// Container format, e.g. "mov,mp4,m4a,3gp,3g2,mj2"
+    pub format_name: String,
+    pub duration_secs: Option<f64>,
+    pub bitrate_bps: Option<u64>,
+    pub streams: Vec<StreamDetails>,
+    /// Track metadata ffprobe found in the container's `format.tags`, so a
+    /// transcode can carry it through instead of dropping it by default.
+    #[serde(default)]
+    pub tags: TrackTags,
+}
+
+/// Library-style track metadata, read from `ffprobe`'s `format.tags` and/or
+/// supplied by the caller when creating a transcode job. Field names match
+/// the common ground between ID3v2, Vorbis comments and MP4/iTunes atoms;
+/// `TranscodeOptions::resolved_tags` merges them and `write_tags` writes them
+/// in the actual target container's tag format.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrackTags {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub track: Option<u32>,
+    pub year: Option<i32>,
+    /// Cover art image bytes (JPEG/PNG), base64-encoded over the wire.
+    #[serde(default, with = "cover_art_base64")]
+    pub cover_art: Option<Vec<u8>>,
+}
+
+impl TrackTags {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.artist.is_none()
+            && self.album.is_none()
+            && self.track.is_none()
+            && self.year.is_none()
+            && self.cover_art.is_none()
+    }
+
+    /// Fill in any field left unset here from `probed` (tags ffprobe read
+    /// off the input), so converting formats doesn't drop existing metadata
+    /// just because the caller didn't repeat it explicitly.
+    pub fn merged_with_probe(&self, probed: &TrackTags) -> TrackTags {
+        TrackTags {
+            title: self.title.clone().or_else(|| probed.title.clone()),
+            artist: self.artist.clone().or_else(|| probed.artist.clone()),
+            album: self.album.clone().or_else(|| probed.album.clone()),
+            track: self.track.or(probed.track),
+            year: self.year.or(probed.year),
+            cover_art: self.cover_art.clone().or_else(|| probed.cover_art.clone()),
+        }
+    }
+}
+
+mod cover_art_base64 {
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<Vec<u8>>, ser: S) -> Result<S::Ok, S::Error> {
+        value
+            .as_ref()
+            .map(|bytes| base64::engine::general_purpose::STANDARD.encode(bytes))
+            .serialize(ser)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<Option<Vec<u8>>, D::Error> {
+        let encoded: Option<String> = Option::deserialize(de)?;
+        encoded
+            .map(|s| {
+                base64::engine::general_purpose::STANDARD
+                    .decode(s)
+                    .map_err(serde::de::Error::custom)
+            })
+            .transpose()
+    }
+}
+
+/// Metadata for a single stream (video, audio, ...) within a media file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamDetails {
+    /// "video", "audio", "subtitle", ...
+    pub codec_type: String,
+    pub codec_name: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub sample_rate_hz: Option<u32>,
+    pub channels: Option<u32>,
+    pub bitrate_bps: Option<u64>,
+}
+
+impl MediaDetails {
+    /// Duration in nanoseconds, for the progress-reporting denominator.
+    pub fn duration_ns(&self) -> Option<u64> {
+        self.duration_secs.map(|secs| (secs * 1_000_000_000.0) as u64)
+    }
+
+    /// Whether any stream of `codec_type` ("video", "audio", ...) is present.
+    pub fn has_stream_type(&self, codec_type: &str) -> bool {
+        self.streams.iter().any(|s| s.codec_type == codec_type)
+    }
+
+    pub fn has_video(&self) -> bool {
+        self.has_stream_type("video")
+    }
+
+    pub fn has_audio(&self) -> bool {
+        self.has_stream_type("audio")
+    }
+}
+
+/// Probe a media file with `ffprobe`, returning its container and stream
+/// metadata.
+pub async fn probe_file(path: &Path) -> Result<MediaDetails> {
+    let output = Command::new("ffprobe")
+        .args(["-v", "quiet", "-print_format", "json", "-show_format", "-show_streams"])
+        .arg(path)
+        .output()
+        .await
+        .map_err(|e| AppError::Probe(format!("Failed to run ffprobe: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(AppError::Probe(format!(
+            "ffprobe exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    let json: Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| AppError::Probe(format!("Failed to parse ffprobe output: {}", e)))?;
+
+    parse_probe_json(&json)
+}
+
+fn parse_probe_json(json: &Value) -> Result<MediaDetails> {
+    let format = json
+        .get("format")
+        .ok_or_else(|| AppError::Probe("ffprobe output missing 'format'".to_string()))?;
+
+    let format_name = format
+        .get("format_name")
+        .and_then(Value::as_str)
+        .unwrap_or("unknown")
+        .to_string();
+
+    let duration_secs = format
+        .get("duration")
+        .and_then(Value::as_str)
+        .and_then(|s| s.parse::<f64>().ok());
+
+    let bitrate_bps = format
+        .get("bit_rate")
+        .and_then(Value::as_str)
+        .and_then(|s| s.parse::<u64>().ok());
+
+    let streams = json
+        .get("streams")
+        .and_then(Value::as_array)
+        .map(|streams| streams.iter().map(parse_stream).collect())
+        .unwrap_or_default();
+
+    let tags = format.get("tags").map(parse_tags).unwrap_or_default();
+
+    Ok(MediaDetails {
+        format_name,
+        duration_secs,
+        bitrate_bps,
+        streams,
+        tags,
+    })
+}
+
+/// Parse the subset of `format.tags` we carry through to a transcode. Key
+/// casing varies by container (`TRACK`/`track`, `date`/`DATE`/`year`), so we
+/// check both; ffprobe doesn't surface embedded cover art here, only the
+/// stream it's attached to, so `cover_art` stays unset.
+fn parse_tags(tags: &Value) -> TrackTags {
+    let get = |keys: &[&str]| -> Option<String> {
+        keys.iter()
+            .find_map(|k| tags.get(k).and_then(Value::as_str))
+            .map(str::to_string)
+    };
+
+    TrackTags {
+        title: get(&["title", "TITLE"]),
+        artist: get(&["artist", "ARTIST"]),
+        album: get(&["album", "ALBUM"]),
+        track: get(&["track", "TRACK"])
+            .and_then(|t| t.split('/').next().map(str::to_string))
+            .and_then(|t| t.parse().ok()),
+        year: get(&["date", "DATE", "year", "YEAR"])
+            .and_then(|d| d.get(0..4).and_then(|y| y.parse().ok())),
+        cover_art: None,
+    }
+}
+
+fn parse_stream(stream: &Value) -> StreamDetails {
+    StreamDetails {
+        codec_type: stream
+            .get("codec_type")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown")
+            .to_string(),
+        codec_name: stream
+            .get("codec_name")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown")
+            .to_string(),
+        width: stream.get("width").and_then(Value::as_u64).map(|n| n as u32),
+        height: stream.get("height").and_then(Value::as_u64).map(|n| n as u32),
+        sample_rate_hz: stream
+            .get("sample_rate")
+            .and_then(Value::as_str)
+            .and_then(|s| s.parse().ok()),
+        channels: stream.get("channels").and_then(Value::as_u64).map(|n| n as u32),
+        bitrate_bps: stream
+            .get("bit_rate")
+            .and_then(Value::as_str)
+            .and_then(|s| s.parse().ok()),
+    }
+}
@@ -0,0 +1,201 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use tracing::{debug, info};
+
+use crate::config::Config;
+use crate::error::{AppError, Result};
+
+/// Where a published output object can be fetched from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum OutputLocation {
+    /// Served directly from local disk by this process.
+    Local(PathBuf),
+    /// Available at a (possibly pre-signed, possibly temporary) URL on a
+    /// remote object store; downloads should redirect there instead of
+    /// streaming the bytes through this process.
+    Remote(String),
+}
+
+/// Destination for completed job outputs. GStreamer's filesink always
+/// writes pipeline output to local disk first, so every backend receives a
+/// finished local file and decides where it should ultimately live.
+#[async_trait]
+pub trait OutputBackend: Send + Sync {
+    /// Publish a locally-written output file under `key`, returning where it
+    /// can now be fetched from.
+    async fn publish(&self, local_path: &Path, key: &str) -> Result<OutputLocation>;
+
+    /// Remove a previously published output.
+    async fn remove(&self, key: &str) -> Result<()>;
+
+    /// Size in bytes of a previously published output under `key`, or `None`
+    /// if it doesn't exist.
+    async fn head(&self, key: &str) -> Result<Option<u64>>;
+}
+
+/// Default backend: outputs already live where `StorageManager` wrote them,
+/// so publishing is a no-op and removal just deletes the local file.
+/// Keys are resolved relative to `outputs_dir`.
+pub struct LocalBackend {
+    outputs_dir: PathBuf,
+}
+
+#[async_trait]
+impl OutputBackend for LocalBackend {
+    async fn publish(&self, local_path: &Path, _key: &str) -> Result<OutputLocation> {
+        Ok(OutputLocation::Local(local_path.to_path_buf()))
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        let path = self.outputs_dir.join(key);
+        if fs::metadata(&path).await.is_ok() {
+            fs::remove_file(&path)
+                .await
+                .map_err(|e| AppError::Storage(format!("Failed to remove output: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<u64>> {
+        Ok(fs::metadata(self.outputs_dir.join(key)).await.ok().map(|m| m.len()))
+    }
+}
+
+/// S3-compatible object store backend. Works against AWS S3 or any
+/// S3-compatible service (MinIO, R2, ...) by pointing `s3_endpoint` at it.
+/// Uploads the finished local file and hands back a time-limited pre-signed
+/// GET URL rather than serving the bytes through this process.
+pub struct S3Backend {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+    prefix: String,
+    presign_seconds: u64,
+}
+
+impl S3Backend {
+    pub async fn new(config: &Config) -> Result<Self> {
+        let bucket = config
+            .s3_bucket
+            .clone()
+            .ok_or_else(|| AppError::Storage("s3 backend requires --s3-bucket".to_string()))?;
+
+        let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(aws_sdk_s3::config::Region::new(config.s3_region.clone()));
+        if let Some(endpoint) = &config.s3_endpoint {
+            loader = loader.endpoint_url(endpoint.clone());
+        }
+        let sdk_config = loader.load().await;
+        let client = aws_sdk_s3::Client::new(&sdk_config);
+
+        info!(
+            "S3 output backend configured: bucket={} region={} prefix={}",
+            bucket, config.s3_region, config.s3_prefix
+        );
+
+        Ok(Self {
+            client,
+            bucket,
+            prefix: config.s3_prefix.clone(),
+            presign_seconds: config.s3_presign_seconds,
+        })
+    }
+
+    fn object_key(&self, key: &str) -> String {
+        if self.prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}/{}", self.prefix.trim_end_matches('/'), key)
+        }
+    }
+}
+
+#[async_trait]
+impl OutputBackend for S3Backend {
+    async fn publish(&self, local_path: &Path, key: &str) -> Result<OutputLocation> {
+        let object_key = self.object_key(key);
+        let body = aws_sdk_s3::primitives::ByteStream::from_path(local_path)
+            .await
+            .map_err(|e| AppError::Storage(format!("Failed to read output for upload: {}", e)))?;
+
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(&object_key)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| AppError::Storage(format!("S3 upload failed: {}", e)))?;
+
+        // The object now lives in the bucket; the local copy was only ever
+        // an intermediate for the pipeline's filesink.
+        let _ = fs::remove_file(local_path).await;
+        debug!("Published {} to s3://{}/{}", local_path.display(), self.bucket, object_key);
+
+        let presign_config = aws_sdk_s3::presigning::PresigningConfig::expires_in(
+            std::time::Duration::from_secs(self.presign_seconds),
+        )
+        .map_err(|e| AppError::Storage(format!("Invalid presign duration: {}", e)))?;
+
+        let presigned = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(&object_key)
+            .presigned(presign_config)
+            .await
+            .map_err(|e| AppError::Storage(format!("Failed to presign download URL: {}", e)))?;
+
+        Ok(OutputLocation::Remote(presigned.uri().to_string()))
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        let object_key = self.object_key(key);
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(&object_key)
+            .send()
+            .await
+            .map_err(|e| AppError::Storage(format!("S3 delete failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<u64>> {
+        let object_key = self.object_key(key);
+        match self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(&object_key)
+            .send()
+            .await
+        {
+            Ok(output) => Ok(output.content_length().map(|len| len as u64)),
+            Err(e) => {
+                if e.as_service_error().map(|se| se.is_not_found()) == Some(true) {
+                    Ok(None)
+                } else {
+                    Err(AppError::Storage(format!("S3 head failed: {}", e)))
+                }
+            }
+        }
+    }
+}
+
+/// Build the configured output backend.
+pub async fn build_backend(config: &Config) -> Result<Arc<dyn OutputBackend>> {
+    match config.storage_backend.as_str() {
+        "local" => Ok(Arc::new(LocalBackend {
+            outputs_dir: config.outputs_dir(),
+        })),
+        "s3" => Ok(Arc::new(S3Backend::new(config).await?)),
+        other => Err(AppError::Storage(format!(
+            "Unknown storage backend '{}', expected 'local' or 's3'",
+            other
+        ))),
+    }
+}
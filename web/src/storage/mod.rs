@@ -1,21 +1,56 @@
+use dashmap::DashMap;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tokio::fs;
 use tracing::{debug, info, warn};
 
 use crate::config::Config;
 use crate::error::{AppError, Result};
 
+pub mod backend;
+
+pub use backend::OutputLocation;
+use backend::OutputBackend;
+
 /// Storage manager for uploaded files and transcoded outputs
 pub struct StorageManager {
     uploads_dir: PathBuf,
     outputs_dir: PathBuf,
+    /// Where `JobManager` persists a JSON snapshot of each job, so job
+    /// history and in-flight state survive a restart.
+    jobs_dir: PathBuf,
+    /// Where finished outputs ultimately get published (local disk, S3, ...).
+    /// GStreamer always writes to `outputs_dir` first; the backend decides
+    /// what happens to the file after the job completes.
+    backend: Arc<dyn OutputBackend>,
+    /// Reference count per content hash for deduplicated upload blobs (see
+    /// `finalize_content_addressed_upload`), so a blob shared by several
+    /// jobs isn't deleted until the last one referencing it is cleaned up.
+    upload_refs: DashMap<String, usize>,
+    /// Content hash recorded for each job that finalized its upload into
+    /// content-addressed storage, consulted by `cleanup_job` to decrement
+    /// the matching `upload_refs` entry.
+    job_upload_hashes: DashMap<String, String>,
+    /// Entries `sweep_retention` has recently confirmed aren't expired yet,
+    /// so the next tick can skip re-`stat`ing them instead of walking the
+    /// whole tree from scratch every time. Stale entries age out of this
+    /// cache on their own (see `SWEEP_RECHECK_TTL`): each `sweep_retention`
+    /// pass first drops every entry older than that.
+    recently_swept: DashMap<PathBuf, std::time::Instant>,
 }
 
+/// How long `sweep_retention` trusts a previous "not expired yet" result for
+/// a given path before re-`stat`ing it. Short relative to any sane
+/// `retention_hours` value, so it only saves work between ticks of the same
+/// sweep interval rather than meaningfully delaying a real cleanup.
+const SWEEP_RECHECK_TTL: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
 impl StorageManager {
     /// Create a new storage manager and ensure directories exist
     pub async fn new(config: &Config) -> Result<Self> {
         let uploads_dir = config.uploads_dir();
         let outputs_dir = config.outputs_dir();
+        let jobs_dir = config.jobs_dir();
 
         fs::create_dir_all(&uploads_dir)
             .await
@@ -25,12 +60,23 @@ impl StorageManager {
             .await
             .map_err(|e| AppError::Storage(format!("Failed to create outputs dir: {}", e)))?;
 
-        info!("Storage initialized: uploads={}, outputs={}",
-              uploads_dir.display(), outputs_dir.display());
+        fs::create_dir_all(&jobs_dir)
+            .await
+            .map_err(|e| AppError::Storage(format!("Failed to create jobs dir: {}", e)))?;
+
+        info!("Storage initialized: uploads={}, outputs={}, jobs={}",
+              uploads_dir.display(), outputs_dir.display(), jobs_dir.display());
+
+        let backend = backend::build_backend(config).await?;
 
         Ok(Self {
             uploads_dir,
             outputs_dir,
+            jobs_dir,
+            backend,
+            upload_refs: DashMap::new(),
+            job_upload_hashes: DashMap::new(),
+            recently_swept: DashMap::new(),
         })
     }
 
@@ -53,13 +99,20 @@ impl StorageManager {
         Ok(dir)
     }
 
-    /// Store an uploaded file for a job
-    pub async fn store_upload(
-        &self,
-        job_id: &str,
-        filename: &str,
-        data: &[u8],
-    ) -> Result<PathBuf> {
+    /// Get the output directory for thumbnail frames/sprite
+    pub async fn job_thumbnail_output_dir(&self, job_id: &str) -> Result<PathBuf> {
+        let dir = self.outputs_dir.join(format!("{}_thumbnails", job_id));
+        fs::create_dir_all(&dir).await.map_err(|e| {
+            AppError::Storage(format!("Failed to create thumbnail output dir: {}", e))
+        })?;
+        Ok(dir)
+    }
+
+    /// Open a file for a streamed upload, creating the job's upload directory
+    /// first. Returns the destination path together with an open handle so
+    /// the caller can write chunks as they arrive from the multipart stream
+    /// instead of buffering the whole file in memory first.
+    pub async fn begin_upload(&self, job_id: &str, filename: &str) -> Result<(PathBuf, fs::File)> {
         let job_dir = self.job_upload_dir(job_id);
         fs::create_dir_all(&job_dir)
             .await
@@ -69,28 +122,146 @@ impl StorageManager {
         let safe_filename = sanitize_filename::sanitize(filename);
         let path = job_dir.join(&safe_filename);
 
-        fs::write(&path, data)
+        let file = fs::File::create(&path)
             .await
-            .map_err(|e| AppError::Storage(format!("Failed to write file: {}", e)))?;
+            .map_err(|e| AppError::Storage(format!("Failed to create upload file: {}", e)))?;
 
-        debug!("Stored upload: {} ({} bytes)", path.display(), data.len());
+        debug!("Streaming upload to: {}", path.display());
+
+        Ok((path, file))
+    }
 
-        Ok(path)
+    /// Path a deduplicated upload blob is stored under, sharded two levels
+    /// deep by the start of its hash so `uploads_dir/cas` doesn't end up
+    /// with one entry per upload ever received in a single directory.
+    fn cas_upload_path(&self, hash: &str) -> PathBuf {
+        let a = &hash[..hash.len().min(2)];
+        let b = &hash[hash.len().min(2)..hash.len().min(4)];
+        self.uploads_dir.join("cas").join(a).join(b).join(hash)
+    }
+
+    /// Create the blob's CAS directory (if needed) and move `job_path` into
+    /// it at `blob_path`. Split out of `finalize_content_addressed_upload`
+    /// so its caller can roll back the refcount entry it just claimed if
+    /// either step fails.
+    async fn store_cas_blob(&self, job_path: &Path, blob_path: &Path) -> Result<()> {
+        if let Some(parent) = blob_path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|e| AppError::Storage(format!("Failed to create CAS dir: {}", e)))?;
+        }
+        fs::rename(job_path, blob_path)
+            .await
+            .map_err(|e| AppError::Storage(format!("Failed to store upload blob: {}", e)))
+    }
+
+    /// Move a just-streamed upload at `job_path` into content-addressed
+    /// storage keyed by `hash` (its BLAKE3 digest, computed incrementally by
+    /// the caller while writing it). If another job already uploaded the
+    /// same content, `job_path` is discarded and the existing blob's
+    /// refcount is bumped instead of storing the bytes twice; otherwise
+    /// `job_path` becomes the first copy. Returns the blob's final path, to
+    /// use in place of `job_path` from here on. `job_id` is recorded against
+    /// `hash` so `cleanup_job` can release this reference later.
+    pub async fn finalize_content_addressed_upload(
+        &self,
+        job_id: &str,
+        job_path: &Path,
+        hash: &str,
+    ) -> Result<PathBuf> {
+        let blob_path = self.cas_upload_path(hash);
+
+        // Atomically check-and-increment so two concurrent uploads of the
+        // same content can't both observe "no existing blob" and both try
+        // to claim `blob_path`, which would leave the refcount at 1 while
+        // two jobs actually hold a reference to it.
+        let count_after = *self
+            .upload_refs
+            .entry(hash.to_string())
+            .and_modify(|c| *c += 1)
+            .or_insert(1);
+
+        if count_after > 1 {
+            fs::remove_file(job_path)
+                .await
+                .map_err(|e| AppError::Storage(format!("Failed to discard duplicate upload: {}", e)))?;
+            debug!(
+                "Deduplicated upload for job {}: reusing blob {}",
+                job_id,
+                blob_path.display()
+            );
+        } else if let Err(e) = self.store_cas_blob(job_path, &blob_path).await {
+            // We were the one who just claimed this hash's refcount slot;
+            // since the blob never actually got created, undo that claim so
+            // a later upload of the same content doesn't see `count_after >
+            // 1`, assume a blob already exists, and discard its own bytes
+            // against a path nothing ever wrote to.
+            self.upload_refs.remove(hash);
+            return Err(e);
+        }
+
+        self.job_upload_hashes
+            .insert(job_id.to_string(), hash.to_string());
+        Ok(blob_path)
+    }
+
+    /// Release `job_id`'s reference to its content-addressed upload blob (if
+    /// it has one), deleting the blob once no job references it anymore.
+    /// Called from `cleanup_job`.
+    async fn release_content_addressed_upload(&self, job_id: &str) {
+        let Some((_, hash)) = self.job_upload_hashes.remove(job_id) else {
+            return;
+        };
+
+        let remaining = match self.upload_refs.get_mut(&hash) {
+            Some(mut count) => {
+                *count = count.saturating_sub(1);
+                *count
+            }
+            None => return,
+        };
+
+        if remaining == 0 {
+            self.upload_refs.remove(&hash);
+            let blob_path = self.cas_upload_path(&hash);
+            if let Err(e) = fs::remove_file(&blob_path).await {
+                warn!(
+                    "Failed to remove deduplicated upload blob {}: {}",
+                    blob_path.display(),
+                    e
+                );
+            }
+        }
     }
 
-    /// Check if an output file exists
+    /// Check if a published output exists, via the configured backend rather
+    /// than assuming it's still sitting on local disk.
     pub async fn output_exists(&self, job_id: &str, extension: &str) -> bool {
-        let path = self.job_output_path(job_id, extension);
-        fs::metadata(&path).await.is_ok()
+        let key = format!("{}.{}", job_id, extension);
+        matches!(self.backend.head(&key).await, Ok(Some(_)))
     }
 
-    /// Get the size of an output file
+    /// Get the size of a published output, via the configured backend.
     pub async fn output_size(&self, job_id: &str, extension: &str) -> Result<u64> {
-        let path = self.job_output_path(job_id, extension);
-        let metadata = fs::metadata(&path)
-            .await
-            .map_err(|_| AppError::FileNotFound(path.display().to_string()))?;
-        Ok(metadata.len())
+        let key = format!("{}.{}", job_id, extension);
+        self.backend
+            .head(&key)
+            .await?
+            .ok_or_else(|| AppError::FileNotFound(key))
+    }
+
+    /// Publish a finished output file (written locally by the pipeline's
+    /// filesink) to the configured backend, returning where it can now be
+    /// downloaded from.
+    pub async fn publish_output(&self, local_path: &Path, key: &str) -> Result<OutputLocation> {
+        self.backend.publish(local_path, key).await
+    }
+
+    /// Remove a previously published output from the backend (the S3
+    /// backend's `remove` deletes the object; the local backend's `remove`
+    /// deletes the file if it still exists under the given key/path).
+    pub async fn remove_published_output(&self, key: &str) -> Result<()> {
+        self.backend.remove(key).await
     }
 
     /// Clean up files for a job
@@ -104,6 +275,8 @@ impl StorageManager {
             debug!("Cleaned up upload dir: {}", upload_dir.display());
         }
 
+        self.release_content_addressed_upload(job_id).await;
+
         Ok(())
     }
 
@@ -133,35 +306,149 @@ impl StorageManager {
         Ok(())
     }
 
+    /// Clean up thumbnail output directory for a job
+    pub async fn cleanup_thumbnail_output(&self, job_id: &str) -> Result<()> {
+        let output_dir = self.outputs_dir.join(format!("{}_thumbnails", job_id));
+        if fs::metadata(&output_dir).await.is_ok() {
+            fs::remove_dir_all(&output_dir).await.map_err(|e| {
+                AppError::Storage(format!("Failed to remove thumbnail output dir: {}", e))
+            })?;
+            debug!("Cleaned up thumbnail output dir: {}", output_dir.display());
+        }
+
+        Ok(())
+    }
+
+    /// Get the path to the uploads directory
+    pub fn uploads_dir(&self) -> &Path {
+        &self.uploads_dir
+    }
+
     /// Get the path to the outputs directory (for serving files)
     pub fn outputs_dir(&self) -> &Path {
         &self.outputs_dir
     }
+
+    /// Get the path to the persisted job records directory
+    pub fn jobs_dir(&self) -> &Path {
+        &self.jobs_dir
+    }
 }
 
-/// Clean up old files based on retention policy
-pub async fn cleanup_old_files(storage: &StorageManager, retention_hours: u64) {
+/// Outcome of a single `sweep_retention` pass, for the caller to log.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RetentionSweepStats {
+    pub files_removed: u64,
+    pub bytes_reclaimed: u64,
+    pub scan_duration: std::time::Duration,
+}
+
+/// Top-level entry under `uploads_dir` that holds content-addressed upload
+/// blobs (see `StorageManager::cas_upload_path`). Its contents are
+/// refcounted by `finalize_content_addressed_upload`/
+/// `release_content_addressed_upload`, not by mtime, so the sweep must never
+/// touch it directly.
+const CAS_DIR_NAME: &str = "cas";
+
+/// Sweep `uploads_dir`/`outputs_dir` for entries older than `retention_hours`,
+/// per `Config::retention_hours`'s "keep forever" semantics (`0` disables
+/// the sweep entirely). Entries are a mix of flat files (`{job_id}.{ext}`)
+/// and per-job directories (`job_upload_dir`, `{job_id}_stems`,
+/// `{job_id}_thumbnails`), so a directory is removed recursively rather
+/// than skipped. `uploads_dir/cas` is skipped entirely: those blobs are
+/// reference-counted, not mtime-based, and are only ever removed via
+/// `StorageManager::release_content_addressed_upload`.
+///
+/// `exempt_job_ids` is normally every job id the `JobManager` still has a
+/// record for: this is a coarser backstop than that per-job `keep_for` TTL,
+/// meant to catch orphaned uploads/outputs whose job record is already
+/// gone, not to race ahead of a still-tracked job's own deadline.
+pub async fn sweep_retention(
+    storage: &StorageManager,
+    retention_hours: u64,
+    exempt_job_ids: &std::collections::HashSet<String>,
+) -> RetentionSweepStats {
     if retention_hours == 0 {
-        return; // Keep forever
+        return RetentionSweepStats::default(); // Keep forever
     }
 
+    let started = std::time::Instant::now();
     let cutoff = std::time::SystemTime::now()
         - std::time::Duration::from_secs(retention_hours * 3600);
 
-    // Clean up old output files
-    if let Ok(mut entries) = fs::read_dir(storage.outputs_dir()).await {
-        while let Ok(Some(entry)) = entries.next_entry().await {
-            if let Ok(metadata) = entry.metadata().await {
-                if let Ok(modified) = metadata.modified() {
-                    if modified < cutoff {
-                        if let Err(e) = fs::remove_file(entry.path()).await {
-                            warn!("Failed to remove old file {}: {}", entry.path().display(), e);
-                        } else {
-                            info!("Cleaned up old file: {}", entry.path().display());
-                        }
-                    }
-                }
+    let mut stats = RetentionSweepStats::default();
+    storage
+        .recently_swept
+        .retain(|_, checked_at| checked_at.elapsed() < SWEEP_RECHECK_TTL);
+    for dir in [storage.uploads_dir(), storage.outputs_dir()] {
+        sweep_dir(dir, cutoff, exempt_job_ids, &storage.recently_swept, &mut stats).await;
+    }
+    stats.scan_duration = started.elapsed();
+    stats
+}
+
+/// Job id an entry under `uploads_dir`/`outputs_dir` belongs to: the part of
+/// its file name before the first `.` or `_`, covering `{job_id}.{ext}`,
+/// `{job_id}_stems`, `{job_id}_thumbnails`, and a bare `{job_id}` upload
+/// directory alike, since job ids are UUIDs and so never contain either.
+fn owning_job_id(name: &str) -> &str {
+    name.split(['.', '_']).next().unwrap_or(name)
+}
+
+async fn sweep_dir(
+    dir: &Path,
+    cutoff: std::time::SystemTime,
+    exempt_job_ids: &std::collections::HashSet<String>,
+    recently_swept: &DashMap<PathBuf, std::time::Instant>,
+    stats: &mut RetentionSweepStats,
+) {
+    let Ok(mut entries) = fs::read_dir(dir).await else {
+        return;
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name == CAS_DIR_NAME {
+            continue;
+        }
+        if exempt_job_ids.contains(owning_job_id(&name)) {
+            continue;
+        }
+
+        let path = entry.path();
+        if let Some(checked_at) = recently_swept.get(&path) {
+            if checked_at.elapsed() < SWEEP_RECHECK_TTL {
+                continue;
+            }
+        }
+
+        let Ok(metadata) = entry.metadata().await else {
+            continue;
+        };
+        let Ok(modified) = metadata.modified() else {
+            continue;
+        };
+        if modified >= cutoff {
+            recently_swept.insert(path, std::time::Instant::now());
+            continue;
+        }
+
+        let size = metadata.len();
+        let result = if metadata.is_dir() {
+            fs::remove_dir_all(&path).await
+        } else {
+            fs::remove_file(&path).await
+        };
+
+        match result {
+            Ok(()) => {
+                info!("Cleaned up expired {}: {}", dir.display(), path.display());
+                stats.files_removed += 1;
+                stats.bytes_reclaimed += size;
+                recently_swept.remove(&path);
             }
+            Err(e) => warn!("Failed to remove expired {}: {}", path.display(), e),
         }
     }
 }
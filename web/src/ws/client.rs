@@ -1,13 +1,51 @@
-use actix_ws::Session;
-use serde::Serialize;
+use actix_ws::{Message as WsMessage, MessageStream, Session};
+use dashmap::DashMap;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::broadcast;
 use tracing::{debug, warn};
 
+/// How often the server pings an idle client to keep the connection alive
+/// through proxies that drop silent sockets.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long the server waits for a pong before treating a client as dead.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a job's last known state stays in the replay cache after a
+/// terminal (`JobCompleted`/`DemucsCompleted`/`JobFailed`) message, so a client reconnecting
+/// moments after completion still catches it. Past this the entry is
+/// evicted so the cache doesn't grow unbounded with finished jobs.
+const TERMINAL_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Query string accepted on `GET /ws/progress`, used to negotiate a binary
+/// encoding for machine consumers that would rather not pay JSON's parsing
+/// cost (e.g. `/ws/progress?encoding=cbor`). Anything other than `"cbor"`,
+/// including the parameter being absent, keeps the default JSON framing.
+#[derive(Debug, Deserialize)]
+pub struct ProgressQuery {
+    encoding: Option<String>,
+}
+
+impl ProgressQuery {
+    pub fn wants_binary(&self) -> bool {
+        self.encoding.as_deref() == Some("cbor")
+    }
+}
+
 /// Progress message sent to browser clients
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type")]
 pub enum ProgressMessage {
+    /// The job-type concurrency gate was saturated, so the job was pushed
+    /// onto the pending queue instead of starting immediately. Re-sent with
+    /// an updated `position` as jobs ahead of it drain.
+    #[serde(rename = "job_queued")]
+    JobQueued { job_id: String, position: usize },
+
     #[serde(rename = "job_started")]
     JobStarted { job_id: String },
 
@@ -28,37 +66,180 @@ pub enum ProgressMessage {
     #[serde(rename = "job_failed")]
     JobFailed { job_id: String, error: String },
 
+    /// A transient failure is being retried after a backoff delay instead
+    /// of being given up on.
+    #[serde(rename = "job_retrying")]
+    JobRetrying {
+        job_id: String,
+        attempt: u32,
+        delay_ms: u64,
+    },
+
     #[serde(rename = "state_changed")]
     StateChanged {
         job_id: String,
         old_state: String,
         new_state: String,
     },
+
+    /// The job's pipeline was paused via `JobManager::pause_job`, retaining
+    /// its position.
+    #[serde(rename = "job_paused")]
+    JobPaused { job_id: String },
+
+    /// A previously-paused job's pipeline was driven back to PLAYING via
+    /// `JobManager::resume_job`.
+    #[serde(rename = "job_resumed")]
+    JobResumed { job_id: String },
+
+    /// The progress-poller watchdog found a `Processing` job whose position
+    /// hasn't advanced in over `Config::stall_timeout_secs` - the pipeline
+    /// may be wedged.
+    #[serde(rename = "job_stalled")]
+    JobStalled { job_id: String, stalled_for_ms: u64 },
+
+    /// A sample from the daemon's per-pipeline element/property statistics
+    /// poller, relayed verbatim (see `collect_pipeline_stats` on the daemon
+    /// side for its shape).
+    #[serde(rename = "stats")]
+    Stats {
+        job_id: String,
+        stats: serde_json::Value,
+    },
+}
+
+impl ProgressMessage {
+    fn job_id(&self) -> &str {
+        match self {
+            ProgressMessage::JobQueued { job_id, .. }
+            | ProgressMessage::JobStarted { job_id }
+            | ProgressMessage::Progress { job_id, .. }
+            | ProgressMessage::JobCompleted { job_id, .. }
+            | ProgressMessage::DemucsCompleted { job_id }
+            | ProgressMessage::JobFailed { job_id, .. }
+            | ProgressMessage::JobRetrying { job_id, .. }
+            | ProgressMessage::StateChanged { job_id, .. }
+            | ProgressMessage::JobStalled { job_id, .. }
+            | ProgressMessage::JobPaused { job_id }
+            | ProgressMessage::JobResumed { job_id }
+            | ProgressMessage::Stats { job_id, .. } => job_id,
+        }
+    }
+
+    /// Whether this message ends a job's lifecycle, and so should only
+    /// linger in the replay cache for `TERMINAL_CACHE_TTL` instead of until
+    /// the next message for that job (which will never come).
+    fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ProgressMessage::JobCompleted { .. }
+                | ProgressMessage::DemucsCompleted { .. }
+                | ProgressMessage::JobFailed { .. }
+        )
+    }
+}
+
+/// A broadcast `ProgressMessage` stamped with a monotonically increasing
+/// sequence number, so a reconnecting client can ask for everything it
+/// missed with `resume_from`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgressFrame {
+    pub seq: u64,
+    #[serde(flatten)]
+    pub message: ProgressMessage,
+}
+
+/// Resume handshake a client may send immediately after connecting, as
+/// either a JSON text frame (`?encoding` unset) or a CBOR binary frame
+/// (`?encoding=cbor`), to catch up on messages it missed instead of only
+/// seeing whatever is broadcast from here on. `resume_from` replays
+/// everything stamped after that sequence number; `last_job_states` (for a
+/// client with no sequence number to resume from, e.g. a fresh page load)
+/// replays just the latest cached message per job.
+#[derive(Debug, Deserialize)]
+struct ResumeHandshake {
+    #[serde(default)]
+    resume_from: Option<u64>,
+    #[serde(default)]
+    last_job_states: bool,
+}
+
+impl ResumeHandshake {
+    fn requests_replay(&self) -> bool {
+        self.resume_from.is_some() || self.last_job_states
+    }
 }
 
 /// Broadcaster for progress messages to all connected browser clients
 #[derive(Clone)]
 pub struct ProgressBroadcaster {
-    tx: broadcast::Sender<ProgressMessage>,
+    tx: broadcast::Sender<ProgressFrame>,
+    next_seq: Arc<AtomicU64>,
+    /// Latest frame per job_id, so a client that reconnects after a lag or
+    /// socket drop can be caught up instead of permanently missing whatever
+    /// transitions it was offline for.
+    last_state: Arc<DashMap<String, ProgressFrame>>,
 }
 
 impl ProgressBroadcaster {
     pub fn new() -> Self {
         let (tx, _) = broadcast::channel(256);
-        Self { tx }
+        Self {
+            tx,
+            next_seq: Arc::new(AtomicU64::new(0)),
+            last_state: Arc::new(DashMap::new()),
+        }
     }
 
     /// Send a progress message to all connected clients
     pub fn send(&self, message: ProgressMessage) {
-        if self.tx.send(message).is_err() {
+        let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        let job_id = message.job_id().to_string();
+        let terminal = message.is_terminal();
+        let frame = ProgressFrame { seq, message };
+
+        self.last_state.insert(job_id.clone(), frame.clone());
+        if terminal {
+            let last_state = Arc::clone(&self.last_state);
+            tokio::spawn(async move {
+                tokio::time::sleep(TERMINAL_CACHE_TTL).await;
+                last_state.remove(&job_id);
+            });
+        }
+
+        if self.tx.send(frame).is_err() {
             debug!("No progress receivers connected");
         }
     }
 
     /// Subscribe to progress messages
-    pub fn subscribe(&self) -> broadcast::Receiver<ProgressMessage> {
+    pub fn subscribe(&self) -> broadcast::Receiver<ProgressFrame> {
         self.tx.subscribe()
     }
+
+    /// The latest cached frame for every job still in the replay cache,
+    /// ordered by sequence number.
+    fn snapshot(&self) -> Vec<ProgressFrame> {
+        let mut frames: Vec<ProgressFrame> =
+            self.last_state.iter().map(|e| e.value().clone()).collect();
+        frames.sort_by_key(|f| f.seq);
+        frames
+    }
+
+    /// Every cached frame stamped after `seq`, ordered by sequence number.
+    /// Note this only covers jobs still in the replay cache, so a client
+    /// resuming after a very long gap may still miss intermediate updates
+    /// for jobs that have since completed and aged out.
+    fn snapshot_since(&self, seq: u64) -> Vec<ProgressFrame> {
+        let mut frames: Vec<ProgressFrame> = self
+            .last_state
+            .iter()
+            .filter(|e| e.value().seq > seq)
+            .map(|e| e.value().clone())
+            .collect();
+        frames.sort_by_key(|f| f.seq);
+        frames
+    }
 }
 
 impl Default for ProgressBroadcaster {
@@ -67,12 +248,64 @@ impl Default for ProgressBroadcaster {
     }
 }
 
-/// Handle a WebSocket connection from a browser client
+/// Serialize a progress frame according to the negotiated framing: CBOR
+/// binary for machine consumers that asked for it (`?encoding=cbor`), JSON
+/// text otherwise.
+fn encode_progress(frame: &ProgressFrame, binary: bool) -> Option<WsMessage> {
+    if binary {
+        match serde_cbor::to_vec(frame) {
+            Ok(bytes) => return Some(WsMessage::Binary(bytes.into())),
+            Err(e) => warn!("Failed to CBOR-encode progress message: {}", e),
+        }
+        return None;
+    }
+    match serde_json::to_string(frame) {
+        Ok(json) => Some(WsMessage::Text(json.into())),
+        Err(e) => {
+            warn!("Failed to serialize progress message: {}", e);
+            None
+        }
+    }
+}
+
+/// Flush the replay the client asked for (everything since `resume_from`, or
+/// the latest per-job state for `last_job_states`) before the live stream
+/// takes over. Stops early if the client disconnects mid-flush.
+async fn send_replay(session: &mut Session, broadcaster: &ProgressBroadcaster, handshake: &ResumeHandshake, binary: bool) {
+    let frames = match handshake.resume_from {
+        Some(seq) => broadcaster.snapshot_since(seq),
+        None => broadcaster.snapshot(),
+    };
+
+    for frame in &frames {
+        let Some(msg) = encode_progress(frame, binary) else {
+            continue;
+        };
+        let sent = match msg {
+            WsMessage::Text(text) => session.text(text).await,
+            WsMessage::Binary(bytes) => session.binary(bytes).await,
+            _ => unreachable!("encode_progress only produces Text or Binary"),
+        };
+        if sent.is_err() {
+            debug!("Client disconnected during replay");
+            break;
+        }
+    }
+}
+
+/// Handle a WebSocket connection from a browser client. `binary` selects the
+/// on-wire encoding negotiated for this connection; `msg_stream` carries
+/// inbound control frames (ping/pong/close) so the session can be kept alive
+/// through proxies and torn down cleanly.
 pub async fn handle_client_websocket(
     mut session: Session,
+    mut msg_stream: MessageStream,
     broadcaster: Arc<ProgressBroadcaster>,
+    binary: bool,
 ) {
     let mut rx = broadcaster.subscribe();
+    let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+    let mut last_pong = Instant::now();
 
     loop {
         tokio::select! {
@@ -80,15 +313,15 @@ pub async fn handle_client_websocket(
             result = rx.recv() => {
                 match result {
                     Ok(msg) => {
-                        let json = match serde_json::to_string(&msg) {
-                            Ok(j) => j,
-                            Err(e) => {
-                                warn!("Failed to serialize progress message: {}", e);
-                                continue;
-                            }
+                        let Some(frame) = encode_progress(&msg, binary) else {
+                            continue;
                         };
-
-                        if session.text(json).await.is_err() {
+                        let sent = match frame {
+                            WsMessage::Text(text) => session.text(text).await,
+                            WsMessage::Binary(bytes) => session.binary(bytes).await,
+                            _ => unreachable!("encode_progress only produces Text or Binary"),
+                        };
+                        if sent.is_err() {
                             debug!("Client disconnected");
                             break;
                         }
@@ -102,6 +335,65 @@ pub async fn handle_client_websocket(
                     }
                 }
             }
+
+            // React to inbound control frames
+            frame = msg_stream.next() => {
+                match frame {
+                    Some(Ok(WsMessage::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(WsMessage::Pong(_))) => {
+                        last_pong = Instant::now();
+                    }
+                    Some(Ok(WsMessage::Close(reason))) => {
+                        debug!("Client closed connection: {:?}", reason);
+                        break;
+                    }
+                    Some(Ok(WsMessage::Text(_))) if binary => {
+                        debug!("Dropping text frame on binary session");
+                    }
+                    Some(Ok(WsMessage::Text(text))) => {
+                        if let Some(handshake) = serde_json::from_str::<ResumeHandshake>(&text)
+                            .ok()
+                            .filter(ResumeHandshake::requests_replay)
+                        {
+                            send_replay(&mut session, &broadcaster, &handshake, binary).await;
+                        }
+                    }
+                    Some(Ok(WsMessage::Binary(bytes))) => {
+                        if let Some(handshake) = serde_cbor::from_slice::<ResumeHandshake>(&bytes)
+                            .ok()
+                            .filter(ResumeHandshake::requests_replay)
+                        {
+                            send_replay(&mut session, &broadcaster, &handshake, binary).await;
+                        }
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => {
+                        warn!("WebSocket protocol error: {}", e);
+                        break;
+                    }
+                    None => {
+                        debug!("Client stream ended");
+                        break;
+                    }
+                }
+            }
+
+            // Periodic keepalive: ping the client and drop it if it hasn't
+            // responded (to a ping or unprompted) within CLIENT_TIMEOUT.
+            _ = heartbeat.tick() => {
+                if last_pong.elapsed() > CLIENT_TIMEOUT {
+                    debug!("Client heartbeat timed out, closing");
+                    let _ = session.close(None).await;
+                    break;
+                }
+                if session.ping(b"").await.is_err() {
+                    break;
+                }
+            }
         }
     }
 
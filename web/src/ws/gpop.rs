@@ -2,13 +2,26 @@ use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::{broadcast, mpsc, oneshot, RwLock};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use tracing::{debug, error, info, warn};
 
 use crate::error::{AppError, Result};
 
+/// Delay before the first reconnect attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+/// Reconnect delay never grows past this, no matter how many attempts fail.
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+/// Max time the `_and_wait` seek/rate variants wait for a follow-up
+/// `StateChanged` event confirming the change landed, before giving up and
+/// returning anyway.
+const SEEK_WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+type WsStream = tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
 /// Events received from gpop-daemon
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "event", content = "data")]
@@ -30,6 +43,27 @@ pub enum GpopEvent {
     },
     #[serde(rename = "pipeline_removed")]
     PipelineRemoved { pipeline_id: String },
+    #[serde(rename = "stats")]
+    Stats {
+        pipeline_id: String,
+        stats: Value,
+    },
+    /// Synthetic event emitted locally (never sent by gpop-daemon) after the
+    /// link is re-established following a disconnect, so subscribers know to
+    /// re-query pipeline state that may have changed while it was down.
+    #[serde(rename = "reconnected")]
+    Reconnected,
+}
+
+/// Connectivity status of a [`GpopConnection`], pushed to every
+/// [`GpopConnection::subscribe_status`] receiver so the UI can show
+/// "reconnecting" instead of requests just silently hanging or failing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionStatus {
+    Connected,
+    Reconnecting,
+    Disconnected,
 }
 
 /// Response from gpop-daemon for JSON-RPC requests
@@ -46,6 +80,78 @@ struct GpopResponse {
 struct GpopErrorInfo {
     code: i32,
     message: String,
+    #[serde(default)]
+    data: Option<GpopErrorData>,
+}
+
+/// Mirrors gpop-daemon's `error_codes` module: well-known server error codes
+/// mapped to a distinct variant so callers can match on kind instead of
+/// parsing `message` text. `Other` covers protocol-level codes (parse error,
+/// method not found, ...) and any server code this client doesn't recognize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GpopErrorKind {
+    PipelineNotFound,
+    PipelineCreationFailed,
+    StateChangeFailed,
+    GStreamerError,
+    DescriptionTooLong,
+    MediaNotSupported,
+    Other,
+}
+
+impl GpopErrorKind {
+    fn from_code(code: i32) -> Self {
+        match code {
+            -32000 => Self::PipelineNotFound,
+            -32001 => Self::PipelineCreationFailed,
+            -32002 => Self::StateChangeFailed,
+            -32003 => Self::GStreamerError,
+            -32004 => Self::DescriptionTooLong,
+            -32005 => Self::MediaNotSupported,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Mirrors gpop-daemon's `ErrorData`: retry guidance attached to an error
+/// whose retriability is actually known (as opposed to a protocol-level
+/// error on our own side, e.g. a response we couldn't parse).
+#[derive(Debug, Clone, Deserialize)]
+struct GpopErrorData {
+    retriable: bool,
+    #[allow(dead_code)]
+    #[serde(default)]
+    retry_after_ms: Option<u64>,
+}
+
+/// An error reported back from a request sent to gpop-daemon, tagged with
+/// enough to tell a caller whether reissuing it stands a chance.
+struct GpopRequestError {
+    message: String,
+    outcome: GpopRequestErrorOutcome,
+}
+
+/// Distinguishes a recoverable daemon-reported failure (retriability known,
+/// per `outcome`) from the two ways a request can fail without ever really
+/// getting an answer: a protocol-level violation (malformed response, ...),
+/// never worth retrying verbatim, and the link dropping before a response
+/// arrived, worth retrying once reconnected.
+enum GpopRequestErrorOutcome {
+    Rpc {
+        code: i32,
+        kind: GpopErrorKind,
+        retriable: bool,
+    },
+    Protocol,
+    ConnectionLost,
+}
+
+/// Result of a `subscribe` call, used only to recover the subscription id
+/// needed to issue a matching `unsubscribe` later.
+#[derive(Debug, Clone, Deserialize)]
+struct SubscribeResult {
+    subscription_id: u64,
 }
 
 /// Position query result
@@ -62,21 +168,63 @@ pub struct PipelineCreatedResult {
     pub pipeline_id: String,
 }
 
-/// Internal request to the writer task
+/// Internal request to the connection's supervisor task
 struct GpopRequest {
     message: String,
-    response_tx: oneshot::Sender<std::result::Result<Value, String>>,
+    response_tx: oneshot::Sender<std::result::Result<Value, GpopRequestError>>,
+}
+
+/// A request still awaiting its response, kept around so it can be resent
+/// if the link drops before the daemon answers.
+struct PendingRequest {
+    message: String,
+    response_tx: oneshot::Sender<std::result::Result<Value, GpopRequestError>>,
+}
+
+/// An event filter established via [`GpopConnection::subscribe`], kept so it
+/// can be transparently reissued against a fresh socket after a reconnect.
+/// `daemon_id` is the id the *current* connection's `subscribe` call handed
+/// back; it's only valid for `unsubscribe` while this same socket is alive,
+/// and is replaced every time the filter is reissued.
+#[derive(Debug, Clone, Serialize)]
+struct SubscriptionEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pipeline_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_kind: Option<String>,
+    #[serde(skip)]
+    daemon_id: Option<u64>,
 }
 
-/// Connection to gpop-daemon via WebSocket
+type PendingMap = Arc<RwLock<HashMap<String, PendingRequest>>>;
+type SubscriptionMap = Arc<RwLock<HashMap<u64, SubscriptionEntry>>>;
+
+/// Opaque handle to a filter registered with [`GpopConnection::subscribe`],
+/// used to cancel it later with [`GpopConnection::unsubscribe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionHandle(u64);
+
+/// Connection to gpop-daemon via WebSocket.
+///
+/// Transparently reconnects with exponential backoff if the link drops:
+/// requests that were sent but never answered are reissued against the new
+/// socket, and every still-active `subscribe` filter is reissued too, so
+/// callers never observe the blip beyond extra request latency.
 pub struct GpopConnection {
     request_tx: mpsc::Sender<GpopRequest>,
     event_tx: broadcast::Sender<GpopEvent>,
+    status_tx: broadcast::Sender<ConnectionStatus>,
     connected: Arc<RwLock<bool>>,
+    active_subscriptions: SubscriptionMap,
+    next_subscription_handle: AtomicU64,
 }
 
 impl GpopConnection {
-    /// Connect to gpop-daemon at the given URL
+    /// Connect to gpop-daemon at the given URL. The first attempt is made
+    /// synchronously so a caller like `main` gets an immediate, actionable
+    /// error if the daemon isn't reachable at all at startup; once
+    /// established, a background task takes over and reconnects
+    /// transparently if the link drops later.
     pub async fn connect(url: &str) -> Result<Self> {
         let (ws_stream, _) = connect_async(url)
             .await
@@ -84,126 +232,250 @@ impl GpopConnection {
 
         info!("Connected to gpop-daemon at {}", url);
 
-        let (write, read) = ws_stream.split();
-
         let (request_tx, request_rx) = mpsc::channel::<GpopRequest>(32);
         let (event_tx, _) = broadcast::channel::<GpopEvent>(256);
+        let (status_tx, _) = broadcast::channel::<ConnectionStatus>(16);
         let connected = Arc::new(RwLock::new(true));
-
-        // Pending requests waiting for responses
-        let pending: Arc<RwLock<HashMap<String, oneshot::Sender<std::result::Result<Value, String>>>>> =
-            Arc::new(RwLock::new(HashMap::new()));
-
-        // Spawn writer task
-        let pending_clone = Arc::clone(&pending);
-        let connected_clone = Arc::clone(&connected);
-        tokio::spawn(async move {
-            Self::writer_loop(write, request_rx, pending_clone, connected_clone).await;
-        });
-
-        // Spawn reader task
-        let event_tx_clone = event_tx.clone();
-        let pending_clone = Arc::clone(&pending);
-        let connected_clone = Arc::clone(&connected);
+        let pending: PendingMap = Arc::new(RwLock::new(HashMap::new()));
+        let active_subscriptions: SubscriptionMap = Arc::new(RwLock::new(HashMap::new()));
+
+        let run_url = url.to_string();
+        let run_event_tx = event_tx.clone();
+        let run_status_tx = status_tx.clone();
+        let run_pending = Arc::clone(&pending);
+        let run_connected = Arc::clone(&connected);
+        let run_subscriptions = Arc::clone(&active_subscriptions);
         tokio::spawn(async move {
-            Self::reader_loop(read, event_tx_clone, pending_clone, connected_clone).await;
+            Self::run(
+                run_url,
+                Some(ws_stream),
+                request_rx,
+                run_pending,
+                run_event_tx,
+                run_status_tx,
+                run_connected,
+                run_subscriptions,
+            )
+            .await;
         });
 
         Ok(Self {
             request_tx,
             event_tx,
+            status_tx,
             connected,
+            active_subscriptions,
+            next_subscription_handle: AtomicU64::new(1),
         })
     }
 
-    async fn writer_loop(
-        mut write: futures_util::stream::SplitSink<
-            tokio_tungstenite::WebSocketStream<
-                tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-            >,
-            Message,
-        >,
+    /// Owns the socket for the lifetime of the connection: on the very first
+    /// iteration reuses `initial_stream` (already connected by `connect`),
+    /// then connects, serves requests and events until the link drops, then
+    /// reconnects with exponential backoff, replaying whatever was still
+    /// pending and reissuing every active subscription.
+    #[allow(clippy::too_many_arguments)]
+    async fn run(
+        url: String,
+        mut initial_stream: Option<WsStream>,
         mut request_rx: mpsc::Receiver<GpopRequest>,
-        pending: Arc<RwLock<HashMap<String, oneshot::Sender<std::result::Result<Value, String>>>>>,
+        pending: PendingMap,
+        event_tx: broadcast::Sender<GpopEvent>,
+        status_tx: broadcast::Sender<ConnectionStatus>,
         connected: Arc<RwLock<bool>>,
+        active_subscriptions: SubscriptionMap,
     ) {
-        while let Some(request) = request_rx.recv().await {
-            // Extract request ID from the message
-            if let Ok(parsed) = serde_json::from_str::<Value>(&request.message) {
-                if let Some(id) = parsed.get("id").and_then(|v| v.as_str()) {
-                    // Store the response channel
-                    pending.write().await.insert(id.to_string(), request.response_tx);
-
-                    // Send the message
-                    if let Err(e) = write.send(Message::Text(request.message.into())).await {
-                        error!("Failed to send message to gpop: {}", e);
-                        *connected.write().await = false;
+        let mut backoff = INITIAL_BACKOFF;
+
+        loop {
+            let (ws_stream, is_reconnect) = match initial_stream.take() {
+                Some(stream) => (stream, false),
+                None => match connect_async(&url).await {
+                    Ok((stream, _)) => {
+                        info!("Reconnected to gpop-daemon at {}", url);
+                        (stream, true)
+                    }
+                    Err(e) => {
+                        warn!("Failed to reconnect to gpop-daemon at {}: {}", url, e);
+                        let _ = status_tx.send(ConnectionStatus::Reconnecting);
+                        Self::sleep_backoff(&mut backoff).await;
+                        continue;
+                    }
+                },
+            };
+
+            backoff = INITIAL_BACKOFF;
+            *connected.write().await = true;
+            let _ = status_tx.send(ConnectionStatus::Connected);
+            if is_reconnect {
+                // Synthetic event (gpop-daemon never sends this itself) so
+                // subscribers can re-query pipeline state that may have
+                // changed while the link was down.
+                let _ = event_tx.send(GpopEvent::Reconnected);
+            }
+
+            let (mut write, mut read) = ws_stream.split();
+
+            // Replay every request still awaiting a response from the
+            // previous socket.
+            {
+                let pending = pending.read().await;
+                for pending_request in pending.values() {
+                    let msg = Message::Text(pending_request.message.clone().into());
+                    if write.send(msg).await.is_err() {
                         break;
                     }
-                } else {
-                    let _ = request.response_tx.send(Err("Invalid request: missing id".to_string()));
                 }
-            } else {
-                let _ = request.response_tx.send(Err("Invalid request: not valid JSON".to_string()));
             }
-        }
 
-        debug!("Writer loop ended");
-    }
+            // Reissue every subscribe filter the caller is still holding
+            // open, matching each response by its id; anything else that
+            // arrives first (a replayed request's response, an event) is
+            // dispatched as usual.
+            {
+                let mut subs = active_subscriptions.write().await;
+                'resubscribe: for (handle, entry) in subs.iter_mut() {
+                    entry.daemon_id = None;
+                    let req_id = format!("resubscribe-{}", handle);
+                    let message = json!({
+                        "id": req_id,
+                        "method": "subscribe",
+                        "params": &*entry,
+                    })
+                    .to_string();
+                    if write.send(Message::Text(message.into())).await.is_err() {
+                        break;
+                    }
+                    loop {
+                        match read.next().await {
+                            Some(Ok(Message::Text(text))) => {
+                                match serde_json::from_str::<GpopResponse>(&text) {
+                                    Ok(response) if response.id == req_id => {
+                                        if let Some(result) = response.result {
+                                            if let Ok(parsed) =
+                                                serde_json::from_value::<SubscribeResult>(result)
+                                            {
+                                                entry.daemon_id = Some(parsed.subscription_id);
+                                            }
+                                        }
+                                        break;
+                                    }
+                                    _ => Self::dispatch_message(&text, &pending, &event_tx).await,
+                                }
+                            }
+                            Some(Ok(_)) => {}
+                            _ => break 'resubscribe,
+                        }
+                    }
+                }
+            }
 
-    async fn reader_loop(
-        mut read: futures_util::stream::SplitStream<
-            tokio_tungstenite::WebSocketStream<
-                tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-            >,
-        >,
-        event_tx: broadcast::Sender<GpopEvent>,
-        pending: Arc<RwLock<HashMap<String, oneshot::Sender<std::result::Result<Value, String>>>>>,
-        connected: Arc<RwLock<bool>>,
-    ) {
-        while let Some(msg) = read.next().await {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    // Try to parse as a response (has "id" field)
-                    if let Ok(response) = serde_json::from_str::<GpopResponse>(&text) {
-                        // This is a response to a request
-                        if let Some(tx) = pending.write().await.remove(&response.id) {
-                            let result = if let Some(error) = response.error {
-                                Err(error.message)
-                            } else {
-                                Ok(response.result.unwrap_or(Value::Null))
-                            };
-                            let _ = tx.send(result);
+            let mut link_alive = true;
+            while link_alive {
+                tokio::select! {
+                    incoming = request_rx.recv() => {
+                        let Some(request) = incoming else {
+                            // Sender side dropped: the process is shutting down.
+                            return;
+                        };
+                        match Self::extract_id(&request.message) {
+                            Some(id) => {
+                                let msg = Message::Text(request.message.clone().into());
+                                if write.send(msg).await.is_ok() {
+                                    pending.write().await.insert(id, PendingRequest {
+                                        message: request.message,
+                                        response_tx: request.response_tx,
+                                    });
+                                } else {
+                                    let _ = request.response_tx.send(Err(GpopRequestError {
+                                        message: "Connection to gpop-daemon lost".to_string(),
+                                        outcome: GpopRequestErrorOutcome::ConnectionLost,
+                                    }));
+                                    link_alive = false;
+                                }
+                            }
+                            None => {
+                                let _ = request.response_tx.send(Err(GpopRequestError {
+                                    message: "Invalid request: missing id".to_string(),
+                                    outcome: GpopRequestErrorOutcome::Protocol,
+                                }));
+                            }
                         }
-                    } else if let Ok(event) = serde_json::from_str::<GpopEvent>(&text) {
-                        // This is an event broadcast
-                        if event_tx.send(event).is_err() {
-                            debug!("No event receivers");
+                    }
+                    msg = read.next() => {
+                        match msg {
+                            Some(Ok(Message::Text(text))) => {
+                                Self::dispatch_message(&text, &pending, &event_tx).await;
+                            }
+                            Some(Ok(Message::Close(_))) | None => {
+                                info!("gpop-daemon connection closed");
+                                link_alive = false;
+                            }
+                            Some(Ok(_)) => {}
+                            Some(Err(e)) => {
+                                error!("Error reading from gpop-daemon: {}", e);
+                                link_alive = false;
+                            }
                         }
-                    } else {
-                        warn!("Unknown message from gpop: {}", text);
                     }
                 }
-                Ok(Message::Close(_)) => {
-                    info!("gpop connection closed");
-                    *connected.write().await = false;
-                    break;
-                }
-                Ok(Message::Ping(data)) => {
-                    debug!("Received ping from gpop");
-                    // Pong is handled automatically by tungstenite
-                    let _ = data;
-                }
-                Err(e) => {
-                    error!("Error reading from gpop: {}", e);
-                    *connected.write().await = false;
-                    break;
-                }
-                _ => {}
             }
+
+            *connected.write().await = false;
+            let _ = status_tx.send(ConnectionStatus::Disconnected);
+            Self::sleep_backoff(&mut backoff).await;
+        }
+    }
+
+    fn extract_id(message: &str) -> Option<String> {
+        serde_json::from_str::<Value>(message)
+            .ok()
+            .and_then(|v| v.get("id").and_then(|id| id.as_str()).map(String::from))
+    }
+
+    /// Parse an incoming text frame as either a response (matched to a
+    /// pending request by id) or an event broadcast. gpop-daemon pushes
+    /// events as JSON-RPC notifications whose `method` is the event tag and
+    /// whose `params` is its data.
+    async fn dispatch_message(text: &str, pending: &PendingMap, event_tx: &broadcast::Sender<GpopEvent>) {
+        if let Ok(response) = serde_json::from_str::<GpopResponse>(text) {
+            if let Some(pending_request) = pending.write().await.remove(&response.id) {
+                let result = if let Some(error) = response.error {
+                    let outcome = match error.data {
+                        Some(data) => GpopRequestErrorOutcome::Rpc {
+                            code: error.code,
+                            kind: GpopErrorKind::from_code(error.code),
+                            retriable: data.retriable,
+                        },
+                        None => GpopRequestErrorOutcome::Protocol,
+                    };
+                    Err(GpopRequestError {
+                        message: error.message,
+                        outcome,
+                    })
+                } else {
+                    Ok(response.result.unwrap_or(Value::Null))
+                };
+                let _ = pending_request.response_tx.send(result);
+            }
+        } else if let Ok(event) = serde_json::from_str::<Value>(text).and_then(|v| {
+            serde_json::from_value::<GpopEvent>(json!({ "event": v["method"], "data": v["params"] }))
+        }) {
+            if event_tx.send(event).is_err() {
+                debug!("No event receivers");
+            }
+        } else {
+            warn!("Unknown message from gpop: {}", text);
         }
+    }
 
-        debug!("Reader loop ended");
+    async fn sleep_backoff(backoff: &mut Duration) {
+        let jitter_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_millis() % 100)
+            .unwrap_or(0) as u64;
+        tokio::time::sleep(*backoff + Duration::from_millis(jitter_ms)).await;
+        *backoff = (*backoff * 2).min(MAX_BACKOFF);
     }
 
     /// Send a JSON-RPC request and wait for the response
@@ -229,7 +501,20 @@ impl GpopConnection {
         response_rx
             .await
             .map_err(|_| AppError::GpopConnection("Response channel closed".to_string()))?
-            .map_err(|e| AppError::GpopProtocol(e))
+            .map_err(|e| match e.outcome {
+                GpopRequestErrorOutcome::Rpc {
+                    code,
+                    kind,
+                    retriable,
+                } => AppError::GpopRpc {
+                    code,
+                    kind,
+                    message: e.message,
+                    retriable,
+                },
+                GpopRequestErrorOutcome::Protocol => AppError::GpopProtocol { message: e.message },
+                GpopRequestErrorOutcome::ConnectionLost => AppError::GpopConnection(e.message),
+            })
     }
 
     /// Create a new pipeline in gpop-daemon
@@ -239,7 +524,9 @@ impl GpopConnection {
             .await?;
 
         let created: PipelineCreatedResult =
-            serde_json::from_value(result).map_err(|e| AppError::GpopProtocol(e.to_string()))?;
+            serde_json::from_value(result).map_err(|e| AppError::GpopProtocol {
+                message: e.to_string(),
+            })?;
 
         Ok(created.pipeline_id)
     }
@@ -278,7 +565,136 @@ impl GpopConnection {
             .request("get_position", json!({ "pipeline_id": pipeline_id }))
             .await?;
 
-        serde_json::from_value(result).map_err(|e| AppError::GpopProtocol(e.to_string()))
+        serde_json::from_value(result).map_err(|e| AppError::GpopProtocol {
+                message: e.to_string(),
+            })
+    }
+
+    /// Reposition playback to an absolute position.
+    pub async fn seek(&self, pipeline_id: &str, position_ns: u64) -> Result<()> {
+        self.request(
+            "seek",
+            json!({ "pipeline_id": pipeline_id, "position_ns": position_ns }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Change a pipeline's playback rate for fast-forward/reverse
+    /// trick-mode playback.
+    pub async fn set_rate(&self, pipeline_id: &str, rate: f64) -> Result<()> {
+        self.request("set_rate", json!({ "pipeline_id": pipeline_id, "rate": rate }))
+            .await?;
+        Ok(())
+    }
+
+    /// Seek by an offset from the pipeline's current position, read via
+    /// `get_position`. Negative offsets never seek past the start.
+    pub async fn seek_relative(&self, pipeline_id: &str, offset_ns: i64) -> Result<()> {
+        let target = self.offset_from_current_position(pipeline_id, offset_ns).await?;
+        self.seek(pipeline_id, target).await
+    }
+
+    /// Like `seek`, but waits for a follow-up `StateChanged` event for this
+    /// pipeline before returning, analogous to a fetch-and-wait range
+    /// request, so callers know the seek actually landed before
+    /// immediately querying position again. Times out silently after
+    /// `SEEK_WAIT_TIMEOUT` rather than erroring, since not every daemon
+    /// state machine emits a `StateChanged` for a same-state seek.
+    pub async fn seek_and_wait(&self, pipeline_id: &str, position_ns: u64) -> Result<()> {
+        let mut events = self.event_tx.subscribe();
+        self.seek(pipeline_id, position_ns).await?;
+        Self::wait_for_state_changed(&mut events, pipeline_id).await;
+        Ok(())
+    }
+
+    /// `seek_relative` plus the same landed-confirmation wait as
+    /// `seek_and_wait`.
+    pub async fn seek_relative_and_wait(&self, pipeline_id: &str, offset_ns: i64) -> Result<()> {
+        let mut events = self.event_tx.subscribe();
+        let target = self.offset_from_current_position(pipeline_id, offset_ns).await?;
+        self.seek(pipeline_id, target).await?;
+        Self::wait_for_state_changed(&mut events, pipeline_id).await;
+        Ok(())
+    }
+
+    async fn offset_from_current_position(&self, pipeline_id: &str, offset_ns: i64) -> Result<u64> {
+        let position = self.get_position(pipeline_id).await?;
+        let current = position.position_ns.unwrap_or(0) as i64;
+        Ok((current + offset_ns).max(0) as u64)
+    }
+
+    /// Drain `events` until a `StateChanged` for `pipeline_id` arrives or
+    /// `SEEK_WAIT_TIMEOUT` elapses, whichever comes first.
+    async fn wait_for_state_changed(events: &mut broadcast::Receiver<GpopEvent>, pipeline_id: &str) {
+        let _ = tokio::time::timeout(SEEK_WAIT_TIMEOUT, async {
+            while let Ok(event) = events.recv().await {
+                if let GpopEvent::StateChanged { pipeline_id: id, .. } = &event {
+                    if id == pipeline_id {
+                        return;
+                    }
+                }
+            }
+        })
+        .await;
+    }
+
+    /// Subscribe to a filtered slice of daemon events (omit a field for
+    /// "any"). The filter is kept and transparently reissued if the
+    /// connection has to reconnect, so the caller never needs to redo this.
+    pub async fn subscribe(
+        &self,
+        pipeline_id: Option<String>,
+        event_kind: Option<String>,
+    ) -> Result<SubscriptionHandle> {
+        let entry = SubscriptionEntry {
+            pipeline_id,
+            event_kind,
+            daemon_id: None,
+        };
+        let result = self
+            .request("subscribe", serde_json::to_value(&entry).unwrap_or(Value::Null))
+            .await?;
+        let subscribed: SubscribeResult =
+            serde_json::from_value(result).map_err(|e| AppError::GpopProtocol {
+                message: e.to_string(),
+            })?;
+
+        let handle = SubscriptionHandle(self.next_subscription_handle.fetch_add(1, Ordering::Relaxed));
+        let mut entry = entry;
+        entry.daemon_id = Some(subscribed.subscription_id);
+        self.active_subscriptions.write().await.insert(handle.0, entry);
+        Ok(handle)
+    }
+
+    /// Start a periodic element-statistics poller for a pipeline on
+    /// gpop-daemon, which streams the results back as `stats` events to
+    /// every client subscribed to that pipeline. `interval_ms` falls back to
+    /// the daemon's own default (`DEFAULT_STATS_POLL_INTERVAL_MS`) when
+    /// omitted.
+    pub async fn subscribe_stats(&self, pipeline_id: &str, interval_ms: Option<u64>) -> Result<()> {
+        self.request(
+            "stats_subscribe",
+            json!({ "pipeline_id": pipeline_id, "interval_ms": interval_ms }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Cancel a subscription made with [`GpopConnection::subscribe`].
+    pub async fn unsubscribe(&self, handle: SubscriptionHandle) -> Result<()> {
+        let daemon_id = self
+            .active_subscriptions
+            .write()
+            .await
+            .remove(&handle.0)
+            .and_then(|entry| entry.daemon_id);
+
+        if let Some(subscription_id) = daemon_id {
+            self.request("unsubscribe", json!({ "subscription_id": subscription_id }))
+                .await?;
+        }
+        Ok(())
     }
 
     /// Subscribe to events from gpop-daemon
@@ -286,6 +702,12 @@ impl GpopConnection {
         self.event_tx.subscribe()
     }
 
+    /// Subscribe to connection status changes (connected / reconnecting /
+    /// disconnected), e.g. to surface a banner in the UI.
+    pub fn subscribe_status(&self) -> broadcast::Receiver<ConnectionStatus> {
+        self.status_tx.subscribe()
+    }
+
     /// Check if connected to gpop-daemon
     pub async fn is_connected(&self) -> bool {
         *self.connected.read().await
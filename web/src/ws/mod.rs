@@ -1,5 +1,5 @@
 pub mod client;
 pub mod gpop;
 
-pub use client::{handle_client_websocket, ProgressBroadcaster, ProgressMessage};
-pub use gpop::{GpopConnection, GpopEvent};
+pub use client::{handle_client_websocket, ProgressBroadcaster, ProgressFrame, ProgressMessage, ProgressQuery};
+pub use gpop::{ConnectionStatus, GpopConnection, GpopErrorKind, GpopEvent, SubscriptionHandle};